@@ -125,13 +125,11 @@ impl ScalePage {
                 ],
             )
         };
+        let bars = (0..entries.len())
+            .step_by(4)
+            .map(new_bar)
+            .collect();
         let track = ProtoTrack::new("notes".to_owned(), TrackKind::Vocal, entries);
-        let bars = vec![
-            new_bar(0),
-            new_bar(4),
-            new_bar(8),
-            new_bar(12),
-        ];
         let section = ProtoSection::new("notes".to_owned(), SectionKind::Verse, bars);
         ProtoTab::new(
             ProtoTab::new_uuid().as_str(),
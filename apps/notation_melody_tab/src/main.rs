@@ -0,0 +1,120 @@
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser};
+
+use notation_tab::prelude::*;
+
+/// Adds a guitar track picking the melody of a track in a `.ron` tab, e.g. the vocal one.
+#[derive(Debug, Parser)]
+#[clap(author, version, long_about = None)]
+struct Args {
+    /// Tab to read, in `.ron`
+    tab: String,
+
+    /// Track with the melody [default: the first vocal track]
+    #[clap(long)]
+    from: Option<String>,
+
+    /// Id of the new track
+    #[clap(long, default_value = "melody")]
+    id: String,
+
+    /// Preset name or pitches from the highest string
+    #[clap(long, default_value = "Standard")]
+    tuning: StringTuning,
+
+    #[clap(long, default_value_t = 0, allow_negative_numbers = true)]
+    capo: i8,
+
+    /// Lowest fret the hand prefers
+    #[clap(long, default_value_t = 0)]
+    min_fret: u8,
+
+    /// Highest fret the hand prefers
+    #[clap(long, default_value_t = 5)]
+    max_fret: u8,
+
+    /// Only fretted notes
+    #[clap(long)]
+    no_open: bool,
+
+    /// Octaves to move the melody, e.g. -1
+    #[clap(long, default_value_t = 0, allow_negative_numbers = true)]
+    octave: i8,
+
+    /// Writes the tab to the file instead of printing it
+    #[clap(long)]
+    output: Option<String>,
+}
+
+fn fail(kind: ErrorKind, message: String) -> ! {
+    Args::command().error(kind, message).exit()
+}
+
+impl Args {
+    fn fretboard(&self) -> Fretboard {
+        Fretboard::new(GUITAR_FRET_NUM_ACOUSTIC, self.tuning, 0)
+            .transpose_capo(self.capo)
+            .unwrap_or_else(|| {
+                fail(
+                    ErrorKind::InvalidValue,
+                    format!("Capo out of the fretboard: {}", self.capo),
+                )
+            })
+    }
+    fn constraints(&self) -> MelodyConstraints {
+        if self.min_fret > self.max_fret {
+            fail(
+                ErrorKind::ArgumentConflict,
+                format!(
+                    "--min-fret {} is above --max-fret {}",
+                    self.min_fret, self.max_fret
+                ),
+            );
+        }
+        MelodyConstraints {
+            min_fret: self.min_fret,
+            max_fret: self.max_fret,
+            allow_open: !self.no_open,
+            octave: self.octave,
+            ..Default::default()
+        }
+    }
+}
+
+pub fn main() {
+    let args = Args::parse();
+    let (fretboard, constraints) = (args.fretboard(), args.constraints());
+    let tab = read_tab(&args.tab).unwrap_or_else(|err| fail(ErrorKind::Io, format!("{:#}", err)));
+    let from = args.from.clone().unwrap_or_else(|| {
+        tab.tracks
+            .iter()
+            .find(|x| x.kind == TrackKind::Vocal)
+            .map(|x| x.id.clone())
+            .unwrap_or_else(|| {
+                fail(
+                    ErrorKind::InvalidValue,
+                    "No vocal track in the tab".to_owned(),
+                )
+            })
+    });
+    let tab = tab
+        .add_melody_track(&from, &args.id, fretboard, &constraints)
+        .unwrap_or_else(|| {
+            fail(
+                ErrorKind::InvalidValue,
+                format!(
+                    "Track `{}` not found, or `{}` already exists",
+                    from, args.id
+                ),
+            )
+        });
+    match args.output {
+        Some(output) => {
+            let s = convert_tab(&tab);
+            std::fs::write(&output, s.as_bytes())
+                .unwrap_or_else(|err| fail(ErrorKind::Io, err.to_string()));
+            eprintln!("Tab written to: `{}` [{}] - {}", output, s.len(), tab);
+        }
+        None => print_tab(&tab),
+    }
+}
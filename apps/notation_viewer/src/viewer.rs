@@ -111,6 +111,10 @@ impl NotationViewer {
             Control::toggle_show_note_syllable(&mut app_state, &mut settings, &mut theme);
         } else if keyboard_input.just_released(KeyCode::P) {
             Control::toggle_show_note_pitch(&mut app_state, &mut settings, &mut theme);
+        } else if keyboard_input.just_released(KeyCode::LBracket) {
+            Control::transpose_tab(&mut app_state, &mut theme, -1);
+        } else if keyboard_input.just_released(KeyCode::RBracket) {
+            Control::transpose_tab(&mut app_state, &mut theme, 1);
         } else if keyboard_input.just_released(KeyCode::F) {
             Control::toggle_always_show_fret(&mut app_state, &mut settings, &mut theme);
         } else if keyboard_input.just_released(KeyCode::L) {
@@ -0,0 +1,106 @@
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser};
+
+use notation_proto::prelude::*;
+
+/// Lists playable shapes for chord symbols, e.g. `C`, `Am7`, `D/F#`, easiest first.
+#[derive(Debug, Parser)]
+#[clap(author, version, long_about = None)]
+struct Args {
+    /// Chord symbols to list the shapes for
+    #[clap(required = true)]
+    chords: Vec<ChordSymbol>,
+
+    /// Preset name or pitches from the highest string
+    #[clap(long, default_value = "Standard")]
+    tuning: StringTuning,
+
+    #[clap(long, default_value_t = 0, allow_negative_numbers = true)]
+    capo: i8,
+
+    /// Largest stretch between fretted notes
+    #[clap(long, default_value_t = 3)]
+    span: u8,
+
+    /// Lowest fret for fretted notes
+    #[clap(long, default_value_t = 0)]
+    min_fret: u8,
+
+    /// Highest fret for fretted notes
+    #[clap(long, default_value_t = 12)]
+    max_fret: u8,
+
+    /// Only shapes without a barre
+    #[clap(long)]
+    no_barre: bool,
+
+    /// Allow muted strings between the played ones
+    #[clap(long)]
+    inner_mute: bool,
+
+    /// Allow notes other than the bass on the lowest string
+    #[clap(long)]
+    any_bass: bool,
+
+    /// Shapes to show for each chord
+    #[clap(long, default_value_t = 10)]
+    count: usize,
+}
+
+fn fail(kind: ErrorKind, message: String) -> ! {
+    Args::command().error(kind, message).exit()
+}
+
+impl Args {
+    fn fretboard(&self) -> Fretboard {
+        Fretboard::new(GUITAR_FRET_NUM_ACOUSTIC, self.tuning, 0)
+            .transpose_capo(self.capo)
+            .unwrap_or_else(|| {
+                fail(
+                    ErrorKind::InvalidValue,
+                    format!("Capo out of the fretboard: {}", self.capo),
+                )
+            })
+    }
+    fn constraints(&self) -> VoicingConstraints {
+        if self.min_fret > self.max_fret {
+            fail(
+                ErrorKind::ArgumentConflict,
+                format!(
+                    "--min-fret {} is above --max-fret {}",
+                    self.min_fret, self.max_fret
+                ),
+            );
+        }
+        VoicingConstraints {
+            max_span: self.span,
+            allow_barre: !self.no_barre,
+            allow_inner_mute: self.inner_mute,
+            require_bass: !self.any_bass,
+            min_fret: self.min_fret,
+            max_fret: self.max_fret,
+        }
+    }
+}
+
+pub fn main() {
+    let args = Args::parse();
+    let (fretboard, constraints) = (args.fretboard(), args.constraints());
+    // Only the pitches matter for the shapes, so any key works.
+    let (key, scale) = (Key::C, Scale::Major);
+    println!("Tuning: {}, Capo: {}", fretboard.tuning, fretboard.capo);
+    for symbol in args.chords.iter() {
+        let chord = symbol.to_chord(&key, &scale);
+        let voicings = fretboard.calc_voicings(&scale, &key, &chord, &constraints);
+        println!();
+        println!("{}: {} shapes", symbol, voicings.len());
+        for voicing in voicings.iter().take(args.count) {
+            println!(
+                "    {:<32} {:<24} {}",
+                voicing.to_shape_text(),
+                voicing.to_fingers_text(),
+                voicing.difficulty
+            );
+        }
+    }
+}
@@ -5,13 +5,14 @@ use bevy_prototype_lyon::prelude::*;
 use notation_bevy_utils::prelude::{FillRectangle, LayoutSize, ShapeOp};
 
 use crate::prelude::{BarData, NotationTheme, TabState};
-use notation_model::prelude::{Signature, TabBar};
+use notation_model::prelude::{Signature, TabBar, Tuplet};
 
 #[derive(Clone, Debug)]
 pub struct BarBeatValue {
     pub signature: Signature,
     pub bar_beats: u8,
     pub beat: u8,
+    pub tuplet: Option<Tuplet>,
     pub bar_size: LayoutSize,
     pub selected: bool,
 }
@@ -27,6 +28,7 @@ impl BarBeatValue {
             signature: *signature,
             bar_beats,
             beat,
+            tuplet: tab_bar.get_beat_tuplet(beat),
             bar_size: LayoutSize::ZERO,
             selected: false,
         }
@@ -38,11 +40,13 @@ pub type BarBeatData = BarData<BarBeatValue>;
 impl ShapeOp<NotationTheme, FillRectangle> for BarBeatData {
     fn get_shape(&self, theme: &NotationTheme) -> FillRectangle {
         let signature = self.value.signature;
-        let color =
-            theme
+        let color = match self.value.tuplet {
+            Some(_) if !self.value.selected => theme.colors.bar.tuplet_beat_color,
+            _ => theme
                 .colors
                 .bar
-                .get_beat_color(&signature, self.value.beat, self.value.selected);
+                .get_beat_color(&signature, self.value.beat, self.value.selected),
+        };
         let x = self.value.bar_size.width / self.value.bar_beats as f32 * self.value.beat as f32;
         FillRectangle {
             width: self.value.bar_size.width / self.value.bar_beats as f32,
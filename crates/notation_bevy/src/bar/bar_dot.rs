@@ -0,0 +1,48 @@
+use std::fmt::Display;
+
+use bevy::prelude::*;
+use notation_bevy_utils::prelude::{BevyUtil, FillCircle, LayoutSize, ShapeOp};
+use notation_model::prelude::Units;
+
+use crate::prelude::{BarData, NotationTheme};
+
+#[derive(Clone, Debug)]
+pub struct BarDotValue {
+    pub pos: Units,
+    pub index: u8,
+    pub bar_size: LayoutSize,
+}
+impl Display for BarDotValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl BarDotValue {
+    pub fn new(pos: Units, index: u8) -> Self {
+        Self {
+            pos,
+            index,
+            bar_size: LayoutSize::ZERO,
+        }
+    }
+}
+
+pub type BarDotData = BarData<BarDotValue>;
+
+impl ShapeOp<NotationTheme, FillCircle> for BarDotData {
+    fn get_shape(&self, theme: &NotationTheme) -> FillCircle {
+        let radius = theme.sizes.bar.dot_radius;
+        let offset = if self.value.bar_size.width <= 0.0 {
+            BevyUtil::offscreen_offset()
+        } else {
+            let x = self.value.bar_size.width * self.value.pos.ratio(&self.bar_props.bar_units)
+                + theme.sizes.bar.dot_x * (self.value.index + 1) as f32;
+            Vec3::new(x, theme.sizes.bar.dot_y, theme.z.bar_separator)
+        };
+        FillCircle {
+            radius,
+            color: theme.colors.bar.dot_color,
+            offset,
+        }
+    }
+}
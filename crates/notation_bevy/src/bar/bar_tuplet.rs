@@ -0,0 +1,73 @@
+use std::fmt::Display;
+
+use bevy::prelude::*;
+use notation_bevy_utils::prelude::{BevyUtil, LayoutSize, ShapeOp, StrokePath};
+use notation_model::prelude::{Tuplet, Units};
+
+use crate::prelude::{BarData, NotationTheme};
+
+#[derive(Clone, Debug)]
+pub struct BarTupletValue {
+    pub tuplet: Tuplet,
+    pub begin: Units,
+    pub end: Units,
+    pub bar_size: LayoutSize,
+}
+impl Display for BarTupletValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl BarTupletValue {
+    pub fn new(tuplet: Tuplet, begin: Units, end: Units) -> Self {
+        Self {
+            tuplet,
+            begin,
+            end,
+            bar_size: LayoutSize::ZERO,
+        }
+    }
+    /// Only the notes for the common ratios, e.g. `3` for triplets, `6:4` otherwise.
+    pub fn number_text(&self) -> String {
+        if self.tuplet.in_time_of == Tuplet::default_in_time_of(self.tuplet.notes) {
+            self.tuplet.notes.to_string()
+        } else {
+            format!("{}:{}", self.tuplet.notes, self.tuplet.in_time_of)
+        }
+    }
+}
+
+pub type BarTupletData = BarData<BarTupletValue>;
+
+impl ShapeOp<NotationTheme, StrokePath> for BarTupletData {
+    fn get_shape(&self, theme: &NotationTheme) -> StrokePath {
+        let bar_units = self.bar_props.bar_units;
+        let width =
+            self.value.bar_size.width * (self.value.end - self.value.begin).ratio(&bar_units);
+        let height = theme.sizes.bar.tuplet_height;
+        let offset = if self.value.bar_size.width <= 0.0 {
+            BevyUtil::offscreen_offset()
+        } else {
+            let x = self.value.bar_size.width * self.value.begin.ratio(&bar_units) + width / 2.0;
+            Vec3::new(
+                x,
+                theme.sizes.bar.tuplet_y + height / 2.0,
+                theme.z.bar_separator,
+            )
+        };
+        // Bracket with the ends hooked down to the notes.
+        let path = format!(
+            "M {} {} L {} {} L {} {} L {} {}",
+            0.0, height, 0.0, 0.0, width, 0.0, width, height
+        );
+        StrokePath {
+            size: Vec2::new(width, height),
+            path,
+            color: theme.colors.bar.tuplet_color,
+            line_width: theme.sizes.bar.tuplet_line_width,
+            offset,
+            scale: 1.0,
+            angle: 0.0,
+        }
+    }
+}
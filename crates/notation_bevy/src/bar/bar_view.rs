@@ -15,7 +15,9 @@ use notation_bevy_utils::prelude::{
 use notation_model::prelude::TabBar;
 
 use super::bar_beat::{BarBeatData, BarBeatValue};
+use super::bar_dot::{BarDotData, BarDotValue};
 use super::bar_separator::{BarSeparatorData, BarSeparatorValue};
+use super::bar_tuplet::{BarTupletData, BarTupletValue};
 
 pub type BarView = BarData<BarLayoutData>;
 
@@ -42,6 +44,8 @@ impl BarView {
         cell_query: ViewQuery<LaneView>,
         mut sep_query: Query<(Entity, &mut BarSeparatorData)>,
         mut beat_query: Query<(Entity, &mut BarBeatData)>,
+        mut tuplet_query: Query<(Entity, &mut BarTupletData)>,
+        mut dot_query: Query<(Entity, &mut BarDotData)>,
     ) {
         if theme._bypass_systems {
             return;
@@ -75,6 +79,22 @@ impl BarView {
                 }
             }
         }
+        for (entity, mut data) in tuplet_query.iter_mut() {
+            for (view, layout) in bars.iter() {
+                if data.bar_props.bar_ordinal == view.bar_props.bar_ordinal {
+                    data.value.bar_size = layout.size;
+                    data.update(&mut commands, &theme, entity);
+                }
+            }
+        }
+        for (entity, mut data) in dot_query.iter_mut() {
+            for (view, layout) in bars.iter() {
+                if data.bar_props.bar_ordinal == view.bar_props.bar_ordinal {
+                    data.value.bar_size = layout.size;
+                    data.update(&mut commands, &theme, entity);
+                }
+            }
+        }
     }
     pub fn update_number_text(
         theme: Res<NotationTheme>,
@@ -132,6 +152,22 @@ impl BarView {
             let data = BarBeatData::new(bar, BarBeatValue::new(bar, &signature, beat));
             data.create(commands, theme, bar_entity);
         }
+        for (tuplet, begin, end) in bar.get_tuplet_spans() {
+            let data = BarTupletData::new(bar, BarTupletValue::new(tuplet, begin, end));
+            let tuplet_entity = data.create(commands, theme, bar_entity);
+            theme.texts.tab.spawn_tuplet_number(
+                commands,
+                assets,
+                tuplet_entity,
+                data.value.number_text().as_str(),
+            );
+        }
+        for (pos, dots) in bar.get_dotted_entries() {
+            for index in 0..dots {
+                let data = BarDotData::new(bar, BarDotValue::new(pos, index));
+                data.create(commands, theme, bar_entity);
+            }
+        }
         if !settings.hide_bar_number {
             theme.texts.tab.spawn_bar_number(
                 commands,
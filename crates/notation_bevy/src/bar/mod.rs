@@ -1,7 +1,9 @@
 pub mod bar_beat;
 pub mod bar_bundle;
+pub mod bar_dot;
 pub mod bar_layout;
 pub mod bar_playing;
 pub mod bar_plugin;
 pub mod bar_separator;
+pub mod bar_tuplet;
 pub mod bar_view;
@@ -5,9 +5,9 @@ use bevy::prelude::*;
 use notation_bevy_utils::prelude::{
     BevyUtil, GridCell, LayoutAnchor, LayoutChangedWithChildrenQuery, View, ViewBundle,
 };
-use notation_model::prelude::TabChord;
+use notation_model::prelude::{ChordSymbol, TabChord};
 
-use crate::prelude::{NotationAssets, NotationTheme};
+use crate::prelude::{NotationAssets, NotationSettings, NotationTheme};
 use crate::prelude::NotationLayout;
 
 use super::chord_base::ChordBaseData;
@@ -21,6 +21,12 @@ pub struct ChordView {
     pub chord: TabChord,
 }
 
+#[derive(Clone, Debug, Component)]
+pub struct ChordName;
+
+#[derive(Clone, Debug, Component)]
+pub struct ChordNumeral;
+
 impl Display for ChordView {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "<ChordView>({})", self.chord)
@@ -43,7 +49,12 @@ impl ChordView {
         mut interval_query: Query<(Entity, &mut ChordIntervalData, &Children)>,
         mut base_query: Query<(Entity, &mut ChordBaseData, &Children)>,
         mut dot_query: Query<(Entity, &mut IntervalDotData)>,
-        mut text_query: Query<&mut Transform, With<Text>>,
+        mut text_query: Query<
+            &mut Transform,
+            (With<Text>, Without<ChordName>, Without<ChordNumeral>),
+        >,
+        mut name_query: Query<&mut Transform, (With<ChordName>, Without<ChordNumeral>)>,
+        mut numeral_query: Query<&mut Transform, With<ChordNumeral>>,
     ) {
         if theme._bypass_systems {
             return;
@@ -68,6 +79,12 @@ impl ChordView {
                 if let Ok(mut transform) = text_query.get_mut(*child) {
                     theme.texts.chord.update_bars_xy(&mut transform, layout);
                 }
+                if let Ok(mut transform) = name_query.get_mut(*child) {
+                    theme.texts.chord.update_name_xy(&mut transform, layout);
+                }
+                if let Ok(mut transform) = numeral_query.get_mut(*child) {
+                    theme.texts.chord.update_numeral_xy(&mut transform, layout);
+                }
             }
         }
     }
@@ -75,6 +92,7 @@ impl ChordView {
         commands: &mut Commands,
         assets: &NotationAssets,
         theme: &NotationTheme,
+        settings: &NotationSettings,
         entity: Entity,
         chord: &TabChord,
     ) -> Entity {
@@ -108,6 +126,32 @@ impl ChordView {
                 theme.z.chord_text,
             );
         }
+        if settings.show_note_pitch {
+            if let Some(symbol) = chord.tab().and_then(|tab| {
+                ChordSymbol::from_chord(&chord.chord, &tab.meta.key, &tab.meta.scale)
+            }) {
+                let name_entity = theme.texts.chord.spawn_name_text(
+                    commands,
+                    assets,
+                    chord_entity,
+                    symbol.to_string().as_str(),
+                    theme.z.chord_text,
+                );
+                commands.entity(name_entity).insert(ChordName);
+            }
+        }
+        if settings.show_chord_numeral {
+            if let Some(numeral) = chord.calc_roman_numeral() {
+                let numeral_entity = theme.texts.chord.spawn_numeral_text(
+                    commands,
+                    assets,
+                    chord_entity,
+                    numeral.to_string().as_str(),
+                    theme.z.chord_text,
+                );
+                commands.entity(numeral_entity).insert(ChordNumeral);
+            }
+        }
         chord_entity
     }
     pub fn on_chord_playing_changed(
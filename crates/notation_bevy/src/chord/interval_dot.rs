@@ -2,13 +2,13 @@ use std::f32::consts::PI;
 
 use bevy::prelude::*;
 use notation_bevy_utils::prelude::{OutlineCircle, ShapeOp};
-use notation_model::prelude::IntervalQuality;
+use notation_model::prelude::Interval;
 
 use crate::prelude::NotationTheme;
 
 #[derive(Clone, Debug, Component)]
 pub struct IntervalDotData {
-    pub quality: IntervalQuality,
+    pub interval: Interval,
     pub total: usize,
     pub index: usize,
     pub note_radius: f32,
@@ -18,9 +18,9 @@ pub struct IntervalDotData {
 pub type DotQuery<'w, 's, 'p, 'd> = Query<'w, 's, (&'p Parent, Entity, &'d mut IntervalDotData)>;
 
 impl IntervalDotData {
-    pub fn new(quality: IntervalQuality, total: usize, index: usize, note_radius: f32) -> Self {
+    pub fn new(interval: Interval, total: usize, index: usize, note_radius: f32) -> Self {
         Self {
-            quality,
+            interval,
             total,
             index,
             note_radius,
@@ -84,9 +84,9 @@ impl ShapeOp<NotationTheme, OutlineCircle> for IntervalDotData {
         } else {
             self.note_radius * theme.sizes.chord.interval_dot_radius_factor
         };
-        let color = theme.colors.chord.dot.of_quality(&self.quality);
+        let color = theme.colors.chord.dot.of_interval(&self.interval);
         let outline_width = theme.sizes.chord.interval_dot_outline;
-        let outline_color = theme.colors.chord.dot_outline.of_quality(&self.quality);
+        let outline_color = theme.colors.chord.dot_outline.of_interval(&self.interval);
         OutlineCircle {
             radius,
             color,
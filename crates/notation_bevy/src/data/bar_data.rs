@@ -1,7 +1,7 @@
 use std::fmt::Display;
 use bevy::prelude::*;
 
-use notation_model::prelude::{TabBar, TabBarProps, TabPosition, Units};
+use notation_model::prelude::{TabBar, TabBarProps, TabPosition};
 
 #[derive(Clone, Debug, Component)]
 pub struct BarData<T: Send + Sync + 'static> {
@@ -32,8 +32,6 @@ impl<T: Send + Sync + 'static> BarData<T> {
         (bar.props, value).into()
     }
     pub fn tab_position(&self) -> TabPosition {
-        TabPosition::new(Units(
-            self.bar_props.bar_ordinal as f32 * self.bar_props.bar_units.0,
-        ))
+        TabPosition::new(self.bar_props.bar_units * self.bar_props.bar_ordinal)
     }
 }
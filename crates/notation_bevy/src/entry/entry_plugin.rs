@@ -11,7 +11,7 @@ use crate::prelude::{
     BevyUtil, ChordBundle, EntryBundle, LyricsPlugin, NotationAssets, NotationAssetsStates,
     NotationSettings, NotationTheme, ShapesPlugin, StringsPlugin, ToneBundle,
 };
-use crate::shapes::shape_diagram::{ShapeDiagramData4, ShapeDiagramData6};
+use crate::shapes::shape_diagram::ShapeDiagramData;
 use crate::strings::pick_note::PickNoteData;
 use crate::strings::single_string::SingleStringData;
 use crate::tab::tab_events::TabBarsResizedEvent;
@@ -70,8 +70,8 @@ fn insert_entry_extra(
             entry,
             lyric_entry,
         ),
-        ProtoEntry::Fretted6(fretted_entry) => {
-            ShapesPlugin::insert_entry_extra6(
+        ProtoEntry::Fretted(fretted_entry) => {
+            ShapesPlugin::insert_entry_extra(
                 commands,
                 assets,
                 theme,
@@ -80,28 +80,7 @@ fn insert_entry_extra(
                 entry,
                 fretted_entry,
             );
-            StringsPlugin::insert_entry_extra6(
-                commands,
-                assets,
-                theme,
-                settings,
-                lane_layout.lane_kind,
-                entry_entity,
-                entry,
-                fretted_entry,
-            );
-        }
-        ProtoEntry::Fretted4(fretted_entry) => {
-            ShapesPlugin::insert_entry_extra4(
-                commands,
-                assets,
-                theme,
-                settings,
-                entry_entity,
-                entry,
-                fretted_entry,
-            );
-            StringsPlugin::insert_entry_extra4(
+            StringsPlugin::insert_entry_extra(
                 commands,
                 assets,
                 theme,
@@ -142,6 +121,8 @@ fn insert_core_entry_extra(
                 .entity(entity)
                 .insert(ChordBundle::from(*chord));
         }
+        CoreEntry::Dynamic(_) => (),
+        CoreEntry::Articulation(_) => (),
     };
 }
 
@@ -155,8 +136,7 @@ fn on_tab_bars_resized(
     mut pick_note_query: Query<(Entity, &mut PickNoteData), With<PickNoteData>>,
     mut single_string_query: Query<(Entity, &mut SingleStringData), With<SingleStringData>>,
     mut word_text_query: Query<(Entity, &mut WordTextData), With<WordTextData>>,
-    mut shape_diagram_6_query: Query<(Entity, &mut ShapeDiagramData6), With<ShapeDiagramData6>>,
-    mut shape_diagram_4_query: Query<(Entity, &mut ShapeDiagramData4), With<ShapeDiagramData4>>,
+    mut shape_diagram_query: Query<(Entity, &mut ShapeDiagramData), With<ShapeDiagramData>>,
 ) {
     if theme._bypass_systems {
         return;
@@ -188,15 +168,7 @@ fn on_tab_bars_resized(
             }
         }
         if !settings.hide_shapes_lane {
-            for (entity, mut data) in shape_diagram_6_query.iter_mut() {
-                for (view, layout) in bars.iter() {
-                    if data.bar_props.bar_ordinal == view.bar_props.bar_ordinal {
-                        data.value.bar_size = layout.size.width;
-                        data.update(&mut commands, &theme, entity);
-                    }
-                }
-            }
-            for (entity, mut data) in shape_diagram_4_query.iter_mut() {
+            for (entity, mut data) in shape_diagram_query.iter_mut() {
                 for (view, layout) in bars.iter() {
                     if data.bar_props.bar_ordinal == view.bar_props.bar_ordinal {
                         data.value.bar_size = layout.size.width;
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use bevy::prelude::*;
 use notation_bevy_utils::prelude::{BevyUtil, LayoutSize, ShapeOp};
 use notation_model::prelude::{
-    Chord, Finger, Fretboard6, HandShape6, Interval, ModelEntryProps, Note, Pick, Syllable, TabMeta, Semitones,
+    Chord, Finger, Fretboard, HandShape, Interval, ModelEntryProps, Note, Pick, Syllable, TabMeta, Semitones,
 };
 
 use crate::chord::chord_note::{ChordNoteData, ChordNoteExtra, ChordNoteValue};
@@ -57,6 +57,7 @@ impl FretFingerData {
     pub fn reset(&mut self) {
         self.value.extra.visible = false;
         self.value.extra.capo = 0;
+        self.value.extra.finger = None;
         self.value.root = Syllable::Fi;
         self.value.interval = Interval::Tritone;
         self.value.extra.in_chord = false;
@@ -98,7 +99,7 @@ impl FretFingerData {
     }
     pub fn update_pick(
         &mut self,
-        fretboard: Option<Fretboard6>,
+        fretboard: Option<Fretboard>,
         chord: Option<Chord>,
         pick: Pick,
         meta: Option<Arc<TabMeta>>,
@@ -108,6 +109,7 @@ impl FretFingerData {
         if self.value.extra.pick {
             self.reset();
             self.value.extra.fret = pick_note.and_then(|x| x.fret);
+            self.value.extra.finger = pick_note.and_then(|x| x.fret_finger);
             if let Some(fretboard) = fretboard {
                 self.value.extra.capo = fretboard.capo;
                 let note = match (meta.as_ref(), pick_note) {
@@ -132,8 +134,8 @@ impl FretFingerData {
     }
     pub fn update_value(
         &mut self,
-        shape: &HandShape6,
-        fretboard: Option<Fretboard6>,
+        shape: &HandShape,
+        fretboard: Option<Fretboard>,
         chord: Option<Chord>,
         pick: Option<Pick>,
         meta: Option<Arc<TabMeta>>,
@@ -142,8 +144,10 @@ impl FretFingerData {
         let pick_note = pick.and_then(|x| x.get_pick_note(self.value.extra.string));
         if self.value.extra.pick {
             self.value.extra.fret = pick_note.and_then(|x| x.fret);
+            self.value.extra.finger = pick_note.and_then(|x| x.fret_finger);
         } else {
             self.value.extra.fret = shape.string_fret_with_barre(self.value.extra.string);
+            self.value.extra.finger = shape.string_finger(self.value.extra.string);
         }
         if let Some(fretboard) = fretboard {
             self.value.extra.capo = fretboard.capo;
@@ -191,9 +195,27 @@ impl FretFingerData {
                 settings,
                 &meta.scale, &key,
                 &syllable,
+                None,
                 size_scale,
             )
         }
+        if let Some(finger) = self.value.extra.finger {
+            if self.value.extra.visible && self.value.extra.fret.unwrap_or(0) > 0 {
+                BevyUtil::spawn_text(
+                    commands,
+                    entity,
+                    finger.to_number_text().as_str(),
+                    assets.latin_font.clone(),
+                    theme.guitar.finger_font_size * size_scale,
+                    theme.guitar.finger_font_color,
+                    HorizontalAlign::Center,
+                    VerticalAlign::Center,
+                    theme.guitar.finger_text_x * size_scale,
+                    theme.guitar.finger_text_y * size_scale,
+                    theme.guitar.syllable_text.text_z,
+                );
+            }
+        }
     }
 }
 
@@ -1,14 +1,14 @@
 use bevy::prelude::*;
 use bevy_prototype_lyon::prelude::*;
 use notation_bevy_utils::prelude::{BevyUtil, FillRectangle, LayoutSize, ShapeOp};
-use notation_model::prelude::{HandShape6, Pick};
+use notation_model::prelude::{HandShape, Pick};
 
 use crate::prelude::NotationTheme;
 
 #[derive(Clone, Debug, Component)]
 pub struct GuitarBarreData {
     pub capo: u8,
-    pub shape: Option<HandShape6>,
+    pub shape: Option<HandShape>,
     pub pick: Option<Pick>,
     pub view_size: LayoutSize,
     pub guitar_size: LayoutSize,
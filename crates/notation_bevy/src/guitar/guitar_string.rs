@@ -4,7 +4,7 @@ use bevy::prelude::*;
 use bevy_prototype_lyon::prelude::*;
 use notation_bevy_utils::prelude::{BevyUtil, LayoutSize, OutlineRectangle, ShapeOp};
 use notation_model::prelude::{
-    Duration, Fretboard6, HandShape6, Pick, PlaySpeed, PlayingState, Note, TabMeta, Units,
+    Duration, Fretboard, HandShape, Pick, PlaySpeed, PlayingState, Note, TabMeta, Units,
 };
 
 use crate::prelude::NotationTheme;
@@ -26,7 +26,7 @@ pub struct GuitarStringData {
 }
 
 impl GuitarStringData {
-    pub fn new(string: u8, upper: bool, fretboard: Option<Fretboard6>) -> Self {
+    pub fn new(string: u8, upper: bool, fretboard: Option<Fretboard>) -> Self {
         let capo = fretboard.map(|x| x.capo).unwrap_or(0);
         Self {
             string,
@@ -74,9 +74,10 @@ impl GuitarStringData {
     fn calc_hit_seconds(
         hit_duration: Duration,
         hit_string_seconds_range: (f32, f32),
-        play_speed: PlaySpeed,
+        play_speed: &PlaySpeed,
+        hit_pos: Units,
     ) -> f32 {
-        let seconds = play_speed.calc_seconds(Units::from(hit_duration));
+        let seconds = play_speed.calc_seconds(hit_pos, Units::from(hit_duration));
         BevyUtil::in_range(seconds * 0.5, hit_string_seconds_range)
     }
     pub fn set_hit(
@@ -85,7 +86,8 @@ impl GuitarStringData {
         hit_duration: Duration,
         time: &Time,
         hit_string_seconds_range: (f32, f32),
-        play_speed: PlaySpeed,
+        play_speed: &PlaySpeed,
+        hit_pos: Units,
     ) {
         if self.hit && !hit && time.elapsed_seconds_f64() < self.hit_expired_seconds {
             return;
@@ -93,13 +95,13 @@ impl GuitarStringData {
         self.hit = hit;
         self.hit_duration = hit_duration;
         self.hit_seconds = if hit {
-            Self::calc_hit_seconds(hit_duration, hit_string_seconds_range, play_speed)
+            Self::calc_hit_seconds(hit_duration, hit_string_seconds_range, play_speed, hit_pos)
         } else {
             0.0
         };
         self.hit_expired_seconds = time.elapsed_seconds_f64() + self.hit_seconds as f64;
     }
-    fn set_note(&mut self, fretboard: Option<Fretboard6>, meta: Option<Arc<TabMeta>>) {
+    fn set_note(&mut self, fretboard: Option<Fretboard>, meta: Option<Arc<TabMeta>>) {
         self.note = None;
         if let (Some(meta), Some(fretboard)) = (meta, fretboard) {
             if let Some(note) = fretboard.fretted_note(&meta.scale, &meta.key, self.string, self.fret()) {
@@ -109,7 +111,7 @@ impl GuitarStringData {
     }
     pub fn update_pick(
         &mut self,
-        fretboard: Option<Fretboard6>,
+        fretboard: Option<Fretboard>,
         pick: Pick,
         meta: Option<Arc<TabMeta>>,
     ) {
@@ -119,8 +121,8 @@ impl GuitarStringData {
     }
     pub fn update_value(
         &mut self,
-        shape: &HandShape6,
-        fretboard: Option<Fretboard6>,
+        shape: &HandShape,
+        fretboard: Option<Fretboard>,
         pick: Option<Pick>,
         meta: Option<Arc<TabMeta>>,
     ) {
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use notation_bevy_utils::prelude::{BevyUtil, LayoutSize};
+use notation_model::prelude::Fretboard;
+
+use crate::prelude::{NotationAssets, NotationTheme};
+
+/// Open note name of the string, shown above the nut.
+#[derive(Clone, Debug, Component)]
+pub struct GuitarTuningData {
+    pub string: u8,
+}
+
+impl GuitarTuningData {
+    pub fn spawn(
+        commands: &mut Commands,
+        assets: &NotationAssets,
+        theme: &NotationTheme,
+        entity: Entity,
+        fretboard: &Fretboard,
+        string: u8,
+    ) {
+        let text = match fretboard.tuning.notes().get(string as usize - 1) {
+            Some((pitch, _)) => pitch.to_text(),
+            None => return,
+        };
+        let text_entity = BevyUtil::spawn_text(
+            commands,
+            entity,
+            text.as_str(),
+            assets.latin_font.clone(),
+            theme.guitar.tuning_font_size,
+            theme.guitar.tuning_font_color,
+            HorizontalAlign::Center,
+            VerticalAlign::Center,
+            0.0,
+            0.0,
+            theme.z.guitar_string,
+        );
+        commands.entity(text_entity).insert(Self { string });
+    }
+    pub fn update_xy(&self, theme: &NotationTheme, transform: &mut Transform, guitar_size: LayoutSize) {
+        transform.translation.x = theme.guitar.calc_string_x(self.string, guitar_size.width);
+        transform.translation.y = guitar_size.height * theme.guitar.tuning_y_factor;
+    }
+}
@@ -7,8 +7,8 @@ use notation_bevy_utils::prelude::{
     BevyUtil, LayoutAnchor, LayoutChangedQuery, LayoutSize, ShapeOp, View, ViewBundle, SingleData,
 };
 use notation_model::prelude::{
-    Duration, Entry, HandShape6, Interval, LaneEntry, LaneKind, ModelEntryProps, Pick, Syllable,
-    Tab, TrackKind, Units,
+    Duration, Entry, Fretboard, HandShape, Interval, LaneEntry, LaneKind, ModelEntryProps, Pick,
+    Syllable, Tab, TrackFingering, TrackKind, Units, GUITAR_STRING_NUM,
 };
 
 use crate::chord::interval_dot::DotQuery;
@@ -19,6 +19,7 @@ use super::fret_finger::FretFingerData;
 use super::guitar_barre::GuitarBarreData;
 use super::guitar_capo::GuitarCapoData;
 use super::guitar_string::GuitarStringData;
+use super::guitar_tuning::GuitarTuningData;
 
 #[cfg(feature = "midi")]
 use notation_midi::prelude::MidiState;
@@ -26,10 +27,15 @@ use notation_midi::prelude::MidiState;
 #[derive(Clone, Debug, Component)]
 pub struct GuitarView {
     pub tab: Arc<Tab>,
+    pub fingering: TrackFingering,
 }
 impl GuitarView {
     pub fn new(tab: Arc<Tab>) -> Self {
-        Self { tab }
+        let fingering = tab
+            .get_track_of_kind(TrackKind::Guitar)
+            .map(|x| TrackFingering::from_tab(&tab, x.props.index))
+            .unwrap_or_default();
+        Self { tab, fingering }
     }
 }
 impl Display for GuitarView {
@@ -72,14 +78,20 @@ impl GuitarView {
         };
         let fretboard = tab
             .get_track_of_kind(TrackKind::Guitar)
-            .and_then(|x| x.get_fretboard6());
+            .and_then(|x| x.get_fretboard());
+        let string_num = fretboard
+            .map(|x| x.string_num())
+            .unwrap_or(GUITAR_STRING_NUM);
 
         BevyUtil::spawn_child_bundle(commands, guitar_entity, sprite_bundle);
-        for string in 1..=6 {
+        for string in 1..=string_num {
             for upper in [true, false] {
                 let string_data = GuitarStringData::new(string as u8, upper, fretboard);
                 string_data.create(commands, theme, guitar_entity);
             }
+            if let Some(fretboard) = fretboard {
+                GuitarTuningData::spawn(commands, assets, theme, guitar_entity, &fretboard, string as u8);
+            }
         }
         let capo_data = GuitarCapoData::default();
         capo_data.create(commands, theme, guitar_entity);
@@ -92,7 +104,7 @@ impl GuitarView {
                 let mut finger_data = FretFingerData::new_data(
                     ModelEntryProps {
                         index: 0,
-                        tied_units: Units(0.0),
+                        tied_units: Units::ZERO,
                     },
                     Syllable::Do,
                     Interval::Unison,
@@ -104,18 +116,18 @@ impl GuitarView {
                 finger_data.value.extra.visible = true;
                 finger_data.spawn(commands, theme, guitar_entity);
                 string = string + 1;
-                if string > 6 {
+                if string > string_num {
                     string = 1;
                 }
                 fret = fret + 1;
             }
         } else {
-            for index in 1..=6 {
+            for index in 1..=string_num {
                 for pick in [true, false] {
                     let finger_data = FretFingerData::new_data(
                         ModelEntryProps {
                             index: 0,
-                            tied_units: Units(0.0),
+                            tied_units: Units::ZERO,
                         },
                         Syllable::Do,
                         Interval::Unison,
@@ -139,6 +151,7 @@ impl GuitarView {
         mut capo_query: Query<(&Parent, Entity, &mut GuitarCapoData), With<GuitarCapoData>>,
         mut barre_query: Query<(&Parent, Entity, &mut GuitarBarreData), With<GuitarBarreData>>,
         mut finger_query: Query<(&Parent, Entity, &mut FretFingerData), With<FretFingerData>>,
+        mut tuning_query: Query<(&Parent, &GuitarTuningData, &mut Transform), Without<Sprite>>,
     ) {
         if theme._bypass_systems {
             return;
@@ -160,6 +173,11 @@ impl GuitarView {
                     string_data.update(&mut commands, &theme, string_entity);
                 }
             }
+            for (parent, tuning_data, mut transform) in tuning_query.iter_mut() {
+                if parent.get() == entity {
+                    tuning_data.update_xy(&theme, &mut transform, guitar_size);
+                }
+            }
             for (parent, finger_entity, mut finger_data) in finger_query.iter_mut() {
                 if parent.get() == entity {
                     finger_data.value.extra.guitar_size = guitar_size;
@@ -196,6 +214,7 @@ impl GuitarView {
         mut barre_query: Query<(Entity, &mut GuitarBarreData), With<GuitarBarreData>>,
         mut dot_query: DotQuery,
         text_query: Query<(&Parent, Entity), With<Text>>,
+        view_query: Query<&GuitarView>,
     ) {
         if Self::CHECKING_FRETS {
             return;
@@ -206,15 +225,17 @@ impl GuitarView {
         if settings.hide_guitar_view {
             return;
         }
+        let fingering = view_query.iter().next().map(|x| &x.fingering);
         let mut current_entry_pick = None;
-        let mut string_states = [None; 6];
-        let mut hit_strings = [(false, Duration::Zero); 6];
+        let mut string_states = [None; Fretboard::MAX_STRINGS];
+        let mut hit_strings = [(false, Duration::Zero); Fretboard::MAX_STRINGS];
         for (entry, pick, playing) in query.iter() {
             if playing.value.is_current() {
-                current_entry_pick = Some((entry.0.clone(), pick.0));
+                let pick = fingering.and_then(|x| x.get_pick(&entry.0)).unwrap_or(pick.0);
+                current_entry_pick = Some((entry.0.clone(), pick));
             }
             for pick_note in pick.0.get_notes() {
-                if pick_note.string >= 1 && pick_note.string <= 6 {
+                if pick_note.string >= 1 && pick_note.string as usize <= Fretboard::MAX_STRINGS {
                     string_states[(pick_note.string - 1) as usize] = Some(playing.value);
                     hit_strings[(pick_note.string - 1) as usize] =
                         (playing.value.is_current(), entry.0.duration());
@@ -222,11 +243,11 @@ impl GuitarView {
             }
         }
         let fretboard = current_entry_pick.clone()
-            .and_then(|(entry, _)| entry.track().and_then(|x| x.get_fretboard6()));
+            .and_then(|(entry, _)| entry.track().and_then(|x| x.get_fretboard()));
         let meta = current_entry_pick.clone().and_then(|(entry, _)| entry.bar().map(|x| x.tab_meta()));
 
         for (string_entity, mut string_data) in string_query.iter_mut() {
-            if string_data.string >= 1 && string_data.string <= 6 {
+            if string_data.string >= 1 && string_data.string as usize <= Fretboard::MAX_STRINGS {
                 let (hit, hit_duration) = hit_strings[(string_data.string - 1) as usize];
                 #[cfg(feature = "midi")]
                 string_data.set_hit(
@@ -234,7 +255,8 @@ impl GuitarView {
                     hit_duration,
                     &time,
                     theme.guitar.hit_string_seconds_range,
-                    midi_state.play_control.play_speed,
+                    &midi_state.play_control.play_speed,
+                    midi_state.play_control.position.tab.in_tab_pos,
                 );
                 if let Some(state) = string_states[(string_data.string - 1) as usize] {
                     string_data.state = state;
@@ -274,12 +296,12 @@ impl GuitarView {
             }
         }
     }
-    pub fn update_hand_shape6(
+    pub fn update_hand_shape(
         mut commands: Commands,
         assets: Res<NotationAssets>,
         theme: Res<NotationTheme>,
         settings: Res<NotationSettings>,
-        query: Query<(&SingleData<LaneEntry>, &SingleData<HandShape6>, &EntryPlaying), Changed<EntryPlaying>>,
+        query: Query<(&SingleData<LaneEntry>, &SingleData<HandShape>, &EntryPlaying), Changed<EntryPlaying>>,
         mut finger_query: Query<(Entity, &mut FretFingerData), With<FretFingerData>>,
         mut string_query: Query<(Entity, &mut GuitarStringData), With<GuitarStringData>>,
         mut capo_query: Query<(Entity, &mut GuitarCapoData), With<GuitarCapoData>>,
@@ -287,6 +309,7 @@ impl GuitarView {
         mut dot_query: DotQuery,
         text_query: Query<(&Parent, Entity), With<Text>>,
         tab_state_query: Query<(Entity, &TabState), With<TabState>>,
+        view_query: Query<&GuitarView>,
     ) {
         if Self::CHECKING_FRETS {
             return;
@@ -297,15 +320,17 @@ impl GuitarView {
         if settings.hide_guitar_view {
             return;
         }
+        let fingering = view_query.iter().next().map(|x| &x.fingering);
         let mut current_shape = None;
         for (entry, shape, playing) in query.iter() {
             if playing.value.is_current() {
-                //println!("GuitarView::update_hand_shape6(): found changed playing shape: {}", shape);
-                current_shape = Some((entry.0.clone(), shape.0));
+                //println!("GuitarView::update_hand_shape(): found changed playing shape: {}", shape);
+                let shape = fingering.and_then(|x| x.get_shape(&entry.0)).unwrap_or(shape.0);
+                current_shape = Some((entry.0.clone(), shape));
             }
         }
         if let Some((entry, shape)) = current_shape {
-            let fretboard = entry.track().and_then(|x| x.get_fretboard6());
+            let fretboard = entry.track().and_then(|x| x.get_fretboard());
             let chord = entry.bar().and_then(|x| x.get_chord_of_entry(&entry));
             let pick = entry.bar().and_then(|x| {
                 x.get_entry_in_other_lane(
@@ -313,15 +338,17 @@ impl GuitarView {
                     entry.track_index(),
                     Some(entry.in_bar_pos()),
                     &|x: &LaneEntry| {
-                        x.proto()
-                            .as_fretted6()
-                            .and_then(|y| y.as_pick())
-                            .map(|z| z.to_owned())
+                        fingering.and_then(|y| y.get_pick(x)).or_else(|| {
+                            x.proto()
+                                .as_fretted()
+                                .and_then(|y| y.as_pick())
+                                .map(|z| z.to_owned())
+                        })
                     },
                 )
             });
             let meta = entry.bar().map(|x| x.tab_meta());
-            //println!("GuitarView::update_hand_shape6(): {}, {:#?}, {:#?}", shape, fretboard, chord);
+            //println!("GuitarView::update_hand_shape(): {}, {:#?}, {:#?}", shape, fretboard, chord);
             for (finger_entity, mut finger_data) in finger_query.iter_mut() {
                 finger_data.update_value(&shape, fretboard, chord, pick, meta.clone());
                 finger_data.respawn_dots(&mut commands, &theme, Some(&mut dot_query), finger_entity);
@@ -2,4 +2,5 @@ pub mod fret_finger;
 pub mod guitar_barre;
 pub mod guitar_capo;
 pub mod guitar_string;
+pub mod guitar_tuning;
 pub mod guitar_view;
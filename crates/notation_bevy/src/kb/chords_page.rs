@@ -2,7 +2,9 @@ use bevy::prelude::*;
 use crate::bevy_egui::egui::{self, Ui};
 use notation_bevy_utils::asset::markdown_asset::MarkDownAsset;
 use notation_bevy_utils::prelude::EasyLinkEvent;
-use notation_model::prelude::TrackKind;
+use notation_model::prelude::{
+    ChordSymbol, DiatonicChordKind, Fretboard, Key, Scale, TabChord, TrackKind, VoicingConstraints,
+};
 
 use crate::prelude::{NotationState, NotationAssets, NotationTheme};
 
@@ -98,5 +100,65 @@ impl KbPage for ChordsPage {
                 ui.end_row();
             }
         });
+        let fretboard = state.tab.as_ref().and_then(|tab| {
+            tab.get_track_of_kind(TrackKind::Guitar)
+                .and_then(|x| x.get_fretboard())
+        });
+        if let Some(fretboard) = fretboard {
+            ui.separator();
+            Self::voicings_ui(ui, &fretboard, &scale, &key, &chords);
+        }
+        ui.separator();
+        Self::diatonic_chords_ui(ui, theme, &scale, &key);
+    }
+}
+
+impl ChordsPage {
+    pub const VOICING_NUM: usize = 4;
+
+    /// Easiest shapes of the chords on the guitar, with the fingers on hover.
+    pub fn voicings_ui(
+        ui: &mut Ui,
+        fretboard: &Fretboard,
+        scale: &Scale,
+        key: &Key,
+        chords: &[TabChord],
+    ) {
+        let constraints = VoicingConstraints::default();
+        egui::Grid::new("chord_voicings").show(ui, |ui| {
+            for chord in chords.iter() {
+                let symbol = ChordSymbol::from_chord(&chord.chord, key, scale)
+                    .map(|x| x.to_string())
+                    .unwrap_or_else(|| chord.chord.to_text());
+                PageHelper::add_strong_text(ui, &symbol);
+                let voicings = fretboard.calc_voicings(scale, key, &chord.chord, &constraints);
+                for voicing in voicings.iter().take(Self::VOICING_NUM) {
+                    ui.label(voicing.to_shape_text())
+                        .on_hover_text(voicing.to_fingers_text());
+                }
+                ui.end_row();
+            }
+        });
+    }
+    pub fn diatonic_chords_ui(ui: &mut Ui, theme: &NotationTheme, scale: &Scale, key: &Key) {
+        egui::Grid::new("diatonic_chords").show(ui, |ui| {
+            for kind in [DiatonicChordKind::Triad, DiatonicChordKind::Seventh] {
+                ui.label(kind.to_string());
+                for chord in scale.get_diatonic_chords(kind).iter() {
+                    PageHelper::add_syllable(ui, theme, true, &chord.chord.root, false, false);
+                }
+                ui.end_row();
+                ui.label("");
+                for chord in scale.get_diatonic_chords(kind).iter() {
+                    let symbol = chord
+                        .to_symbol(key, scale)
+                        .map(|x| x.to_string())
+                        .unwrap_or_default();
+                    ui.label("");
+                    PageHelper::add_strong_text(ui, &format!("{} {}", chord.numeral, symbol));
+                }
+                ui.end_row();
+            }
+        });
     }
 }
@@ -3,7 +3,7 @@ use crate::bevy_egui::egui::{self, Ui};
 use notation_bevy_utils::asset::markdown_asset::MarkDownAsset;
 use notation_bevy_utils::egui::{label_from_style, EasyMarkStyle};
 use notation_bevy_utils::prelude::EasyLinkEvent;
-use notation_model::prelude::{TrackKind, Scale, Key};
+use notation_model::prelude::{TrackKind, Scale, Key, NamingSystem};
 
 use crate::prelude::{NotationState, NotationAssets, NotationTheme};
 
@@ -38,7 +38,7 @@ impl KbPage for NotesPage {
 
         let capo = state.tab.as_ref().and_then(|tab| {
             tab.get_track_of_kind(TrackKind::Guitar)
-                .and_then(|x| x.get_fretboard6())
+                .and_then(|x| x.get_fretboard())
         }).map(|x| x.capo).unwrap_or(0);
         let transpose = capo as i8;
         Self::notes_ui(ui, texts, assets, state, theme, link_evts, scale, key, transpose);
@@ -79,6 +79,28 @@ impl NotesPage {
                 PageHelper::add_syllable_pitch(ui, theme, &scale, &key, syllable, index == 0);
             }
             ui.end_row();
+            for (index, degree) in scale.get_pattern().get_degrees().iter().enumerate() {
+                PageHelper::add_interval(ui, theme, degree, false, index == 0);
+            }
+            ui.end_row();
+            for naming_system in NamingSystem::ALL.iter() {
+                if *naming_system == NamingSystem::MoveableDo {
+                    continue;
+                }
+                for (index, syllable) in syllables.iter().enumerate() {
+                    PageHelper::add_syllable_naming(
+                        ui,
+                        theme,
+                        naming_system,
+                        &scale,
+                        &key,
+                        syllable,
+                        index == 0,
+                    );
+                }
+                ui.label(naming_system.to_string());
+                ui.end_row();
+            }
             if transpose != 0 {
                 ui.separator();
                 ui.add(label_from_style("with", &strong_style));
@@ -2,7 +2,7 @@ use crate::bevy_egui::egui::color_picker::show_color;
 use crate::bevy_egui::egui::{Ui};
 use notation_bevy_utils::egui::{label_from_style, EasyMarkStyle};
 use notation_bevy_utils::prelude::{BevyUtil};
-use notation_model::prelude::{Interval, Key, Scale, Semitones, Syllable};
+use notation_model::prelude::{Interval, Key, NamingSystem, Scale, Semitones, Syllable};
 
 use crate::prelude::NotationTheme;
 
@@ -66,6 +66,18 @@ impl PageHelper {
         let text = pitch.to_string();
         Self::add_maybe_strong_text(ui, strong, &text);
     }
+    pub fn add_syllable_naming(
+        ui: &mut Ui,
+        _theme: &NotationTheme,
+        naming_system: &NamingSystem,
+        scale: &Scale,
+        key: &Key,
+        syllable: &Syllable,
+        strong: bool,
+    ) {
+        let text = naming_system.calc_text(scale, key, syllable, None, false);
+        Self::add_maybe_strong_text(ui, strong, &text);
+    }
     pub fn add_syllable_pitch_with_transpose(
         ui: &mut Ui,
         theme: &NotationTheme,
@@ -120,7 +120,7 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::shapes::shapes_plugin::ShapesPlugin;
     #[doc(hidden)]
-    pub use crate::strings::strings_grid::{StringsGrid4, StringsGrid6};
+    pub use crate::strings::strings_grid::StringsGrid;
     #[doc(hidden)]
     pub use crate::strings::strings_plugin::StringsPlugin;
     #[doc(hidden)]
@@ -164,6 +164,12 @@ impl EguiControlPanel {
                             Slider::new(&mut midi_settings.guitar_velocity, 0..=127).text("Guitar"),
                         );
                     });
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut midi_settings.chord_mute, "Mute");
+                        ui.add(
+                            Slider::new(&mut midi_settings.chord_velocity, 0..=127).text("Chord"),
+                        );
+                    });
                     if ui.button("Reset Audio").clicked() {
                         let default = MidiSettings::default();
                         midi_settings.click_mute = default.click_mute;
@@ -172,6 +178,8 @@ impl EguiControlPanel {
                         midi_settings.vocal_velocity = default.vocal_velocity;
                         midi_settings.guitar_mute = default.guitar_mute;
                         midi_settings.guitar_velocity = default.guitar_velocity;
+                        midi_settings.chord_mute = default.chord_mute;
+                        midi_settings.chord_velocity = default.chord_velocity;
                     }
                 }
             });
@@ -268,8 +268,19 @@ impl NotationApp {
                 if let Some(tab_asset) = load_tab(state.tab_path.clone()) {
                     match tab_asset.tab {
                         Ok(tab) => {
+                            let tab = if state.transpose == Semitones(0) {
+                                tab
+                            } else {
+                                tab.transpose(state.transpose, settings.transpose_strategy)
+                            };
                             match Tab::try_parse_arc(tab, settings.add_ready_section, state.bars_range) {
                                 Ok(tab) => {
+                                    if let Some(candidate) = tab.check_key() {
+                                        println!(
+                                            "load_tab(): Declared key {} {} looks wrong, detected: {}",
+                                            tab.meta.key, tab.meta.scale, candidate
+                                        );
+                                    }
                                     state.tab = Some(tab.clone());
                                     if let Some(window) = windows.get_primary_mut() {
                                         let title = format!("{} - {}", NotationApp::TITLE, state.tab_path);
@@ -2,6 +2,8 @@ use bevy::prelude::*;
 
 use crate::settings::layout_settings::LayoutMode;
 
+use notation_model::prelude::Semitones;
+
 use crate::prelude::{NotationState, NotationSettings, NotationTheme};
 
 use super::events::WindowResizedEvent;
@@ -78,6 +80,14 @@ impl Control {
         settings.show_note_pitch = !settings.show_note_pitch;
         Self::reload_tab(state, theme);
     }
+    pub fn transpose_tab(
+        state: &mut NotationState,
+        theme: &mut NotationTheme,
+        offset: i8,
+    ) {
+        state.transpose = Semitones(state.transpose.0 + offset);
+        Self::reload_tab(state, theme);
+    }
     pub fn toggle_always_show_fret(
         state: &mut NotationState,
         settings: &mut NotationSettings,
@@ -2,7 +2,7 @@ use bevy::prelude::*;
 use crate::bevy_egui::egui::{self, CollapsingHeader, Slider, Ui};
 use crate::bevy_egui::EguiContext;
 use float_eq::float_ne;
-use notation_model::prelude::{JumpToBarEvent, PlayControlEvent};
+use notation_model::prelude::{JumpToBarEvent, NamingSystem, PlayControlEvent, TransposeStrategy};
 
 use crate::settings::layout_settings::{GridAlignMode, LayoutMode};
 use super::control::Control;
@@ -213,6 +213,26 @@ impl EguiControlPanel {
                 if show_syllable_as_num != settings.show_syllable_as_num {
                     Control::reload_tab(state, theme);
                 }
+                let naming_system = settings.naming_system;
+                egui::ComboBox::from_label("Naming System")
+                    .selected_text(naming_system.to_string())
+                    .show_ui(ui, |ui| {
+                        for system in NamingSystem::ALL {
+                            ui.selectable_value(
+                                &mut settings.naming_system,
+                                system,
+                                system.to_string(),
+                            );
+                        }
+                    });
+                if naming_system != settings.naming_system {
+                    Control::reload_tab(state, theme);
+                }
+                let show_chord_numeral = settings.show_chord_numeral;
+                ui.checkbox(&mut settings.show_chord_numeral, "Show Chord Numeral");
+                if show_chord_numeral != settings.show_chord_numeral {
+                    Control::reload_tab(state, theme);
+                }
                 let hide_bar_number = settings.hide_bar_number;
                 ui.checkbox(&mut settings.hide_bar_number, "Hide Bar Number");
                 if hide_bar_number != settings.hide_bar_number {
@@ -229,6 +249,31 @@ impl EguiControlPanel {
                     Control::reload_tab(state, theme);
                 }
                 ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("-").clicked() {
+                        Control::transpose_tab(state, theme, -1);
+                    }
+                    ui.label(format!("Transpose: {}", state.transpose));
+                    if ui.button("+").clicked() {
+                        Control::transpose_tab(state, theme, 1);
+                    }
+                });
+                let transpose_strategy = settings.transpose_strategy;
+                egui::ComboBox::from_label("Transpose Strategy")
+                    .selected_text(transpose_strategy.to_string())
+                    .show_ui(ui, |ui| {
+                        for strategy in TransposeStrategy::ALL {
+                            ui.selectable_value(
+                                &mut settings.transpose_strategy,
+                                strategy,
+                                strategy.to_string(),
+                            );
+                        }
+                    });
+                if transpose_strategy != settings.transpose_strategy && state.transpose.0 != 0 {
+                    Control::reload_tab(state, theme);
+                }
+                ui.separator();
                 let mut hide_guitar_view = settings.hide_guitar_view;
                 ui.checkbox(&mut hide_guitar_view, "Hide Guitar View");
                 if settings.hide_guitar_view != hide_guitar_view {
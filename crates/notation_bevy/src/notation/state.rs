@@ -13,6 +13,7 @@ pub struct NotationState {
     pub tab_path: String,
     pub tab: Option<Arc<Tab>>,
     pub bars_range: Option<(usize, usize)>,
+    pub transpose: Semitones,
     pub show_control: bool,
     pub show_kb: bool,
     pub preset: Option<String>,
@@ -32,6 +33,7 @@ impl NotationState {
             tab_path,
             tab: None,
             bars_range: None,
+            transpose: Semitones(0),
             show_control: false,
 
             #[cfg(debug_assertions)]
@@ -51,6 +53,7 @@ impl NotationState {
         theme._bypass_systems = true;
         self.tab_path = tab_path;
         self.bars_range = None;
+        self.transpose = Semitones(0);
         self.reload_tab()
     }
     pub fn reload_tab(&mut self) {
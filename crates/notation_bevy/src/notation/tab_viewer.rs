@@ -43,13 +43,13 @@ impl Plugin for TabViewerPlugin {
             SystemSet::on_update(NotationAssetsStates::Loaded)
                 .with_system(GuitarView::on_layout_changed)
                 .with_system(
-                    GuitarView::update_hand_shape6
-                        .label("GuitarView::update_hand_shape6"),
+                    GuitarView::update_hand_shape
+                        .label("GuitarView::update_hand_shape"),
                 )
                 .with_system(
                     GuitarView::update_string_state
                         .label("GuitarView::update_string_state")
-                        .after("GuitarView::update_hand_shape6"),
+                        .after("GuitarView::update_hand_shape"),
                 )
                 .with_system(GuitarView::adjust_y_by_barre)
         );
@@ -118,6 +118,7 @@ impl TabViewer {
         if let Some(tab) = tab {
             theme.sizes.melody.update_with_tab_vocal(&tab);
             theme.sizes.harmony.update_with_tab_guitar(&tab, None);
+            theme.sizes.strings.update_with_tab(&tab);
             TabViewer::spawn(
                 &mut commands,
                 &assets,
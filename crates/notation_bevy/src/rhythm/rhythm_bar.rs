@@ -130,8 +130,8 @@ impl RhythmBarData {
                 break;
             }
         }
-        if current_bar.is_some() {
-            let bar_props = current_bar.unwrap().props;
+        if let Some(current_bar) = current_bar {
+            let bar_props = current_bar.props;
             for (bar_entity, mut bar_data, bar_children) in bar_query.iter_mut() {
                 if bar_data.bar_props.bar_ordinal != bar_props.bar_ordinal
                     || bar_data.value.chord != current_chord
@@ -151,6 +151,7 @@ impl RhythmBarData {
             for (beat_entity, mut beat_data) in beat_query.iter_mut() {
                 beat_data.bar_props = bar_props;
                 beat_data.value.in_bar_pos = in_bar_pos;
+                beat_data.value.tuplet = current_bar.get_beat_tuplet(beat_data.value.index);
                 beat_data.update(&mut commands, &theme, beat_entity);
             }
             for (indicator_entity, mut indicator_data) in indicator_query.iter_mut() {
@@ -4,7 +4,7 @@ use std::fmt::Display;
 use bevy::prelude::*;
 
 use notation_bevy_utils::prelude::{FillCircle, ShapeOp};
-use notation_model::prelude::{Signature, TabBarProps, Units};
+use notation_model::prelude::{Signature, TabBarProps, Tuplet, Units};
 
 use crate::prelude::{BarData, NotationTheme};
 
@@ -12,6 +12,7 @@ use crate::prelude::{BarData, NotationTheme};
 pub struct RhythmBeatValue {
     pub signature: Signature,
     pub index: u8,
+    pub tuplet: Option<Tuplet>,
     pub bar_radius: f32,
     pub in_bar_pos: Units,
 }
@@ -38,17 +39,18 @@ impl RhythmBeatData {
     pub fn scale(&self, theme: &NotationTheme) -> f32 {
         let bar_units = Units::from(self.value.signature);
         let beat_units = Units::from(self.value.signature.beat_unit);
-        let center = Units(self.value.index as f32 * beat_units.0);
+        let center = beat_units * self.value.index as usize;
+        let half_beat = beat_units.scaled(1, 2);
         let mut in_bar_pos = self.value.in_bar_pos;
         if self.value.index == 0 {
-            if bar_units.0 - in_bar_pos.0 < beat_units.0 / 2.0 {
+            if bar_units - in_bar_pos < half_beat {
                 in_bar_pos = in_bar_pos - bar_units;
             }
         }
-        let delta = (in_bar_pos - center).0.abs();
-        if delta < beat_units.0 / 2.0 {
+        let delta = (in_bar_pos - center).abs();
+        if delta < half_beat {
             // https://math.stackexchange.com/questions/121720/ease-in-out-function/121755#121755
-            let x = 1.0 - delta / beat_units.0 * 2.0;
+            let x = 1.0 - delta.ratio(&half_beat);
             let y = x * x * (3.0 - 2.0 * x);
             1.0 + y * (theme.sizes.tab_control.rhythm_beat_max_scale - 1.0)
         } else {
@@ -62,10 +64,13 @@ impl ShapeOp<NotationTheme, FillCircle> for RhythmBeatData {
         let radius = self.value.bar_radius
             * theme.sizes.tab_control.rhythm_beat_radius_factor
             * self.scale(theme);
-        let color = theme
-            .colors
-            .rhythm
-            .get_beat_color(&self.value.signature, self.value.index);
+        let color = match self.value.tuplet {
+            Some(_) => theme.colors.rhythm.tuplet_beat,
+            None => theme
+                .colors
+                .rhythm
+                .get_beat_color(&self.value.signature, self.value.index),
+        };
         let offset = self.offset(theme);
         FillCircle {
             radius,
@@ -97,8 +102,9 @@ impl RhythmBeatData {
         let beat_value = RhythmBeatValue {
             signature,
             index,
+            tuplet: None,
             bar_radius: 0.0,
-            in_bar_pos: Units(0.0),
+            in_bar_pos: Units::ZERO,
         };
         let beat_data = RhythmBeatData {
             bar_props,
@@ -24,7 +24,19 @@ pub type RhythmIndicatorData = BarData<RhythmIndicatorValue>;
 impl RhythmIndicatorData {
     pub fn angle(&self) -> f32 {
         let bar_units = Units::from(self.value.signature);
-        -PI * 2.0 * (self.value.in_bar_pos.0 / bar_units.0)
+        -PI * 2.0 * self.value.in_bar_pos.ratio(&bar_units)
+    }
+    /// Only accent the first beats of groups, not every beat, e.g. beat 1 and 4 in 6/8.
+    pub fn in_group_start(&self) -> bool {
+        let signature = self.value.signature;
+        if !signature.is_grouped() {
+            return false;
+        }
+        let beat = self
+            .value
+            .in_bar_pos
+            .div_floor(&Units::from(signature.beat_unit));
+        beat >= 0 && signature.is_group_start(beat as u8)
     }
     pub fn shape(&self, theme: &NotationTheme) -> shapes::SvgPathShape {
         let width = self.value.bar_radius * theme.sizes.tab_control.rhythm_indicator_width_factor;
@@ -58,7 +70,11 @@ impl ShapeOp<NotationTheme, StrokePath> for RhythmIndicatorData {
             radius - width / 2.0,
             radius
         );
-        let color = theme.colors.rhythm.indicator;
+        let color = if self.in_group_start() {
+            theme.colors.rhythm.group_indicator
+        } else {
+            theme.colors.rhythm.indicator
+        };
         let line_width = theme.sizes.tab_control.rhythm_indicator_line_width;
         StrokePath {
             size: Vec2::new(radius * 2.0, radius * 2.0),
@@ -93,7 +109,7 @@ impl RhythmIndicatorData {
         let indicator_value = RhythmIndicatorValue {
             signature,
             bar_radius: 0.0,
-            in_bar_pos: Units(0.0),
+            in_bar_pos: Units::ZERO,
         };
         let indicator_data = RhythmIndicatorData {
             bar_props,
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use bevy::prelude::*;
 use unic_langid::LanguageIdentifier;
 use unic_langid::langid;
+use notation_model::prelude::{NamingSystem, TransposeStrategy};
 
 use crate::notation::args::NotationArgs;
 
@@ -20,7 +21,10 @@ pub struct NotationSettings {
     pub show_note_pitch: bool,
     pub show_note_syllable: bool,
     pub show_syllable_as_num: bool,
+    pub naming_system: NamingSystem,
+    pub show_chord_numeral: bool,
     pub always_show_fret: bool,
+    pub transpose_strategy: TransposeStrategy,
     pub allow_panning: bool,
     pub panning_line_size: f32,
     pub hide_guitar_view: bool,
@@ -52,7 +56,10 @@ impl FromWorld for NotationSettings {
             show_note_pitch: false,
             show_note_syllable: true,
             show_syllable_as_num: true,
+            naming_system: NamingSystem::default(),
+            show_chord_numeral: false,
             always_show_fret: false,
+            transpose_strategy: TransposeStrategy::default(),
             allow_panning: true,
             panning_line_size: 32.0,
             hide_shapes_lane: false,
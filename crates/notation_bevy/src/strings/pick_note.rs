@@ -24,6 +24,12 @@ impl PickNoteValue {
             bar_size: 0.0,
         }
     }
+    pub fn calc_fret_text(&self, fret: u8) -> String {
+        self.pick_note.techniques.to_fret_text(fret)
+    }
+    pub fn calc_techniques_text(&self) -> Option<String> {
+        self.pick_note.techniques.to_marks_text()
+    }
 }
 
 impl PickNoteData {
@@ -36,7 +42,7 @@ impl PickNoteData {
     }
     pub fn calc_width_height(&self, theme: &NotationTheme) -> (f32, f32) {
         let width =
-            self.value.bar_size / self.bar_props.bar_units.0 * self.entry_props.tied_units.0;
+            self.value.bar_size * self.entry_props.tied_units.ratio(&self.bar_props.bar_units);
         let mut height = theme.sizes.strings.note_height;
         let outline = self.calc_outline(theme);
         if self.value.playing_state.is_current() {
@@ -70,7 +76,7 @@ impl ShapeOp<NotationTheme, OutlineRectangle> for PickNoteData {
             BevyUtil::offscreen_offset()
         } else {
             let x =
-                self.value.bar_size / self.bar_props.bar_units.0 * self.entry_props.in_bar_pos.0;
+                self.value.bar_size * self.entry_props.in_bar_pos.ratio(&self.bar_props.bar_units);
             let y = theme
                 .sizes
                 .strings
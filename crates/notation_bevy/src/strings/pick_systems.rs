@@ -43,90 +43,95 @@ pub fn on_entry_playing_changed(
     }
 }
 
-macro_rules! impl_pick_system {
-    ($create_pick_notes:ident, $create_pick_tones:ident, $fretboard:ident, $hand_shape:ident, $get_fretted_shape:ident
-    ) => {
-        pub fn $create_pick_notes(
-            commands: &mut Commands,
-            assets: &NotationAssets,
-            theme: &NotationTheme,
-            settings: &NotationSettings,
-            entity: Entity,
-            entry: &LaneEntry,
-            pick: &Pick,
-        ) {
-            /* TODO: check whether is the first bar in row
-            if entry.as_ref().prev_is_tie() {
-                continue;
-            }
-            */
-            if let Some(bar) = entry.bar() {
-                if let Some((fretboard, shape)) = bar.$get_fretted_shape(entry) {
-                    let meta = bar.tab_meta();
-                    for pick_note in pick.get_notes() {
-                        if let Some((fret, note)) =
-                            fretboard.shape_pick_fret_note(&meta.scale, &meta.key, &shape, pick_note)
-                        {
-                            let syllable = bar.calc_syllable(&note.pitch);
-                            let data =
-                                PickNoteData::new(entry, PickNoteValue::new(pick_note, syllable));
-                            let note_entity = data.create(commands, theme, entity);
-                            if !settings.hide_strings_lane
-                                && (settings.always_show_fret || pick_note.fret.is_some())
-                            {
-                                theme.texts.strings.spawn_fret_text(
-                                    commands,
-                                    note_entity,
-                                    &assets,
-                                    fret,
-                                );
-                            }
+pub fn create_pick_notes(
+    commands: &mut Commands,
+    assets: &NotationAssets,
+    theme: &NotationTheme,
+    settings: &NotationSettings,
+    entity: Entity,
+    entry: &LaneEntry,
+    pick: &Pick,
+) {
+    /* TODO: check whether is the first bar in row
+    if entry.as_ref().prev_is_tie() {
+        continue;
+    }
+    */
+    if let Some(bar) = entry.bar() {
+        if let Some((fretboard, shape)) = bar.get_fretted_shape(entry) {
+            let meta = bar.tab_meta();
+            for (index, pick_note) in pick.get_notes().into_iter().enumerate() {
+                if let Some((fret, note)) =
+                    fretboard.shape_pick_fret_note(&meta.scale, &meta.key, &shape, pick_note)
+                {
+                    let syllable = bar.calc_syllable(&note.pitch);
+                    let value = PickNoteValue::new(pick_note, syllable);
+                    let fret_text = value.calc_fret_text(fret);
+                    let techniques_text = value.calc_techniques_text();
+                    let data = PickNoteData::new(entry, value);
+                    let note_entity = data.create(commands, theme, entity);
+                    if index == 0 && !settings.hide_strings_lane {
+                        theme.texts.strings.spawn_marks_text(
+                            commands,
+                            note_entity,
+                            &assets,
+                            entry,
+                        );
+                    }
+                    // Dead notes and harmonics are always marked on the fret.
+                    let techniques = pick_note.techniques;
+                    if !settings.hide_strings_lane
+                        && (settings.always_show_fret
+                            || pick_note.fret.is_some()
+                            || techniques.dead
+                            || techniques.harmonic.is_some())
+                    {
+                        theme.texts.strings.spawn_fret_text(
+                            commands,
+                            note_entity,
+                            &assets,
+                            fret_text.as_str(),
+                        );
+                    }
+                    if let Some(text) = techniques_text {
+                        if !settings.hide_strings_lane {
+                            theme.texts.strings.spawn_techniques_text(
+                                commands,
+                                note_entity,
+                                &assets,
+                                text.as_str(),
+                            );
                         }
                     }
                 }
             }
         }
-        pub fn $create_pick_tones(
-            commands: &mut Commands,
-            assets: &NotationAssets,
-            theme: &NotationTheme,
-            settings: &NotationSettings,
-            entity: Entity,
-            entry: &LaneEntry,
-            pick: &Pick,
-        ) {
-            /* TODO: check whether is the first bar in row
-            if entry.as_ref().prev_is_tie() {
-                continue;
-            }
-            */
-            if let Some(bar) = entry.bar() {
-                if let Some((fretboard, shape)) = bar.$get_fretted_shape(entry) {
-                    let meta = bar.tab_meta();
-                    let tone = fretboard.pick_tone(&meta.scale, &meta.key, &shape, pick);
-                    commands
-                        .entity(entity)
-                        .insert(ToneBundle::from(tone));
-                    crate::tone::tone_systems::create_tone_notes(
-                        commands, assets, theme, settings, ToneMode::Harmony, entity, entry, &tone,
-                    );
-                }
-            }
+    }
+}
+pub fn create_pick_tones(
+    commands: &mut Commands,
+    assets: &NotationAssets,
+    theme: &NotationTheme,
+    settings: &NotationSettings,
+    entity: Entity,
+    entry: &LaneEntry,
+    pick: &Pick,
+) {
+    /* TODO: check whether is the first bar in row
+    if entry.as_ref().prev_is_tie() {
+        continue;
+    }
+    */
+    if let Some(bar) = entry.bar() {
+        if let Some((fretboard, shape)) = bar.get_fretted_shape(entry) {
+            let meta = bar.tab_meta();
+            let tone = fretboard.pick_tone(&meta.scale, &meta.key, &shape, pick);
+            commands
+                .entity(entity)
+                .insert(ToneBundle::from(tone));
+            crate::tone::tone_systems::create_tone_notes(
+                commands, assets, theme, settings, ToneMode::Harmony, entity, entry, &tone,
+            );
         }
-    };
+    }
 }
-
-impl_pick_system!(
-    create_pick_notes6,
-    create_pick_tones6,
-    Fretboard6,
-    HandShape6,
-    get_fretted_shape6
-);
-impl_pick_system!(
-    create_pick_notes4,
-    create_pick_tones4,
-    Fretboard4,
-    HandShape4,
-    get_fretted_shape4
-);
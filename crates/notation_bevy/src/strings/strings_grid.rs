@@ -2,34 +2,39 @@ use bevy::prelude::*;
 
 use crate::prelude::NotationTheme;
 use notation_bevy_utils::prelude::ShapeOp;
-use notation_model::prelude::BarLane;
+use notation_model::prelude::{BarLane, GUITAR_STRING_NUM};
 
 use super::single_string::{SingleStringData, SingleStringValue};
 
-macro_rules! impl_strings_grid {
-    ($type:ident, $strings: literal) => {
-        #[derive(Debug, Default, Component)]
-        pub struct $type();
-
-        impl $type {
-            pub fn add_strings(
-                &self,
-                commands: &mut Commands,
-                theme: &NotationTheme,
-                entity: Entity,
-                lane: &BarLane,
-            ) {
-                for string in 1..=$strings {
-                    let data = SingleStringData::new(lane, SingleStringValue {
-                        string: string as u8,
-                        bar_size: 0.0,
-                    });
-                    data.create(commands, theme, entity);
-                }
-            }
+#[derive(Debug, Component)]
+pub struct StringsGrid {
+    pub string_num: usize,
+}
+impl Default for StringsGrid {
+    fn default() -> Self {
+        Self {
+            string_num: GUITAR_STRING_NUM,
         }
-    };
+    }
 }
 
-impl_strings_grid!(StringsGrid6, 6);
-impl_strings_grid!(StringsGrid4, 4);
+impl StringsGrid {
+    pub fn new(string_num: usize) -> Self {
+        Self { string_num }
+    }
+    pub fn add_strings(
+        &self,
+        commands: &mut Commands,
+        theme: &NotationTheme,
+        entity: Entity,
+        lane: &BarLane,
+    ) {
+        for string in 1..=self.string_num {
+            let data = SingleStringData::new(lane, SingleStringValue {
+                string: string as u8,
+                bar_size: 0.0,
+            });
+            data.create(commands, theme, entity);
+        }
+    }
+}
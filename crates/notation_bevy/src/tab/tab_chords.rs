@@ -81,6 +81,7 @@ impl TabChords {
         commands: &mut Commands,
         assets: &NotationAssets,
         theme: &NotationTheme,
+        settings: &NotationSettings,
         entity: Entity,
         tab: &Arc<Tab>,
         chords: &Vec<TabChord>,
@@ -89,7 +90,7 @@ impl TabChords {
         let view = view_bundle.view.clone();
         let chords_entity = BevyUtil::spawn_child_bundle(commands, entity, view_bundle);
         for chord_view in view.chords.iter() {
-            ChordView::spawn(commands, assets, theme, chords_entity, chord_view);
+            ChordView::spawn(commands, assets, theme, settings, chords_entity, chord_view);
         }
         chords_entity
     }
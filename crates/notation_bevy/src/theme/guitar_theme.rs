@@ -23,6 +23,13 @@ pub struct GuitarTheme {
     pub hit_string_seconds_range: (f32, f32),
     pub syllable_text: NoteTexts,
     pub syllable_base_width: f32,
+    pub finger_font_size: f32,
+    pub finger_font_color: Color,
+    pub finger_text_x: f32,
+    pub finger_text_y: f32,
+    pub tuning_font_size: f32,
+    pub tuning_font_color: Color,
+    pub tuning_y_factor: f32,
 }
 
 impl Default for GuitarTheme {
@@ -55,8 +62,16 @@ impl Default for GuitarTheme {
                 horizontal_center: true,
                 syllable_font_size: 18.0,
                 syllable_font_color: Color::hex("FFFFFF").unwrap(),
+                ..Default::default()
             },
             syllable_base_width: 256.0,
+            finger_font_size: 16.0,
+            finger_font_color: Color::hex("FFFF00").unwrap(),
+            finger_text_x: 0.0,
+            finger_text_y: -24.0,
+            tuning_font_size: 14.0,
+            tuning_font_color: Color::hex("FFFFFF").unwrap(),
+            tuning_y_factor: 0.488,
         }
     }
 }
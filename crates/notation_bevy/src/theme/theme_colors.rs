@@ -1,5 +1,5 @@
 use notation_model::prelude::{
-    Chord, IntervalQuality, Octave, PlayingState, Semitones, Signature, Syllable,
+    Chord, Interval, IntervalQuality, Octave, PlayingState, Semitones, Signature, Syllable,
 };
 use serde::{Deserialize, Serialize};
 
@@ -45,6 +45,7 @@ pub struct IntervalColors {
     pub augmented: Color,
     pub diminished: Color,
     pub tritone: Color,
+    pub extended: Color,
 }
 impl IntervalColors {
     pub fn of_quality(&self, quality: &IntervalQuality) -> Color {
@@ -57,6 +58,13 @@ impl IntervalColors {
             IntervalQuality::Tritone => self.tritone,
         }
     }
+    // Natural 9th, 11th and 13th got their own color, altered ones are colored by quality.
+    pub fn of_interval(&self, interval: &Interval) -> Color {
+        match interval {
+            Interval::Major9th | Interval::Perfect11th | Interval::Major13th => self.extended,
+            _ => self.of_quality(&(*interval).into()),
+        }
+    }
 }
 impl Default for IntervalColors {
     fn default() -> Self {
@@ -67,6 +75,7 @@ impl Default for IntervalColors {
             augmented: hex_linear("FF00FFAA"),
             diminished: hex_linear("66666644"),
             tritone: hex_linear("FF00FFAA"),
+            extended: hex_linear("00FFFFAA"),
         }
     }
 }
@@ -135,6 +144,9 @@ pub struct BarColors {
     pub beat_color0: Color,
     pub beat_color1: Color,
     pub beat_color2: Color,
+    pub tuplet_beat_color: Color,
+    pub tuplet_color: Color,
+    pub dot_color: Color,
     pub pos_indicator_color: Color,
     pub line_color: Color,
 }
@@ -149,6 +161,9 @@ impl Default for BarColors {
             beat_color0: ThemeColors::hex_linear("00000000"),
             beat_color1: ThemeColors::hex_linear("00000010"),
             beat_color2: ThemeColors::hex_linear("00000000"),
+            tuplet_beat_color: ThemeColors::hex_linear("4A90D920"),
+            tuplet_color: ThemeColors::hex_linear("4A90D9AA"),
+            dot_color: ThemeColors::hex_linear("000000AA"),
             pos_indicator_color: ThemeColors::hex_linear("00000077"),
             line_color: ThemeColors::hex_linear("79554844"),
         }
@@ -185,6 +200,13 @@ impl BarColors {
         if beat == 0 {
             return color0;
         }
+        if signature.is_grouped() {
+            return if signature.is_group_start(beat) {
+                color2
+            } else {
+                color1
+            };
+        }
         if signature.bar_beats % 4 == 0 {
             match beat % 4 {
                 1 => color1,
@@ -231,6 +253,7 @@ impl Default for ChordColors {
                 augmented: hex_linear("FF00FF"),
                 diminished: hex_linear("333333"),
                 tritone: hex_linear("FF00FF"),
+                extended: hex_linear("00FFFF"),
             },
         }
     }
@@ -317,7 +340,10 @@ impl Default for SectionColors {
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
 pub struct RhythmColors {
     pub beats: [Color; 3],
+    pub tuplet_beat: Color,
     pub indicator: Color,
+    /// Used in the first beat of beat groups, e.g. 6/8 or 7/8 as 2+2+3.
+    pub group_indicator: Color,
 }
 impl Default for RhythmColors {
     fn default() -> Self {
@@ -327,7 +353,9 @@ impl Default for RhythmColors {
                 hex_linear("00000099"),
                 hex_linear("00000088"),
             ],
+            tuplet_beat: hex_linear("4A90D9AA"),
             indicator: hex_linear("00000077"),
+            group_indicator: hex_linear("000000CC"),
         }
     }
 }
@@ -337,6 +365,13 @@ impl RhythmColors {
         if beat == 0 {
             return self.beats[0];
         }
+        if signature.is_grouped() {
+            return if signature.is_group_start(beat) {
+                self.beats[2]
+            } else {
+                self.beats[1]
+            };
+        }
         if signature.bar_beats % 4 == 0 {
             match beat % 4 {
                 1 => self.beats[1],
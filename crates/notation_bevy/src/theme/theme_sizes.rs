@@ -1,5 +1,7 @@
 use notation_bevy_utils::prelude::LayoutSize;
-use notation_model::prelude::{LaneKind, Note, PlayingState, Semitones, Tab, TrackKind};
+use notation_model::prelude::{
+    LaneKind, Note, PlayingState, Semitones, Tab, TrackKind, GUITAR_STRING_NUM,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::prelude::NotationSettings;
@@ -62,6 +64,12 @@ pub struct BarSizes {
     pub bar_separator_extra: f32,
     pub bar_separator_size: f32,
     pub bar_beat_extra: f32,
+    pub tuplet_y: f32,
+    pub tuplet_height: f32,
+    pub tuplet_line_width: f32,
+    pub dot_radius: f32,
+    pub dot_x: f32,
+    pub dot_y: f32,
     pub pos_indicator_size: f32,
     pub pos_indicator_outline: f32,
     pub pos_indicator_extra: f32,
@@ -75,6 +83,12 @@ impl Default for BarSizes {
             bar_separator_extra: 3.0,
             bar_separator_size: 3.0,
             bar_beat_extra: 0.0,
+            tuplet_y: 4.0,
+            tuplet_height: 4.0,
+            tuplet_line_width: 1.0,
+            dot_radius: 1.5,
+            dot_x: 4.0,
+            dot_y: 4.0,
             pos_indicator_size: 2.0,
             pos_indicator_outline: 0.5,
             pos_indicator_extra: 8.0,
@@ -193,9 +207,9 @@ impl NotesSizes {
         for bar in tab.bars.iter() {
             if let Some(lane) = bar.get_lane_of_kind(LaneKind::Strings, track_index) {
                 for entry in lane.entries.iter() {
-                    if let Some(fretted_entry) = entry.model.proto.as_fretted6() {
+                    if let Some(fretted_entry) = entry.model.proto.as_fretted() {
                         if let Some(pick) = fretted_entry.as_pick() {
-                            if let Some((fretboard, shape)) = bar.get_fretted_shape6(entry) {
+                            if let Some((fretboard, shape)) = bar.get_fretted_shape(entry) {
                                 let tone = fretboard.pick_tone(&tab.meta.scale, &tab.meta.key, &shape, pick);
                                 for note in tone.get_notes() {
                                     let v = Semitones::from(note);
@@ -253,6 +267,7 @@ impl LyricsSizes {
 
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
 pub struct StringsSizes {
+    pub string_num: usize,
     pub string_space: f32,
     pub note_height: f32,
     pub note_outline: PlayingSize,
@@ -260,6 +275,7 @@ pub struct StringsSizes {
 impl Default for StringsSizes {
     fn default() -> Self {
         Self {
+            string_num: GUITAR_STRING_NUM,
             string_space: 12.0,
             note_height: 6.0,
             note_outline: PlayingSize::new(1.0, 1.5, 1.0),
@@ -267,8 +283,18 @@ impl Default for StringsSizes {
     }
 }
 impl StringsSizes {
+    /// Makes room for the fretboard with most strings in the tab.
+    pub fn update_with_tab(&mut self, tab: &Tab) {
+        self.string_num = tab
+            .tracks
+            .iter()
+            .filter_map(|x| x.get_fretboard())
+            .map(|x| x.string_num())
+            .max()
+            .unwrap_or(GUITAR_STRING_NUM);
+    }
     pub fn layout_height(&self) -> f32 {
-        self.string_space * 6.0
+        self.string_space * self.string_num as f32
     }
     pub fn calc_string_y(&self, string: u8) -> f32 {
         -1.0 * self.string_space * (string as f32 - 0.5)
@@ -1,5 +1,5 @@
 use notation_bevy_utils::prelude::{BevyUtil, LayoutData};
-use notation_model::prelude::{Syllable, Scale, Key};
+use notation_model::prelude::{Syllable, Scale, Key, Note, LaneEntry};
 use serde::{Deserialize, Serialize};
 
 use bevy::prelude::*;
@@ -39,6 +39,9 @@ pub struct TabTexts {
     pub bar_font_color: Color,
     pub bar_x: f32,
     pub bar_y: f32,
+    pub tuplet_font_size: f32,
+    pub tuplet_font_color: Color,
+    pub tuplet_y: f32,
 }
 impl Default for TabTexts {
     fn default() -> Self {
@@ -47,6 +50,9 @@ impl Default for TabTexts {
             bar_font_color: ThemeColors::hex_linear("00000066"),
             bar_x: -6.0,
             bar_y: -6.0,
+            tuplet_font_size: 14.0,
+            tuplet_font_color: ThemeColors::hex_linear("4A90D9"),
+            tuplet_y: 8.0,
         }
     }
 }
@@ -72,6 +78,27 @@ impl TabTexts {
             3.0,
         );
     }
+    pub fn spawn_tuplet_number(
+        &self,
+        commands: &mut Commands,
+        assets: &NotationAssets,
+        entity: Entity,
+        text: &str,
+    ) {
+        BevyUtil::spawn_text(
+            commands,
+            entity,
+            text,
+            assets.latin_font.clone(),
+            self.tuplet_font_size,
+            self.tuplet_font_color,
+            HorizontalAlign::Center,
+            VerticalAlign::Center,
+            0.0,
+            self.tuplet_y,
+            1.0,
+        );
+    }
     pub fn update_bar_number_x(&self, transform: &mut Transform, bar_width: f32) {
         transform.translation.x = bar_width + self.bar_x;
     }
@@ -83,6 +110,13 @@ pub struct ChordTexts {
     pub bars_font_color: Color,
     pub bars_x: f32,
     pub bars_y: f32,
+    pub name_font_size: f32,
+    pub name_font_color: Color,
+    pub name_y: f32,
+    pub numeral_font_size: f32,
+    pub numeral_font_color: Color,
+    pub numeral_x: f32,
+    pub numeral_y: f32,
 }
 impl Default for ChordTexts {
     fn default() -> Self {
@@ -91,6 +125,13 @@ impl Default for ChordTexts {
             bars_font_color: ThemeColors::hex_linear("FFFFFF"),
             bars_x: 2.0,
             bars_y: -2.0,
+            name_font_size: 18.0,
+            name_font_color: ThemeColors::hex_linear("FFFFFF"),
+            name_y: 2.0,
+            numeral_font_size: 16.0,
+            numeral_font_color: ThemeColors::hex_linear("FFFFFF"),
+            numeral_x: -2.0,
+            numeral_y: -2.0,
         }
     }
 }
@@ -121,6 +162,58 @@ impl ChordTexts {
         transform.translation.x = -layout.size.width / 2.0 + self.bars_x;
         transform.translation.y = layout.size.height / 2.0 + self.bars_y;
     }
+    pub fn spawn_name_text(
+        &self,
+        commands: &mut Commands,
+        assets: &NotationAssets,
+        entity: Entity,
+        text: &str,
+        z: f32,
+    ) -> Entity {
+        BevyUtil::spawn_text(
+            commands,
+            entity,
+            text,
+            assets.latin_font.clone(),
+            self.name_font_size,
+            self.name_font_color,
+            HorizontalAlign::Center,
+            VerticalAlign::Bottom,
+            0.0,
+            self.name_y,
+            z,
+        )
+    }
+    pub fn update_name_xy(&self, transform: &mut Transform, layout: &LayoutData) {
+        transform.translation.x = 0.0;
+        transform.translation.y = -layout.size.height / 2.0 + self.name_y;
+    }
+    pub fn spawn_numeral_text(
+        &self,
+        commands: &mut Commands,
+        assets: &NotationAssets,
+        entity: Entity,
+        text: &str,
+        z: f32,
+    ) -> Entity {
+        BevyUtil::spawn_text(
+            commands,
+            entity,
+            text,
+            assets.latin_font.clone(),
+            self.numeral_font_size,
+            self.numeral_font_color,
+            HorizontalAlign::Right,
+            VerticalAlign::Top,
+            self.numeral_x,
+            self.numeral_y,
+            z,
+        )
+    }
+    pub fn update_numeral_xy(&self, transform: &mut Transform, layout: &LayoutData) {
+        transform.translation.x = layout.size.width / 2.0 + self.numeral_x;
+        transform.translation.y = layout.size.height / 2.0 + self.numeral_y;
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
@@ -213,6 +306,8 @@ pub struct NoteTexts {
     pub horizontal_center: bool,
     pub syllable_font_size: f32,
     pub syllable_font_color: Color,
+    pub marks_y: f32,
+    pub marks_font_size: f32,
 }
 impl Default for NoteTexts {
     fn default() -> Self {
@@ -223,6 +318,8 @@ impl Default for NoteTexts {
             horizontal_center: false,
             syllable_font_size: 16.0,
             syllable_font_color: Color::hex("000000").unwrap(),
+            marks_y: 8.0,
+            marks_font_size: 14.0,
         }
     }
 }
@@ -231,6 +328,7 @@ impl NoteTexts {
         Self {
             text_y: 7.0,
             syllable_font_size: 18.0,
+            marks_y: 24.0,
             ..Default::default()
         }
     }
@@ -243,8 +341,9 @@ impl NoteTexts {
         scale: &Scale,
         key: &Key,
         syllable: &Syllable,
+        note: Option<&Note>,
     ) {
-        self.spawn_scaled_note_text(commands, entity, assets, settings, scale, key, syllable, 1.0);
+        self.spawn_scaled_note_text(commands, entity, assets, settings, scale, key, syllable, note, 1.0);
     }
     pub fn spawn_scaled_note_text(
         &self,
@@ -255,9 +354,10 @@ impl NoteTexts {
         scale: &Scale,
         key: &Key,
         syllable: &Syllable,
+        note: Option<&Note>,
         size_scale: f32,
     ) {
-        let text = Self::calc_text(settings, scale, key, syllable);
+        let text = Self::calc_text(settings, scale, key, syllable, note);
         BevyUtil::spawn_text(
             commands,
             entity,
@@ -276,29 +376,68 @@ impl NoteTexts {
             self.text_z,
         );
     }
+    /// Dynamics and articulations before the entry, e.g. "p cresc. >".
+    pub fn calc_marks_text(entry: &LaneEntry) -> Option<String> {
+        let model = entry.model();
+        let mut marks: Vec<String> = model.get_dynamics().iter().map(|x| x.to_text()).collect();
+        marks.extend(model.get_articulations().iter().map(|x| x.to_symbol()));
+        if marks.is_empty() {
+            None
+        } else {
+            Some(marks.join(" "))
+        }
+    }
+    pub fn spawn_marks_text(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        assets: &NotationAssets,
+        entry: &LaneEntry,
+    ) {
+        if let Some(text) = Self::calc_marks_text(entry) {
+            BevyUtil::spawn_text(
+                commands,
+                entity,
+                text.as_str(),
+                assets.latin_font.clone(),
+                self.marks_font_size,
+                self.syllable_font_color,
+                HorizontalAlign::Left,
+                VerticalAlign::Center,
+                self.text_x,
+                self.marks_y,
+                self.text_z,
+            );
+        }
+    }
+    /// Note is only needed for the octave dots in jianpu.
     pub fn calc_text(
         settings: &NotationSettings,
         scale: &Scale,
         key: &Key,
         syllable: &Syllable,
+        note: Option<&Note>,
     ) -> String {
+        let naming_system = settings.naming_system;
+        let show_note_syllable = settings.show_note_syllable
+            && !(settings.show_note_pitch && naming_system.is_pitch_name());
         let pitch_text = if settings.show_note_pitch {
-            Some(scale.calc_pitch(key, syllable).to_text())
+            Some(if naming_system.is_pitch_name() {
+                naming_system.calc_text(scale, key, syllable, note, settings.show_syllable_as_num)
+            } else {
+                scale.calc_pitch(key, syllable).to_text()
+            })
         } else {
             None
         };
-        let syllable_text = if settings.show_note_syllable {
-            Some(if settings.show_syllable_as_num {
-                syllable.to_text()
-            } else {
-                syllable.to_ident()
-            })
+        let syllable_text = if show_note_syllable {
+            Some(naming_system.calc_text(scale, key, syllable, note, settings.show_syllable_as_num))
         } else {
             None
         };
         format!("{}{}{}",
             pitch_text.unwrap_or("".to_owned()),
-            if settings.show_note_pitch && settings.show_note_syllable { " " } else { "" },
+            if settings.show_note_pitch && show_note_syllable { " " } else { "" },
             syllable_text.unwrap_or("".to_owned())
         )
     }
@@ -311,6 +450,10 @@ pub struct StringsTexts {
     pub text_z: f32,
     pub fret_font_size: f32,
     pub fret_font_color: Color,
+    pub marks_y: f32,
+    pub marks_font_size: f32,
+    pub techniques_y: f32,
+    pub techniques_font_size: f32,
 }
 impl Default for StringsTexts {
     fn default() -> Self {
@@ -320,22 +463,48 @@ impl Default for StringsTexts {
             text_z: 1.0,
             fret_font_size: 18.0,
             fret_font_color: super::theme_colors::hex_linear("000000"),
+            marks_y: 14.0,
+            marks_font_size: 14.0,
+            techniques_y: -16.0,
+            techniques_font_size: 12.0,
         }
     }
 }
 impl StringsTexts {
+    pub fn spawn_marks_text(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        assets: &NotationAssets,
+        entry: &LaneEntry,
+    ) {
+        if let Some(text) = NoteTexts::calc_marks_text(entry) {
+            BevyUtil::spawn_text(
+                commands,
+                entity,
+                text.as_str(),
+                assets.latin_font.clone(),
+                self.marks_font_size,
+                self.fret_font_color,
+                HorizontalAlign::Left,
+                VerticalAlign::Center,
+                self.text_x,
+                self.marks_y,
+                self.text_z,
+            );
+        }
+    }
     pub fn spawn_fret_text(
         &self,
         commands: &mut Commands,
         entity: Entity,
         assets: &NotationAssets,
-        fret: u8,
+        text: &str,
     ) {
-        let text = format!("{}", fret);
         BevyUtil::spawn_text(
             commands,
             entity,
-            text.as_str(),
+            text,
             assets.fret_font.clone(),
             self.fret_font_size,
             self.fret_font_color,
@@ -346,6 +515,27 @@ impl StringsTexts {
             self.text_z,
         );
     }
+    pub fn spawn_techniques_text(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        assets: &NotationAssets,
+        text: &str,
+    ) {
+        BevyUtil::spawn_text(
+            commands,
+            entity,
+            text,
+            assets.latin_font.clone(),
+            self.techniques_font_size,
+            self.fret_font_color,
+            HorizontalAlign::Left,
+            VerticalAlign::Center,
+            self.text_x,
+            self.techniques_y,
+            self.text_z,
+        );
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
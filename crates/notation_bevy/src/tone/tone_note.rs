@@ -47,7 +47,7 @@ impl ShapeOp<NotationTheme, OutlineRectangle> for ToneNoteData {
             BevyUtil::offscreen_offset()
         } else {
             let x =
-                self.value.bar_size / self.bar_props.bar_units.0 * self.entry_props.in_bar_pos.0;
+                self.value.bar_size * self.entry_props.in_bar_pos.ratio(&self.bar_props.bar_units);
             let mut y = self.get_sizes(theme).calc_note_y(self.value.note);
             if self.value.playing_state.is_current() {
                 let outline = self.calc_outline(theme);
@@ -89,7 +89,7 @@ impl ToneNoteData {
     fn calc_width_height(&self, theme: &NotationTheme) -> (f32, f32) {
         let outline = self.calc_outline(theme);
         let width =
-            self.value.bar_size / self.bar_props.bar_units.0 * self.entry_props.tied_units.0;
+            self.value.bar_size * self.entry_props.tied_units.ratio(&self.bar_props.bar_units);
         let mut height = self.get_sizes(theme).note_height;
         if self.value.playing_state.is_current() {
             height += outline;
@@ -27,9 +27,18 @@ pub fn create_tone_notes(
         let mode: ToneMode = lane.kind.into();
         let bar = lane.bar().unwrap();
         let meta = bar.tab_meta();
-        for note in tone.get_notes() {
+        for (index, note) in tone.get_notes().into_iter().enumerate() {
             let data = ToneNoteData::new(entry, ToneNoteValue::new(&bar, note, mode));
             let note_entity = data.create(commands, theme, entity);
+            if index == 0 && !entry.prev_is_tie() {
+                if let Some(text) = match tone_mode {
+                    ToneMode::Melody => Some(theme.texts.melody),
+                    ToneMode::Harmony => Some(theme.texts.harmony),
+                    _ => None,
+                } {
+                    text.spawn_marks_text(commands, note_entity, assets, entry);
+                }
+            }
             if settings.show_note_text() && !entry.prev_is_tie() {
                 if let Some(text) = match tone_mode {
                     ToneMode::Melody => Some(theme.texts.melody),
@@ -43,6 +52,7 @@ pub fn create_tone_notes(
                         settings,
                         &meta.scale, &meta.key,
                         &data.value.syllable(),
+                        Some(&data.value.note),
                     )
                 }
             }
@@ -0,0 +1,72 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{TempoChange, Units};
+
+// https://en.wikipedia.org/wiki/Articulation_(music)
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum Articulation {
+    Accent,
+    Staccato,
+    Tenuto,
+    /// Held longer, played as a tempo change over the note.
+    Fermata,
+    /// Barely audible, usually written in parentheses.
+    Ghost,
+}
+impl Display for Articulation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl Articulation {
+    /// Time stretch of a fermata, in percentage as in `TempoChange::Fermata`.
+    pub const FERMATA_PERCENT: u16 = 200;
+    pub const ALL: [Articulation; 5] = [
+        Articulation::Accent,
+        Articulation::Staccato,
+        Articulation::Tenuto,
+        Articulation::Fermata,
+        Articulation::Ghost,
+    ];
+    pub fn to_ident(&self) -> String {
+        format!("{:?}", self)
+    }
+    pub fn from_ident(ident: &str) -> Option<Self> {
+        Self::ALL.iter().find(|x| x.to_ident() == ident).copied()
+    }
+    /// Short mark drawn above the note.
+    pub fn to_symbol(&self) -> String {
+        match self {
+            Articulation::Accent => ">",
+            Articulation::Staccato => "·",
+            Articulation::Tenuto => "–",
+            Articulation::Fermata => "⌒",
+            Articulation::Ghost => "( )",
+        }
+        .to_owned()
+    }
+    /// Scale of the velocity on top of the dynamics.
+    pub fn velocity_factor(&self) -> f32 {
+        match self {
+            Articulation::Accent => 1.3,
+            Articulation::Ghost => 0.35,
+            _ => 1.0,
+        }
+    }
+    /// Scale of how long the note is sounding, against the written duration.
+    pub fn length_factor(&self) -> f32 {
+        match self {
+            Articulation::Staccato => 0.5,
+            _ => 1.0,
+        }
+    }
+    /// Change of the tempo over the span of the note.
+    pub fn tempo_change(&self, span: Units) -> Option<TempoChange> {
+        match self {
+            Articulation::Fermata => Some(TempoChange::Fermata(span, Self::FERMATA_PERCENT)),
+            _ => None,
+        }
+    }
+}
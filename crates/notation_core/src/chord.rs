@@ -1,10 +1,12 @@
 use std::fmt::Display;
 use std::hash::Hash;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
 use crate::interval::Interval;
-use crate::prelude::{Intervals, Semitones, Syllable};
+use crate::parse_error::ParseTextError;
+use crate::prelude::{CapacityError, Intervals, Key, Note, Octave, Scale, Semitones, Syllable};
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Debug)]
 pub struct Chord {
@@ -14,11 +16,7 @@ pub struct Chord {
 }
 impl Display for Chord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let bass = match self.bass {
-            Some(base) => format!(" /{}", base),
-            None => "".to_owned(),
-        };
-        write!(f, "<Chord>({}: {}{})", self.root, self.intervals, bass)
+        write!(f, "{}", self.to_text())
     }
 }
 impl Chord {
@@ -29,6 +27,17 @@ impl Chord {
             bass,
         }
     }
+    /// Same as in the tab files, root and intervals, then the bass if any, e.g. `6: 3- 5 /4`.
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}:", self.root);
+        for interval in self.intervals.get_intervals() {
+            text.push_str(&format!(" {}", interval));
+        }
+        if let Some(bass) = self.bass {
+            text.push_str(&format!(" /{}", bass));
+        }
+        text
+    }
     pub fn calc_interval(&self, syllable: Syllable) -> Option<Interval> {
         if Semitones::from(self.root) == Semitones::from(syllable) {
             return Some(Interval::Unison);
@@ -45,7 +54,56 @@ impl Chord {
         }
         None
     }
+    /// Root is put in the given octave, the bass below it, and each interval is
+    /// stacked above the previous one, so quartal and extended chords keep their voicing.
+    pub fn calc_notes(&self, scale: &Scale, key: &Key, octave: &Octave) -> Vec<Note> {
+        let root = Semitones::from(*octave) + scale.calc_do_semitones(key) + self.root.into();
+        let mut semitones = Vec::new();
+        if let Some(bass) = self.bass {
+            let mut bass = root + bass.into();
+            while bass >= root {
+                bass = bass - Semitones(12);
+            }
+            semitones.push(bass);
+        }
+        semitones.push(root);
+        let mut last = root;
+        for interval in self.intervals.get_intervals() {
+            let mut value = root + interval.into();
+            while value <= last {
+                value = value + Semitones(12);
+            }
+            semitones.push(value);
+            last = value;
+        }
+        semitones
+            .into_iter()
+            .map(|x| scale.calc_note_from_semitones(key, x))
+            .collect()
+    }
 }
+/// Parses the text from `to_text()`.
+impl FromStr for Chord {
+    type Err = ParseTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseTextError::new("Chord", s);
+        let (root, rest) = s.split_once(':').ok_or_else(err)?;
+        let root = root.parse::<Syllable>().map_err(|_| err())?;
+        let (rest, bass) = match rest.split_once('/') {
+            Some((rest, bass)) => (rest, Some(bass.parse::<Interval>().map_err(|_| err())?)),
+            None => (rest, None),
+        };
+        let intervals = rest
+            .split_whitespace()
+            .map(|x| x.parse::<Interval>())
+            .collect::<Result<Vec<Interval>, ParseTextError>>()
+            .map_err(|_| err())?;
+        let intervals = Intervals::try_from(intervals).map_err(|_| err())?;
+        Ok(Self::new(root, intervals, bass))
+    }
+}
+
 impl Hash for Chord {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         let string = self.to_string();
@@ -59,9 +117,11 @@ impl From<(Syllable, Intervals)> for Chord {
     }
 }
 
-impl From<(Syllable, Vec<Interval>)> for Chord {
-    fn from(v: (Syllable, Vec<Interval>)) -> Self {
-        Self::new(v.0, v.1.into(), None)
+impl TryFrom<(Syllable, Vec<Interval>)> for Chord {
+    type Error = CapacityError;
+
+    fn try_from(v: (Syllable, Vec<Interval>)) -> Result<Self, Self::Error> {
+        Ok(Self::new(v.0, v.1.try_into()?, None))
     }
 }
 
@@ -71,8 +131,19 @@ impl From<(Syllable, Intervals, Interval)> for Chord {
     }
 }
 
-impl From<(Syllable, Vec<Interval>, Interval)> for Chord {
-    fn from(v: (Syllable, Vec<Interval>, Interval)) -> Self {
-        Self::new(v.0, v.1.into(), Some(v.2))
+impl TryFrom<(Syllable, Vec<Interval>, Interval)> for Chord {
+    type Error = CapacityError;
+
+    fn try_from(v: (Syllable, Vec<Interval>, Interval)) -> Result<Self, Self::Error> {
+        Ok(Self::new(v.0, v.1.try_into()?, Some(v.2)))
+    }
+}
+
+#[test]
+fn test_chord_text_round_trip() {
+    for text in ["1: 3 5", "6: 3- 5", "5: 3 5 7-", "1: 4 5 /5", "2: 3- 5o 7- /3-", "1:"] {
+        let chord = text.parse::<Chord>().unwrap();
+        assert_eq!(chord.to_text(), text);
+        assert_eq!(chord.to_string().parse::<Chord>(), Ok(chord));
     }
 }
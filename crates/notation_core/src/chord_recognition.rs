@@ -0,0 +1,209 @@
+use crate::prelude::{Chord, Interval, Intervals, Key, Note, Scale, Semitones, Syllable, Tone};
+
+// https://en.wikipedia.org/wiki/Chord_names_and_symbols_(popular_music)
+// Templates are listed from most to least common, the order is used to break ties.
+const CHORD_TEMPLATES: [Intervals; 27] = [
+    Intervals::new(&[Interval::Major3nd, Interval::Perfect5th]),
+    Intervals::new(&[Interval::Minor3nd, Interval::Perfect5th]),
+    Intervals::new(&[Interval::Major3nd, Interval::Perfect5th, Interval::Minor7th]),
+    Intervals::new(&[Interval::Minor3nd, Interval::Perfect5th, Interval::Minor7th]),
+    Intervals::new(&[Interval::Major3nd, Interval::Perfect5th, Interval::Major7th]),
+    Intervals::new(&[Interval::Perfect4th, Interval::Perfect5th]),
+    Intervals::new(&[Interval::Major2nd, Interval::Perfect5th]),
+    Intervals::new(&[Interval::Minor3nd, Interval::Diminished5th]),
+    Intervals::new(&[
+        Interval::Minor3nd,
+        Interval::Diminished5th,
+        Interval::Minor7th,
+    ]),
+    Intervals::new(&[
+        Interval::Minor3nd,
+        Interval::Diminished5th,
+        Interval::Diminished7th,
+    ]),
+    Intervals::new(&[Interval::Major3nd, Interval::Augmented5th]),
+    Intervals::new(&[
+        Interval::Perfect4th,
+        Interval::Perfect5th,
+        Interval::Minor7th,
+    ]),
+    Intervals::new(&[Interval::Major3nd, Interval::Perfect5th, Interval::Major9th]),
+    Intervals::new(&[Interval::Minor3nd, Interval::Perfect5th, Interval::Major9th]),
+    Intervals::new(&[Interval::Major3nd, Interval::Perfect5th, Interval::Major6th]),
+    Intervals::new(&[Interval::Minor3nd, Interval::Perfect5th, Interval::Major6th]),
+    Intervals::new(&[Interval::Minor3nd, Interval::Perfect5th, Interval::Major7th]),
+    Intervals::new(&[
+        Interval::Major3nd,
+        Interval::Perfect5th,
+        Interval::Minor7th,
+        Interval::Major9th,
+    ]),
+    Intervals::new(&[
+        Interval::Minor3nd,
+        Interval::Perfect5th,
+        Interval::Minor7th,
+        Interval::Major9th,
+    ]),
+    Intervals::new(&[
+        Interval::Major3nd,
+        Interval::Perfect5th,
+        Interval::Major7th,
+        Interval::Major9th,
+    ]),
+    Intervals::new(&[
+        Interval::Major3nd,
+        Interval::Perfect5th,
+        Interval::Major6th,
+        Interval::Major9th,
+    ]),
+    Intervals::new(&[
+        Interval::Major3nd,
+        Interval::Perfect5th,
+        Interval::Minor7th,
+        Interval::Minor9th,
+    ]),
+    Intervals::new(&[
+        Interval::Major3nd,
+        Interval::Perfect5th,
+        Interval::Minor7th,
+        Interval::Augmented9th,
+    ]),
+    Intervals::new(&[
+        Interval::Minor3nd,
+        Interval::Perfect5th,
+        Interval::Minor7th,
+        Interval::Major9th,
+        Interval::Perfect11th,
+    ]),
+    Intervals::new(&[
+        Interval::Major3nd,
+        Interval::Perfect5th,
+        Interval::Minor7th,
+        Interval::Major9th,
+        Interval::Major13th,
+    ]),
+    Intervals::new(&[Interval::Perfect4th, Interval::Minor7th]),
+    Intervals::new(&[Interval::Perfect5th]),
+];
+
+fn pitch_class(v: Semitones) -> i8 {
+    v.0.rem_euclid(12)
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ChordCandidate {
+    pub chord: Chord,
+    pub score: i32,
+}
+
+impl Chord {
+    pub fn recognize_tone(tone: &Tone, key: &Key, scale: &Scale) -> Vec<ChordCandidate> {
+        Self::recognize_notes(&tone.get_notes(), key, scale)
+    }
+    pub fn recognize_notes(notes: &[Note], key: &Key, scale: &Scale) -> Vec<ChordCandidate> {
+        let bass_note = notes.iter().min_by_key(|x| Semitones::from(**x));
+        let bass = match bass_note {
+            Some(note) => scale.calc_syllable(key, &note.pitch),
+            None => return vec![],
+        };
+        let mut syllables: Vec<Syllable> = Vec::new();
+        for note in notes.iter() {
+            let syllable = scale.calc_syllable(key, &note.pitch);
+            if !syllables
+                .iter()
+                .any(|x| pitch_class(Semitones::from(*x)) == pitch_class(Semitones::from(syllable)))
+            {
+                syllables.push(syllable);
+            }
+        }
+        let mut candidates = Vec::new();
+        for root in syllables.iter() {
+            for (index, template) in CHORD_TEMPLATES.iter().enumerate() {
+                if let Some(candidate) =
+                    Self::match_template(scale, *root, bass, &syllables, *template, index)
+                {
+                    candidates.push(candidate);
+                }
+            }
+        }
+        if candidates.is_empty() {
+            candidates.push(Self::fallback_candidate(bass, &syllables));
+        }
+        candidates.sort_by_key(|x| std::cmp::Reverse(x.score));
+        candidates
+    }
+    pub fn recognize_best(notes: &[Note], key: &Key, scale: &Scale) -> Option<Chord> {
+        Self::recognize_notes(notes, key, scale)
+            .first()
+            .map(|x| x.chord)
+    }
+    fn match_template(
+        scale: &Scale,
+        root: Syllable,
+        bass: Syllable,
+        syllables: &[Syllable],
+        template: Intervals,
+        template_index: usize,
+    ) -> Option<ChordCandidate> {
+        let intervals = template.get_intervals();
+        let chord = Chord::new(root, template, None);
+        let mut extra = None;
+        for syllable in syllables.iter() {
+            if chord.calc_interval(*syllable).is_none() {
+                if extra.is_some() || Semitones::from(*syllable) != Semitones::from(bass) {
+                    return None;
+                }
+                extra = Some(Interval::from((root, *syllable)));
+            }
+        }
+        let mut missing = 0;
+        for interval in intervals.iter() {
+            if !syllables.iter().any(|x| interval.is_matched(root, *x)) {
+                if *interval != Interval::Perfect5th || intervals.len() < 2 {
+                    return None;
+                }
+                missing += 1;
+            }
+        }
+        let bass_interval =
+            if pitch_class(Semitones::from(root)) == pitch_class(Semitones::from(bass)) {
+                None
+            } else if let Some(interval) = extra {
+                Some(interval)
+            } else {
+                chord.calc_interval(bass)
+            };
+        let mut score = 100 - template_index as i32 - missing * 20;
+        match (bass_interval, extra) {
+            (None, _) => score += 30,
+            (Some(_), None) => score += 10,
+            (Some(_), Some(_)) => score -= 20,
+        }
+        if Self::is_diatonic(scale, root) {
+            score += 5;
+        }
+        Some(ChordCandidate {
+            chord: Chord::new(root, template, bass_interval),
+            score,
+        })
+    }
+    fn fallback_candidate(bass: Syllable, syllables: &[Syllable]) -> ChordCandidate {
+        let intervals = syllables
+            .iter()
+            .filter(|x| pitch_class(Semitones::from(**x)) != pitch_class(Semitones::from(bass)))
+            .map(|x| Interval::from((bass, *x)))
+            .collect::<Vec<Interval>>();
+        // Only the first ones are kept when there are more than a chord can hold.
+        let intervals = Intervals::new(&intervals[..intervals.len().min(Intervals::MAX_LEN)]);
+        ChordCandidate {
+            chord: Chord::new(bass, intervals, None),
+            score: 0,
+        }
+    }
+    fn is_diatonic(scale: &Scale, root: Syllable) -> bool {
+        scale
+            .get_syllables()
+            .iter()
+            .any(|x| pitch_class(Semitones::from(*x)) == pitch_class(Semitones::from(root)))
+    }
+}
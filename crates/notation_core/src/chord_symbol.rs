@@ -1,8 +1,10 @@
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
-use crate::prelude::{Pitch, Syllable};
+use crate::parse_error::ParseTextError;
+use crate::prelude::{Chord, Interval, Intervals, Key, Pitch, Scale, Semitones, Syllable};
 
 // https://hellomusictheory.com/learn/chord-inversions/
 #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
@@ -35,14 +37,194 @@ pub enum ChordQuality {
     Dominant7th,
     Diminished7th,
     HaldDiminished7th,
+    MinorMajor7th,
+    AugmentedMajor7th,
+    Dominant7thSuspended4th,
+    // Extended
+    Major6th,
+    Minor6th,
+    SixNine,
+    Add9th,
+    MinorAdd9th,
+    Major9th,
+    Minor9th,
+    Dominant9th,
+    Minor11th,
+    Dominant11th,
+    Major13th,
+    Minor13th,
+    Dominant13th,
+    // Altered
+    Dominant7thFlat9th,
+    Dominant7thSharp9th,
+    Dominant7thSharp11th,
+    Dominant7thFlat13th,
+    Altered,
+    /// Root and fifth only.
+    Power,
+    // Stacked fourths and fifths
+    Quartal,
+    Quintal,
 }
-//Extended, Altered, Quartal and Quintal not supported
 impl Display for ChordQuality {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self)
     }
 }
 
+impl ChordQuality {
+    pub const ALL: [ChordQuality; 35] = [
+        ChordQuality::Major,
+        ChordQuality::Minor,
+        ChordQuality::Diminished,
+        ChordQuality::Augmented,
+        ChordQuality::Suspended2th,
+        ChordQuality::Suspended4th,
+        ChordQuality::Major7th,
+        ChordQuality::Minor7th,
+        ChordQuality::Dominant7th,
+        ChordQuality::Diminished7th,
+        ChordQuality::HaldDiminished7th,
+        ChordQuality::MinorMajor7th,
+        ChordQuality::AugmentedMajor7th,
+        ChordQuality::Dominant7thSuspended4th,
+        ChordQuality::Major6th,
+        ChordQuality::Minor6th,
+        ChordQuality::SixNine,
+        ChordQuality::Add9th,
+        ChordQuality::MinorAdd9th,
+        ChordQuality::Major9th,
+        ChordQuality::Minor9th,
+        ChordQuality::Dominant9th,
+        ChordQuality::Minor11th,
+        ChordQuality::Dominant11th,
+        ChordQuality::Major13th,
+        ChordQuality::Minor13th,
+        ChordQuality::Dominant13th,
+        ChordQuality::Dominant7thFlat9th,
+        ChordQuality::Dominant7thSharp9th,
+        ChordQuality::Dominant7thSharp11th,
+        ChordQuality::Dominant7thFlat13th,
+        ChordQuality::Altered,
+        ChordQuality::Power,
+        ChordQuality::Quartal,
+        ChordQuality::Quintal,
+    ];
+    pub fn to_symbol(&self) -> &'static str {
+        self.get_symbols()[0]
+    }
+    // The first one is the canonical spelling, others are accepted when parsing.
+    pub fn get_symbols(&self) -> &'static [&'static str] {
+        match self {
+            ChordQuality::Major => &["", "M", "maj"],
+            ChordQuality::Minor => &["m", "min", "-"],
+            ChordQuality::Diminished => &["dim", "o", "°"],
+            ChordQuality::Augmented => &["aug", "+"],
+            ChordQuality::Suspended2th => &["sus2"],
+            ChordQuality::Suspended4th => &["sus4", "sus"],
+            ChordQuality::Major7th => &["maj7", "M7", "Δ7", "Δ"],
+            ChordQuality::Minor7th => &["m7", "min7", "-7"],
+            ChordQuality::Dominant7th => &["7", "dom7"],
+            ChordQuality::Diminished7th => &["dim7", "o7", "°7"],
+            ChordQuality::HaldDiminished7th => &["m7b5", "ø", "ø7", "-7b5", "min7b5"],
+            ChordQuality::MinorMajor7th => &["mMaj7", "mM7", "m(maj7)", "-Δ7"],
+            ChordQuality::AugmentedMajor7th => &["maj7#5", "+maj7", "augMaj7", "Δ7#5"],
+            ChordQuality::Dominant7thSuspended4th => &["7sus4", "7sus"],
+            ChordQuality::Major6th => &["6", "maj6"],
+            ChordQuality::Minor6th => &["m6", "min6", "-6"],
+            ChordQuality::SixNine => &["6/9", "69"],
+            ChordQuality::Add9th => &["add9", "add2"],
+            ChordQuality::MinorAdd9th => &["madd9", "m(add9)"],
+            ChordQuality::Major9th => &["maj9", "M9", "Δ9"],
+            ChordQuality::Minor9th => &["m9", "min9", "-9"],
+            ChordQuality::Dominant9th => &["9"],
+            ChordQuality::Minor11th => &["m11", "min11", "-11"],
+            ChordQuality::Dominant11th => &["11"],
+            ChordQuality::Major13th => &["maj13", "M13", "Δ13"],
+            ChordQuality::Minor13th => &["m13", "min13", "-13"],
+            ChordQuality::Dominant13th => &["13"],
+            ChordQuality::Dominant7thFlat9th => &["7b9", "7♭9"],
+            ChordQuality::Dominant7thSharp9th => &["7#9", "7♯9"],
+            ChordQuality::Dominant7thSharp11th => &["7#11", "7♯11"],
+            ChordQuality::Dominant7thFlat13th => &["7b13", "7♭13"],
+            ChordQuality::Altered => &["7alt", "alt"],
+            ChordQuality::Power => &["5"],
+            ChordQuality::Quartal => &["quartal"],
+            ChordQuality::Quintal => &["quintal"],
+        }
+    }
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|x| x.get_symbols().contains(&symbol))
+            .copied()
+    }
+    pub fn get_intervals(&self) -> Intervals {
+        use Interval::*;
+        let intervals: &[Interval] = match self {
+            ChordQuality::Major => &[Major3nd, Perfect5th],
+            ChordQuality::Minor => &[Minor3nd, Perfect5th],
+            ChordQuality::Diminished => &[Minor3nd, Diminished5th],
+            ChordQuality::Augmented => &[Major3nd, Augmented5th],
+            ChordQuality::Suspended2th => &[Major2nd, Perfect5th],
+            ChordQuality::Suspended4th => &[Perfect4th, Perfect5th],
+            ChordQuality::Major7th => &[Major3nd, Perfect5th, Major7th],
+            ChordQuality::Minor7th => &[Minor3nd, Perfect5th, Minor7th],
+            ChordQuality::Dominant7th => &[Major3nd, Perfect5th, Minor7th],
+            ChordQuality::Diminished7th => &[Minor3nd, Diminished5th, Diminished7th],
+            ChordQuality::HaldDiminished7th => &[Minor3nd, Diminished5th, Minor7th],
+            ChordQuality::MinorMajor7th => &[Minor3nd, Perfect5th, Major7th],
+            ChordQuality::AugmentedMajor7th => &[Major3nd, Augmented5th, Major7th],
+            ChordQuality::Dominant7thSuspended4th => &[Perfect4th, Perfect5th, Minor7th],
+            ChordQuality::Major6th => &[Major3nd, Perfect5th, Major6th],
+            ChordQuality::Minor6th => &[Minor3nd, Perfect5th, Major6th],
+            ChordQuality::SixNine => &[Major3nd, Perfect5th, Major6th, Major9th],
+            ChordQuality::Add9th => &[Major3nd, Perfect5th, Major9th],
+            ChordQuality::MinorAdd9th => &[Minor3nd, Perfect5th, Major9th],
+            ChordQuality::Major9th => &[Major3nd, Perfect5th, Major7th, Major9th],
+            ChordQuality::Minor9th => &[Minor3nd, Perfect5th, Minor7th, Major9th],
+            ChordQuality::Dominant9th => &[Major3nd, Perfect5th, Minor7th, Major9th],
+            ChordQuality::Minor11th => &[Minor3nd, Perfect5th, Minor7th, Major9th, Perfect11th],
+            ChordQuality::Dominant11th => &[Perfect5th, Minor7th, Major9th, Perfect11th],
+            ChordQuality::Major13th => &[Major3nd, Perfect5th, Major7th, Major9th, Major13th],
+            ChordQuality::Minor13th => &[Minor3nd, Perfect5th, Minor7th, Major9th, Major13th],
+            ChordQuality::Dominant13th => &[Major3nd, Perfect5th, Minor7th, Major9th, Major13th],
+            ChordQuality::Dominant7thFlat9th => &[Major3nd, Perfect5th, Minor7th, Minor9th],
+            ChordQuality::Dominant7thSharp9th => &[Major3nd, Perfect5th, Minor7th, Augmented9th],
+            ChordQuality::Dominant7thSharp11th => &[Major3nd, Perfect5th, Minor7th, Augmented11th],
+            ChordQuality::Dominant7thFlat13th => &[Major3nd, Perfect5th, Minor7th, Minor13th],
+            ChordQuality::Altered => &[
+                Major3nd,
+                Minor7th,
+                Minor9th,
+                Augmented9th,
+                Augmented11th,
+                Minor13th,
+            ],
+            ChordQuality::Power => &[Perfect5th],
+            ChordQuality::Quartal => &[Perfect4th, Minor7th],
+            ChordQuality::Quintal => &[Perfect5th, Major9th],
+        };
+        Intervals::new(intervals)
+    }
+    pub fn from_intervals(intervals: &Intervals) -> Option<Self> {
+        let semitones = Self::intervals_semitones(intervals);
+        Self::ALL
+            .iter()
+            .find(|x| Self::intervals_semitones(&x.get_intervals()) == semitones)
+            .copied()
+    }
+    fn intervals_semitones(intervals: &Intervals) -> Vec<i8> {
+        let mut result = intervals
+            .get_intervals()
+            .iter()
+            .map(|x| Semitones::from(*x).0)
+            .collect::<Vec<i8>>();
+        result.sort();
+        result
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub struct ChordSymbol {
     pub pitch: Pitch,
@@ -52,7 +234,14 @@ pub struct ChordSymbol {
 }
 impl Display for ChordSymbol {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {} {}", self.pitch, self.quality, self.inversion)
+        write!(f, "{}{}", self.pitch, self.quality.to_symbol())?;
+        if let Some(bass) = self.bass_pitch() {
+            write!(f, "/{}", bass)?;
+        } else if let Some(interval) = self.bass_interval() {
+            let bass = Semitones::from(self.pitch) + Semitones::from(interval);
+            write!(f, "/{}", Pitch::from(bass))?;
+        }
+        Ok(())
     }
 }
 
@@ -70,4 +259,103 @@ impl ChordSymbol {
             syllable,
         }
     }
+    pub fn bass_interval(&self) -> Option<Interval> {
+        let intervals = self.quality.get_intervals().get_intervals();
+        match self.inversion {
+            ChordInversion::RootPosition => None,
+            ChordInversion::FirstInversion => intervals.first().copied(),
+            ChordInversion::SecondInversion => intervals.get(1).copied(),
+            ChordInversion::ThirdInversion => intervals.get(2).copied(),
+            ChordInversion::SlashBass(bass) => Some(Interval::from(
+                Semitones::from(bass) - Semitones::from(self.pitch),
+            )),
+        }
+    }
+    /// None if there is no bass, or it can't be spelled from the root.
+    pub fn bass_pitch(&self) -> Option<Pitch> {
+        match self.inversion {
+            ChordInversion::SlashBass(bass) => Some(bass),
+            _ => self
+                .bass_interval()
+                .and_then(|interval| spell_interval(self.pitch, interval)),
+        }
+    }
+    pub fn to_chord(&self, key: &Key, scale: &Scale) -> Chord {
+        let root = scale.calc_syllable(key, &self.pitch);
+        Chord::new(root, self.quality.get_intervals(), self.bass_interval())
+    }
+    /// The root is spelled from the key, or as an enharmonic one when the chord tones
+    /// can't be spelled from it, e.g. `Fbmaj7` as `Emaj7`.
+    pub fn from_chord(chord: &Chord, key: &Key, scale: &Scale) -> Option<Self> {
+        let quality = ChordQuality::from_intervals(&chord.intervals)?;
+        let mut intervals = quality.get_intervals().get_intervals();
+        intervals.extend(chord.bass);
+        let pitch = scale.calc_pitch(key, &chord.root);
+        let pitch = [pitch]
+            .into_iter()
+            .chain(pitch.enharmonics())
+            .find(|root| {
+                intervals
+                    .iter()
+                    .all(|x| spell_interval(*root, *x).is_some())
+            })?;
+        let inversion = match chord.bass {
+            None => ChordInversion::RootPosition,
+            Some(bass) => {
+                let intervals = quality.get_intervals().get_intervals();
+                match intervals
+                    .iter()
+                    .position(|x| Semitones::from(*x) == Semitones::from(bass))
+                {
+                    Some(0) => ChordInversion::FirstInversion,
+                    Some(1) => ChordInversion::SecondInversion,
+                    Some(2) => ChordInversion::ThirdInversion,
+                    _ => ChordInversion::SlashBass(spell_interval(pitch, bass)?),
+                }
+            }
+        };
+        Some(Self::new(pitch, quality, inversion, Some(chord.root)))
+    }
+}
+
+impl FromStr for ChordSymbol {
+    type Err = ParseTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseTextError::new("ChordSymbol", s);
+        // Only split when a pitch follows, so that "C6/9" is not taken as a slash chord.
+        let (main, bass) = match s.trim().rsplit_once('/') {
+            Some((main, bass)) if Pitch::split_text(bass).is_some() => (main, Some(bass)),
+            _ => (s.trim(), None),
+        };
+        let (pitch, suffix) = Pitch::split_text(main).ok_or_else(err)?;
+        let quality = ChordQuality::from_symbol(suffix).ok_or_else(err)?;
+        let inversion = match bass {
+            None => ChordInversion::RootPosition,
+            Some(bass) => {
+                let bass = match Pitch::split_text(bass) {
+                    Some((bass, "")) => bass,
+                    _ => return Err(err()),
+                };
+                let intervals = quality.get_intervals().get_intervals();
+                match intervals
+                    .iter()
+                    .position(|x| spell_interval(pitch, *x) == Some(bass))
+                {
+                    Some(0) => ChordInversion::FirstInversion,
+                    Some(1) => ChordInversion::SecondInversion,
+                    Some(2) => ChordInversion::ThirdInversion,
+                    _ => ChordInversion::SlashBass(bass),
+                }
+            }
+        };
+        Ok(Self::new(pitch, quality, inversion, None))
+    }
+}
+
+// Keep the letter name moving with the interval number, so that the third of Eb is G, not F##,
+// None if it needs more than two signs, e.g. the major third of B##.
+fn spell_interval(root: Pitch, interval: Interval) -> Option<Pitch> {
+    let name = root.name.offset(interval.dot_count() as isize - 1);
+    Pitch::spell(name, Semitones::from(root) + Semitones::from(interval))
 }
@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
-use crate::prelude::{Chord, Duration, Entry, EntryPassMode, Tone};
+use crate::prelude::{Articulation, Chord, Duration, Dynamic, Entry, EntryPassMode, Tone};
 
 #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub enum CoreEntry {
@@ -10,6 +10,10 @@ pub enum CoreEntry {
     Rest(Duration),
     Tone(Tone, Duration),
     Chord(Chord, Duration),
+    /// Applies to the following entries, until the next level.
+    Dynamic(Dynamic),
+    /// Applies to the next entry, can be repeated for multiple articulations.
+    Articulation(Articulation),
 }
 impl Display for CoreEntry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -18,6 +22,8 @@ impl Display for CoreEntry {
             CoreEntry::Rest(duration) => write!(f, "Rest({})", duration),
             CoreEntry::Tone(tone, duration) => write!(f, "Tone({}, {})", tone, duration),
             CoreEntry::Chord(chord, duration) => write!(f, "Chord({}, {})", chord, duration),
+            CoreEntry::Dynamic(dynamic) => write!(f, "Dynamic({})", dynamic),
+            CoreEntry::Articulation(articulation) => write!(f, "Articulation({})", articulation),
         }
     }
 }
@@ -28,6 +34,8 @@ impl CoreEntry {
             CoreEntry::Rest(duration) => *duration,
             CoreEntry::Tone(_, duration) => *duration,
             CoreEntry::Chord(_, duration) => *duration,
+            CoreEntry::Dynamic(_) => Duration::Zero,
+            CoreEntry::Articulation(_) => Duration::Zero,
         }
     }
     pub fn pass_mode(&self) -> EntryPassMode {
@@ -68,6 +76,16 @@ impl CoreEntry {
     pub fn is_chord(&self) -> bool {
         matches!(self, Self::Chord(..))
     }
+
+    /// Returns `true` if the entry is [`Dynamic`].
+    pub fn is_dynamic(&self) -> bool {
+        matches!(self, Self::Dynamic(..))
+    }
+
+    /// Returns `true` if the entry is [`Articulation`].
+    pub fn is_articulation(&self) -> bool {
+        matches!(self, Self::Articulation(..))
+    }
 }
 
 impl CoreEntry {
@@ -94,6 +112,22 @@ impl CoreEntry {
             None
         }
     }
+
+    pub fn as_dynamic(&self) -> Option<&Dynamic> {
+        if let Self::Dynamic(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_articulation(&self) -> Option<&Articulation> {
+        if let Self::Articulation(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
 }
 
 impl From<()> for CoreEntry {
@@ -119,3 +153,15 @@ impl From<(Chord, Duration)> for CoreEntry {
         Self::Chord(v.0, v.1)
     }
 }
+
+impl From<Dynamic> for CoreEntry {
+    fn from(v: Dynamic) -> Self {
+        Self::Dynamic(v)
+    }
+}
+
+impl From<Articulation> for CoreEntry {
+    fn from(v: Articulation) -> Self {
+        Self::Articulation(v)
+    }
+}
@@ -0,0 +1,171 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{
+    Chord, ChordQuality, ChordSymbol, Interval, Intervals, Key, RomanNumeral, Scale, Semitones,
+    Syllable,
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum DiatonicChordKind {
+    Triad,
+    Seventh,
+    Suspended2nd,
+    Suspended4th,
+    Add9th,
+}
+impl Display for DiatonicChordKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl DiatonicChordKind {
+    pub const ALL: [DiatonicChordKind; 5] = [
+        DiatonicChordKind::Triad,
+        DiatonicChordKind::Seventh,
+        DiatonicChordKind::Suspended2nd,
+        DiatonicChordKind::Suspended4th,
+        DiatonicChordKind::Add9th,
+    ];
+    /// Triads and sevenths are kept on every degree, e.g. the augmented major 7th
+    /// in harmonic minor, other kinds only where they form a known chord.
+    pub fn is_stacked_thirds(&self) -> bool {
+        matches!(self, DiatonicChordKind::Triad | DiatonicChordKind::Seventh)
+    }
+    /// Scale steps above the root, e.g. 2 and 4 for a triad, 8 is the 9th.
+    pub fn get_steps(&self) -> &'static [usize] {
+        match self {
+            DiatonicChordKind::Triad => &[2, 4],
+            DiatonicChordKind::Seventh => &[2, 4, 6],
+            DiatonicChordKind::Suspended2nd => &[1, 4],
+            DiatonicChordKind::Suspended4th => &[3, 4],
+            DiatonicChordKind::Add9th => &[2, 4, 8],
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DiatonicChord {
+    /// 0-based degree counted from the tonic of the scale.
+    pub degree: usize,
+    pub kind: DiatonicChordKind,
+    pub chord: Chord,
+    pub quality: Option<ChordQuality>,
+    pub numeral: RomanNumeral,
+}
+impl Display for DiatonicChord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.numeral, self.chord)
+    }
+}
+impl DiatonicChord {
+    pub fn to_symbol(&self, key: &Key, scale: &Scale) -> Option<ChordSymbol> {
+        ChordSymbol::from_chord(&self.chord, key, scale)
+    }
+}
+
+// Interval spelled from the number of scale steps, e.g. 6 semitones is a diminished 5th
+// when it's 4 steps above the root, None if there is no such interval, e.g. augmented 2nd.
+fn step_interval(steps: usize, semitones: i8) -> Option<Interval> {
+    match (steps, semitones) {
+        (1, 1) => Some(Interval::Minor2nd),
+        (1, 2) => Some(Interval::Major2nd),
+        (2, 3) => Some(Interval::Minor3nd),
+        (2, 4) => Some(Interval::Major3nd),
+        (3, 5) => Some(Interval::Perfect4th),
+        (3, 6) => Some(Interval::Augmented4th),
+        (4, 6) => Some(Interval::Diminished5th),
+        (4, 7) => Some(Interval::Perfect5th),
+        (4, 8) => Some(Interval::Augmented5th),
+        (6, 9) => Some(Interval::Diminished7th),
+        (6, 10) => Some(Interval::Minor7th),
+        (6, 11) => Some(Interval::Major7th),
+        (8, 13) => Some(Interval::Minor9th),
+        (8, 14) => Some(Interval::Major9th),
+        (8, 15) => Some(Interval::Augmented9th),
+        _ => None,
+    }
+}
+
+impl Scale {
+    /// Chords built on the degree with notes from the scale, non-heptatonic scales use
+    /// the church mode sharing the same tonic, None if any of the notes is not in the scale.
+    pub fn calc_diatonic_chord(
+        &self,
+        degree: usize,
+        kind: DiatonicChordKind,
+    ) -> Option<DiatonicChord> {
+        let scale = if self.get_pattern().len() == 7 {
+            *self
+        } else {
+            self.get_mode()?
+        };
+        let syllables = scale.get_syllables();
+        let root = syllables[degree % 7];
+        let scale_syllables = self.get_syllables();
+        let in_scale = |x: Syllable| {
+            scale_syllables
+                .iter()
+                .any(|y| Semitones::from(*y) == Semitones::from(x))
+        };
+        if !in_scale(root) {
+            return None;
+        }
+        let root_semitones = Semitones::from(root).0;
+        let mut intervals = Vec::new();
+        for steps in kind.get_steps().iter() {
+            let syllable: Syllable = syllables[(degree + steps) % 7];
+            if !in_scale(syllable) {
+                return None;
+            }
+            let octave = if *steps >= 7 { 12 } else { 0 };
+            let semitones = (Semitones::from(syllable).0 - root_semitones).rem_euclid(12) + octave;
+            intervals.push(step_interval(*steps, semitones)?);
+        }
+        let intervals = Intervals::try_from(intervals).ok()?;
+        let quality = ChordQuality::from_intervals(&intervals);
+        if quality.is_none() && !kind.is_stacked_thirds() {
+            return None;
+        }
+        let chord = Chord::new(root, intervals, None);
+        Some(DiatonicChord {
+            degree: degree % 7,
+            kind,
+            chord,
+            quality,
+            numeral: RomanNumeral::from_chord(&chord, &scale)?,
+        })
+    }
+    /// One chord for each degree where it can be built, e.g. no sus4 on Fa in major.
+    pub fn get_diatonic_chords(&self, kind: DiatonicChordKind) -> Vec<DiatonicChord> {
+        (0..7)
+            .filter_map(|degree| self.calc_diatonic_chord(degree, kind))
+            .collect()
+    }
+    /// Triads and seventh chords of all degrees.
+    pub fn get_harmonic_field(&self) -> Vec<DiatonicChord> {
+        let mut chords = self.get_diatonic_chords(DiatonicChordKind::Triad);
+        chords.extend(self.get_diatonic_chords(DiatonicChordKind::Seventh));
+        chords
+    }
+}
+
+#[test]
+fn test_diatonic_chords_in_scale() {
+    let text = |scale: Scale, kind: DiatonicChordKind| -> Vec<String> {
+        scale
+            .get_diatonic_chords(kind)
+            .iter()
+            .map(|x| x.numeral.to_string())
+            .collect()
+    };
+    assert_eq!(
+        text(Scale::MajorPentatonic, DiatonicChordKind::Triad),
+        vec!["I", "vi"]
+    );
+    assert!(text(Scale::WholeTone, DiatonicChordKind::Triad).is_empty());
+    let chords = Scale::HarmonicMinor.get_diatonic_chords(DiatonicChordKind::Seventh);
+    assert_eq!(chords[2].quality, Some(ChordQuality::AugmentedMajor7th));
+    assert_eq!(chords[2].numeral.to_string(), "III+maj7");
+}
@@ -1,9 +1,13 @@
 use std::cmp::Ordering;
 use std::fmt::Display;
-use std::ops::{Add, Sub};
+use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
+use crate::parse_error::ParseTextError;
+use crate::prelude::InlineVec;
+
 #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub enum Unit {
     Whole,
@@ -66,39 +70,137 @@ impl Unit {
     pub fn to_ident(&self) -> String {
         format!("{}", self)
     }
-    pub fn from_ident(ident: &str) -> Self {
-        match ident {
+    pub fn from_ident(ident: &str) -> Result<Self, ParseTextError> {
+        ident.parse()
+    }
+}
+
+/// Parses the text from `to_ident()`, e.g. `_1_8`, the short form used in time
+/// signatures is accepted as well, e.g. `_8`.
+impl FromStr for Unit {
+    type Err = ParseTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
             "_1" => Self::_1,
-            "_1_2" => Self::_1_2,
-            "_1_4" => Self::_1_4,
-            "_1_8" => Self::_1_8,
-            "_1_16" => Self::_1_16,
-            "_1_32" => Self::_1_32,
-            _ => Self::_1_4,
-        }
+            "_1_2" | "_2" => Self::_1_2,
+            "_1_4" | "_4" => Self::_1_4,
+            "_1_8" | "_8" => Self::_1_8,
+            "_1_16" | "_16" => Self::_1_16,
+            "_1_32" | "_32" => Self::_1_32,
+            _ => return Err(ParseTextError::new("Unit", s)),
+        })
     }
 }
 
-#[derive(Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Debug, Default)]
-pub struct Units(pub f32);
+/// Length of time in whole notes, kept as an exact fraction, so that triplets won't
+/// drift over long tabs. The f32 value is only meant for rendering.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+pub struct Units {
+    numer: i64,
+    denom: i64,
+}
 
-impl Units {
-    pub const _MIN_ACCURACY: f32 = 0.00001;
-    pub const MIN_ACCURACY: Units = Units(Self::_MIN_ACCURACY);
-    /*
-     * This is quite messy here, the pasition passed checking needs some cleanup,
-     * mainly for control the guitar view timing, and seeking next note
-     */
-    pub const HALF_MIN_ACCURACY: Units = Units(Self::_MIN_ACCURACY * 0.5);
+impl Default for Units {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
 
-    pub fn is_bigger_than(&self, other: &Units) -> bool {
-        self.0 > other.0 + Self::_MIN_ACCURACY
+impl Units {
+    pub const ZERO: Self = Self { numer: 0, denom: 1 };
+    /// Steps per whole note when converting from f32, e.g. the elapsed seconds while playing.
+    pub const RESOLUTION: i64 = 3_628_800;
+    /// Smallest step from f32 values, used for seeking to right before an entry.
+    pub const TICK: Self = Self {
+        numer: 1,
+        denom: Self::RESOLUTION,
+    };
+    fn reduce(numer: i128, denom: i128) -> Self {
+        let denom_sign = if denom < 0 { -1 } else { 1 };
+        let divisor = gcd(numer, denom).max(1) * denom_sign;
+        let (numer, denom) = (numer / divisor, denom / divisor);
+        if let (Ok(numer), Ok(denom)) = (i64::try_from(numer), i64::try_from(denom)) {
+            return Self { numer, denom };
+        }
+        // Too fine to be kept exactly, rounded down to the resolution instead.
+        let numer = numer
+            .checked_mul(Self::RESOLUTION as i128)
+            .map(|x| x.div_euclid(denom))
+            .and_then(|x| i64::try_from(x).ok())
+            .unwrap_or(if numer < 0 { i64::MIN } else { i64::MAX });
+        Self::new(numer, Self::RESOLUTION)
+    }
+    /// Zero denominators are not valid, checked in debug builds and give zero otherwise.
+    pub fn new(numer: i64, denom: i64) -> Self {
+        debug_assert!(denom != 0, "Invalid Units: {}/{}", numer, denom);
+        if denom == 0 {
+            return Self::ZERO;
+        }
+        Self::reduce(numer as i128, denom as i128)
+    }
+    pub fn numer(&self) -> i64 {
+        self.numer
+    }
+    pub fn denom(&self) -> i64 {
+        self.denom
+    }
+    pub fn is_zero(&self) -> bool {
+        self.numer == 0
+    }
+    pub fn as_f32(&self) -> f32 {
+        (self.numer as f64 / self.denom as f64) as f32
+    }
+    /// Multiply by `numer / denom`, e.g. 3/2 for dotted notes, zero denominators are checked
+    /// the same way as in `new()`.
+    pub fn scaled(&self, numer: i64, denom: i64) -> Self {
+        debug_assert!(denom != 0, "Invalid Units scale: {}/{}", numer, denom);
+        if denom == 0 {
+            return Self::ZERO;
+        }
+        Self::reduce(
+            self.numer as i128 * numer as i128,
+            self.denom as i128 * denom as i128,
+        )
+    }
+    /// How many times `other` fits in, rounded down, e.g. for bar ordinal of a position.
+    pub fn div_floor(&self, other: &Self) -> i64 {
+        if other.is_zero() {
+            return 0;
+        }
+        let numer = self.numer as i128 * other.denom as i128;
+        let denom = self.denom as i128 * other.numer as i128;
+        numer.div_euclid(denom) as i64
+    }
+    /// Ratio between two lengths, only meant for rendering.
+    pub fn ratio(&self, other: &Self) -> f32 {
+        if other.is_zero() {
+            return 0.0;
+        }
+        ((self.numer as f64 * other.denom as f64) / (self.denom as f64 * other.numer as f64))
+            as f32
+    }
+    pub fn abs(&self) -> Self {
+        Self {
+            numer: self.numer.abs(),
+            denom: self.denom,
+        }
     }
 }
 
 impl Display for Units {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "<Units>({})", self.0)
+        write!(f, "<Units>({}/{})", self.numer, self.denom)
     }
 }
 
@@ -106,7 +208,10 @@ impl Add for Units {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Units(self.0 + rhs.0)
+        Self::reduce(
+            self.numer as i128 * rhs.denom as i128 + rhs.numer as i128 * self.denom as i128,
+            self.denom as i128 * rhs.denom as i128,
+        )
     }
 }
 
@@ -114,30 +219,113 @@ impl Sub for Units {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Units(self.0 - rhs.0)
+        Self::reduce(
+            self.numer as i128 * rhs.denom as i128 - rhs.numer as i128 * self.denom as i128,
+            self.denom as i128 * rhs.denom as i128,
+        )
     }
 }
 
-impl Units {
-    pub fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        if self.0 == other.0 {
-            Ordering::Equal
-        } else if self.0 < other.0 {
-            Ordering::Less
+impl Mul<usize> for Units {
+    type Output = Self;
+
+    fn mul(self, rhs: usize) -> Self::Output {
+        self.scaled(rhs as i64, 1)
+    }
+}
+
+impl Ord for Units {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numer as i128 * other.denom as i128).cmp(&(other.numer as i128 * self.denom as i128))
+    }
+}
+
+impl PartialOrd for Units {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// https://en.wikipedia.org/wiki/Tuplet
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+pub struct Tuplet {
+    /// Number of notes played.
+    pub notes: u8,
+    /// Number of notes of the same unit they take the time of.
+    pub in_time_of: u8,
+}
+
+impl Display for Tuplet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if *self == Self::TRIPLET {
+            write!(f, "T")
+        } else if self.in_time_of == Self::default_in_time_of(self.notes) {
+            write!(f, "T{}", self.notes)
         } else {
-            Ordering::Greater
+            write!(f, "T{}in{}", self.notes, self.in_time_of)
+        }
+    }
+}
+
+impl Tuplet {
+    pub const DUPLET: Self = Self::new(2, 3);
+    pub const TRIPLET: Self = Self::new(3, 2);
+    pub const QUADRUPLET: Self = Self::new(4, 3);
+    pub const QUINTUPLET: Self = Self::new(5, 4);
+    pub const SEXTUPLET: Self = Self::new(6, 4);
+    pub const SEPTUPLET: Self = Self::new(7, 4);
+
+    pub const fn new(notes: u8, in_time_of: u8) -> Self {
+        Self { notes, in_time_of }
+    }
+    /// Duplets and quadruplets are in compound meters, others take the time of
+    /// the largest power of two below.
+    pub const fn default_in_time_of(notes: u8) -> u8 {
+        match notes {
+            0 | 1 => 1,
+            2 => 3,
+            4 => 3,
+            _ => {
+                let mut in_time_of = 1;
+                while in_time_of * 2 < notes {
+                    in_time_of *= 2;
+                }
+                in_time_of
+            }
         }
     }
+    pub const fn of_notes(notes: u8) -> Self {
+        Self::new(notes, Self::default_in_time_of(notes))
+    }
+    pub fn is_valid(&self) -> bool {
+        self.notes > 0 && self.in_time_of > 0
+    }
+    /// Parse the tuplet part of a duration ident, e.g. `T`, `T5` or `T6in4`.
+    pub fn from_ident(ident: &str) -> Option<Self> {
+        let ident = ident.strip_prefix('T')?;
+        if ident.is_empty() {
+            return Some(Self::TRIPLET);
+        }
+        let tuplet = match ident.split_once("in") {
+            Some((notes, in_time_of)) => {
+                Self::new(notes.parse().ok()?, in_time_of.parse().ok()?)
+            }
+            None => Self::of_notes(ident.parse().ok()?),
+        };
+        Some(tuplet).filter(|x| x.is_valid())
+    }
 }
 
+/// Nested from outside in, e.g. triplets within a quintuplet are `[T5, T]`.
+pub type Tuplets = InlineVec<Tuplet, { Duration::MAX_TUPLET_DEPTH }>;
+
 // https://hellomusictheory.com/learn/tuplets/
 #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub enum Duration {
     Zero,
     Simple(Unit),
-    Dotted(Unit),
-    Triplet(Unit),
-    DottedTriplet(Unit),
+    /// Unit with any number of dots, within tuplets.
+    Complex(Unit, u8, Tuplets),
 }
 
 impl Display for Duration {
@@ -145,16 +333,11 @@ impl Display for Duration {
         match self {
             Duration::Zero => write!(f, "_0"),
             Duration::Simple(d) => d.fmt(f),
-            Duration::Dotted(d) => {
-                write!(f, "D")?;
-                d.fmt(f)
-            }
-            Duration::Triplet(d) => {
-                write!(f, "T")?;
-                d.fmt(f)
-            }
-            Duration::DottedTriplet(d) => {
-                write!(f, "DT")?;
+            Duration::Complex(d, dots, tuplets) => {
+                write!(f, "{}", "D".repeat(*dots as usize))?;
+                for tuplet in tuplets.iter() {
+                    tuplet.fmt(f)?;
+                }
                 d.fmt(f)
             }
         }
@@ -162,6 +345,10 @@ impl Display for Duration {
 }
 
 impl Duration {
+    pub const MAX_TUPLET_DEPTH: usize = 3;
+    /// Each dot adds half of the previous one, more than these are not told apart.
+    pub const MAX_DOTS: u8 = 8;
+
     pub const _0: Self = Duration::Zero;
     pub const _1: Self = Duration::Simple(Unit::Whole);
     pub const _1_2: Self = Duration::Simple(Unit::Half);
@@ -169,24 +356,41 @@ impl Duration {
     pub const _1_8: Self = Duration::Simple(Unit::Eighth);
     pub const _1_16: Self = Duration::Simple(Unit::Sixteenth);
     pub const _1_32: Self = Duration::Simple(Unit::ThirtySecondth);
-    pub const D_1: Self = Duration::Dotted(Unit::Whole);
-    pub const D_1_2: Self = Duration::Dotted(Unit::Half);
-    pub const D_1_4: Self = Duration::Dotted(Unit::Quarter);
-    pub const D_1_8: Self = Duration::Dotted(Unit::Eighth);
-    pub const D_1_16: Self = Duration::Dotted(Unit::Sixteenth);
-    pub const D_1_32: Self = Duration::Dotted(Unit::ThirtySecondth);
-    pub const T_1: Self = Duration::Triplet(Unit::Whole);
-    pub const T_1_2: Self = Duration::Triplet(Unit::Half);
-    pub const T_1_4: Self = Duration::Triplet(Unit::Quarter);
-    pub const T_1_8: Self = Duration::Triplet(Unit::Eighth);
-    pub const T_1_16: Self = Duration::Triplet(Unit::Sixteenth);
-    pub const T_1_32: Self = Duration::Triplet(Unit::ThirtySecondth);
-    pub const DT_1: Self = Duration::DottedTriplet(Unit::Whole);
-    pub const DT_1_2: Self = Duration::DottedTriplet(Unit::Half);
-    pub const DT_1_4: Self = Duration::DottedTriplet(Unit::Quarter);
-    pub const DT_1_8: Self = Duration::DottedTriplet(Unit::Eighth);
-    pub const DT_1_16: Self = Duration::DottedTriplet(Unit::Sixteenth);
-    pub const DT_1_32: Self = Duration::DottedTriplet(Unit::ThirtySecondth);
+    pub const D_1: Self = Duration::dotted(Unit::Whole);
+    pub const D_1_2: Self = Duration::dotted(Unit::Half);
+    pub const D_1_4: Self = Duration::dotted(Unit::Quarter);
+    pub const D_1_8: Self = Duration::dotted(Unit::Eighth);
+    pub const D_1_16: Self = Duration::dotted(Unit::Sixteenth);
+    pub const D_1_32: Self = Duration::dotted(Unit::ThirtySecondth);
+    pub const T_1: Self = Duration::triplet(Unit::Whole);
+    pub const T_1_2: Self = Duration::triplet(Unit::Half);
+    pub const T_1_4: Self = Duration::triplet(Unit::Quarter);
+    pub const T_1_8: Self = Duration::triplet(Unit::Eighth);
+    pub const T_1_16: Self = Duration::triplet(Unit::Sixteenth);
+    pub const T_1_32: Self = Duration::triplet(Unit::ThirtySecondth);
+    pub const DT_1: Self = Duration::dotted_triplet(Unit::Whole);
+    pub const DT_1_2: Self = Duration::dotted_triplet(Unit::Half);
+    pub const DT_1_4: Self = Duration::dotted_triplet(Unit::Quarter);
+    pub const DT_1_8: Self = Duration::dotted_triplet(Unit::Eighth);
+    pub const DT_1_16: Self = Duration::dotted_triplet(Unit::Sixteenth);
+    pub const DT_1_32: Self = Duration::dotted_triplet(Unit::ThirtySecondth);
+
+    pub const fn new(unit: Unit, dots: u8, tuplets: &[Tuplet]) -> Self {
+        if dots == 0 && tuplets.is_empty() {
+            Duration::Simple(unit)
+        } else {
+            Duration::Complex(unit, dots, Tuplets::from_slice(tuplets))
+        }
+    }
+    pub const fn dotted(unit: Unit) -> Self {
+        Self::new(unit, 1, &[])
+    }
+    pub const fn triplet(unit: Unit) -> Self {
+        Self::new(unit, 0, &[Tuplet::TRIPLET])
+    }
+    pub const fn dotted_triplet(unit: Unit) -> Self {
+        Self::new(unit, 1, &[Tuplet::TRIPLET])
+    }
 
     /// Returns `true` if the duration is [`Simple`].
     pub fn is_simple(&self) -> bool {
@@ -200,41 +404,89 @@ impl Duration {
             None
         }
     }
+    pub fn unit(&self) -> Option<Unit> {
+        match self {
+            Self::Zero => None,
+            Self::Simple(unit) => Some(*unit),
+            Self::Complex(unit, _, _) => Some(*unit),
+        }
+    }
+    pub fn dots(&self) -> u8 {
+        match self {
+            Self::Complex(_, dots, _) => *dots,
+            _ => 0,
+        }
+    }
+    pub fn tuplets(&self) -> Tuplets {
+        match self {
+            Self::Complex(_, _, tuplets) => *tuplets,
+            _ => Tuplets::new(),
+        }
+    }
+    pub fn with_unit(&self, unit: Unit) -> Self {
+        match self {
+            Self::Zero => Self::Zero,
+            _ => Self::new(unit, self.dots(), &self.tuplets().to_vec()),
+        }
+    }
+    pub fn with_dots(&self, dots: u8) -> Self {
+        match self.unit() {
+            None => Self::Zero,
+            Some(unit) => Self::new(unit, dots.min(Self::MAX_DOTS), &self.tuplets().to_vec()),
+        }
+    }
+    /// Put the duration within another tuplet, ignored if already too deep.
+    pub fn within_tuplet(&self, tuplet: Tuplet) -> Self {
+        match self.unit() {
+            None => Self::Zero,
+            Some(unit) => {
+                let mut tuplets = self.tuplets();
+                if !tuplets.push(tuplet) {
+                    println!("Tuplets too deep: {} - {}", self, tuplet);
+                }
+                Self::new(unit, self.dots(), &tuplets.to_vec())
+            }
+        }
+    }
 }
 
 impl Duration {
     pub fn to_ident(&self) -> String {
         format!("{}", self)
     }
-    pub fn from_ident(ident: &str) -> Self {
-        match ident {
-            "_0" => Self::_0,
-            "_1" => Self::_1,
-            "_1_2" => Self::_1_2,
-            "_1_4" => Self::_1_4,
-            "_1_8" => Self::_1_8,
-            "_1_16" => Self::_1_16,
-            "_1_32" => Self::_1_32,
-            "D_1" => Self::D_1,
-            "D_1_2" => Self::D_1_2,
-            "D_1_4" => Self::D_1_4,
-            "D_1_8" => Self::D_1_8,
-            "D_1_16" => Self::D_1_16,
-            "D_1_32" => Self::D_1_32,
-            "T_1" => Self::T_1,
-            "T_1_2" => Self::T_1_2,
-            "T_1_4" => Self::T_1_4,
-            "T_1_8" => Self::T_1_8,
-            "T_1_16" => Self::T_1_16,
-            "T_1_32" => Self::T_1_32,
-            "DT_1" => Self::DT_1,
-            "DT_1_2" => Self::DT_1_2,
-            "DT_1_4" => Self::DT_1_4,
-            "DT_1_8" => Self::DT_1_8,
-            "DT_1_16" => Self::DT_1_16,
-            "DT_1_32" => Self::DT_1_32,
-            _ => Self::_0,
+    /// Format is dots, then tuplets, then unit, e.g. `_1_4`, `DD_1_4`, `DT_1_8`, `TT5_1_16`.
+    pub fn from_ident(ident: &str) -> Result<Self, ParseTextError> {
+        ident.parse()
+    }
+}
+
+/// Parses the text from `to_ident()`, see `from_ident()` for the format.
+impl FromStr for Duration {
+    type Err = ParseTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseTextError::new("Duration", s);
+        let ident = s.trim();
+        if ident == "_0" {
+            return Ok(Self::_0);
+        }
+        let (prefix, unit) = ident.split_at(ident.find('_').ok_or_else(err)?);
+        if !prefix.is_ascii() {
+            return Err(err());
+        }
+        let unit = unit.parse::<Unit>().map_err(|_| err())?;
+        let dots = prefix.len() - prefix.trim_start_matches('D').len();
+        let mut tuplets = Vec::new();
+        let mut rest = &prefix[dots..];
+        while !rest.is_empty() {
+            let next = rest[1..].find('T').map(|x| x + 1).unwrap_or(rest.len());
+            tuplets.push(Tuplet::from_ident(&rest[..next]).ok_or_else(err)?);
+            rest = &rest[next..];
         }
+        if tuplets.len() > Self::MAX_TUPLET_DEPTH || dots > Self::MAX_DOTS as usize {
+            return Err(err());
+        }
+        Ok(Self::new(unit, dots as u8, &tuplets))
     }
 }
 
@@ -252,33 +504,53 @@ impl From<Unit> for Duration {
 
 impl From<f32> for Units {
     fn from(v: f32) -> Self {
-        Self(v)
+        Self::new((v as f64 * Self::RESOLUTION as f64).round() as i64, Self::RESOLUTION)
     }
 }
 
 impl From<Unit> for Units {
     fn from(v: Unit) -> Self {
-        match v {
-            Unit::Whole => 1.0,
-            Unit::Half => 1.0 / 2.0,
-            Unit::Quarter => 1.0 / 4.0,
-            Unit::Eighth => 1.0 / 8.0,
-            Unit::Sixteenth => 1.0 / 16.0,
-            Unit::ThirtySecondth => 1.0 / 32.0,
-        }
-        .into()
+        let denom = match v {
+            Unit::Whole => 1,
+            Unit::Half => 2,
+            Unit::Quarter => 4,
+            Unit::Eighth => 8,
+            Unit::Sixteenth => 16,
+            Unit::ThirtySecondth => 32,
+        };
+        Self::new(1, denom)
     }
 }
 
 impl From<Duration> for Units {
     fn from(v: Duration) -> Self {
         match v {
-            Duration::Zero => 0.0,
-            Duration::Simple(v) => Units::from(v).0,
-            Duration::Dotted(v) => Units::from(v).0 * 1.5,
-            Duration::Triplet(v) => Units::from(v).0 * 2.0 / 3.0,
-            Duration::DottedTriplet(v) => Units::from(v).0 * 4.0 / 3.0,
+            Duration::Zero => Units::ZERO,
+            Duration::Simple(v) => Units::from(v),
+            Duration::Complex(v, dots, tuplets) => {
+                // Each dot adds half of the previous one, e.g. 1 + 1/2 + 1/4 for double dotted.
+                let dots = dots.min(Duration::MAX_DOTS) as u32;
+                let units = Units::from(v).scaled(2i64.pow(dots + 1) - 1, 2i64.pow(dots));
+                tuplets.iter().fold(units, |units, tuplet| {
+                    units.scaled(tuplet.in_time_of as i64, tuplet.notes as i64)
+                })
+            }
+        }
+    }
+}
+
+#[test]
+fn test_duration_ident_round_trip() {
+    let units = [Unit::_1, Unit::_1_2, Unit::_1_4, Unit::_1_8, Unit::_1_16, Unit::_1_32];
+    let tuplets = [Tuplet::TRIPLET, Tuplet::QUINTUPLET, Tuplet::new(6, 4)];
+    for unit in units {
+        assert_eq!(unit.to_ident().parse::<Unit>(), Ok(unit));
+        for dots in 0..=Duration::MAX_DOTS {
+            for depth in 0..=Duration::MAX_TUPLET_DEPTH {
+                let duration = Duration::new(unit, dots, &tuplets[..depth]);
+                assert_eq!(Duration::from_ident(&duration.to_ident()), Ok(duration));
+            }
         }
-        .into()
     }
+    assert_eq!(Duration::from_ident(&Duration::Zero.to_ident()), Ok(Duration::Zero));
 }
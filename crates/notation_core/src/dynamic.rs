@@ -0,0 +1,119 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::Units;
+
+// https://en.wikipedia.org/wiki/Dynamics_(music)
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub enum DynamicLevel {
+    Pianissimo,
+    Piano,
+    MezzoPiano,
+    #[default]
+    MezzoForte,
+    Forte,
+    Fortissimo,
+}
+impl Display for DynamicLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_text())
+    }
+}
+impl DynamicLevel {
+    pub const ALL: [DynamicLevel; 6] = [
+        DynamicLevel::Pianissimo,
+        DynamicLevel::Piano,
+        DynamicLevel::MezzoPiano,
+        DynamicLevel::MezzoForte,
+        DynamicLevel::Forte,
+        DynamicLevel::Fortissimo,
+    ];
+    pub fn to_text(&self) -> String {
+        match self {
+            DynamicLevel::Pianissimo => "pp",
+            DynamicLevel::Piano => "p",
+            DynamicLevel::MezzoPiano => "mp",
+            DynamicLevel::MezzoForte => "mf",
+            DynamicLevel::Forte => "f",
+            DynamicLevel::Fortissimo => "ff",
+        }
+        .to_owned()
+    }
+    pub fn from_text(text: &str) -> Option<Self> {
+        Self::ALL.iter().find(|x| x.to_text() == text).copied()
+    }
+    /// Scale of the track velocity, mf plays at the velocity in the settings.
+    pub fn velocity_factor(&self) -> f32 {
+        match self {
+            DynamicLevel::Pianissimo => 0.4,
+            DynamicLevel::Piano => 0.55,
+            DynamicLevel::MezzoPiano => 0.75,
+            DynamicLevel::MezzoForte => 1.0,
+            DynamicLevel::Forte => 1.2,
+            DynamicLevel::Fortissimo => 1.4,
+        }
+    }
+    /// One level up, stays at ff.
+    pub fn louder(&self) -> Self {
+        let index = Self::ALL.iter().position(|x| x == self).unwrap_or(0);
+        Self::ALL[(index + 1).min(Self::ALL.len() - 1)]
+    }
+    /// One level down, stays at pp.
+    pub fn softer(&self) -> Self {
+        let index = Self::ALL.iter().position(|x| x == self).unwrap_or(0);
+        Self::ALL[index.saturating_sub(1)]
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum Dynamic {
+    Level(DynamicLevel),
+    /// Getting louder over the span, towards the next level, or one level up if there is none.
+    Crescendo(Units),
+    /// Getting softer over the span, towards the next level, or one level down if there is none.
+    Decrescendo(Units),
+}
+impl Display for Dynamic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_text())
+    }
+}
+impl Dynamic {
+    pub fn to_text(&self) -> String {
+        match self {
+            Dynamic::Level(level) => level.to_text(),
+            Dynamic::Crescendo(_) => "cresc.".to_owned(),
+            Dynamic::Decrescendo(_) => "decresc.".to_owned(),
+        }
+    }
+    pub fn as_level(&self) -> Option<&DynamicLevel> {
+        if let Self::Level(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+    /// Returns the span of crescendo or decrescendo.
+    pub fn span(&self) -> Option<Units> {
+        match self {
+            Dynamic::Level(_) => None,
+            Dynamic::Crescendo(span) => Some(*span),
+            Dynamic::Decrescendo(span) => Some(*span),
+        }
+    }
+    /// The level reached at the end of the span, if no other level is given.
+    pub fn default_target(&self, level: &DynamicLevel) -> DynamicLevel {
+        match self {
+            Dynamic::Level(target) => *target,
+            Dynamic::Crescendo(_) => level.louder(),
+            Dynamic::Decrescendo(_) => level.softer(),
+        }
+    }
+}
+
+impl From<DynamicLevel> for Dynamic {
+    fn from(v: DynamicLevel) -> Self {
+        Self::Level(v)
+    }
+}
@@ -0,0 +1,164 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+use serde::de::{SeqAccess, Visitor};
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Fixed capacity vector stored inline, so that the types using it can stay `Copy`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct InlineVec<T: Copy, const N: usize> {
+    len: usize,
+    items: [Option<T>; N],
+}
+
+/// More items than the capacity of an `InlineVec`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct CapacityError {
+    pub capacity: usize,
+    pub len: usize,
+}
+impl Display for CapacityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} items over the capacity of {}", self.len, self.capacity)
+    }
+}
+impl std::error::Error for CapacityError {}
+
+impl<T: Copy, const N: usize> Default for InlineVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy, const N: usize> InlineVec<T, N> {
+    pub const CAPACITY: usize = N;
+
+    pub const fn new() -> Self {
+        Self {
+            len: 0,
+            items: [None; N],
+        }
+    }
+    /// Items past the capacity are dropped, use `try_from_slice()` to check.
+    pub const fn from_slice(v: &[T]) -> Self {
+        let mut items = [None; N];
+        let mut len = 0;
+        while len < v.len() && len < N {
+            items[len] = Some(v[len]);
+            len += 1;
+        }
+        Self { len, items }
+    }
+    pub fn try_from_slice(v: &[T]) -> Result<Self, CapacityError> {
+        if v.len() > N {
+            Err(CapacityError {
+                capacity: N,
+                len: v.len(),
+            })
+        } else {
+            Ok(Self::from_slice(v))
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index < self.len {
+            self.items[index]
+        } else {
+            None
+        }
+    }
+    pub fn first(&self) -> Option<T> {
+        self.get(0)
+    }
+    pub fn last(&self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.get(self.len - 1)
+        }
+    }
+    /// Returns `false` if the vector is full, the value is dropped in that case.
+    pub fn push(&mut self, v: T) -> bool {
+        if self.len >= N {
+            return false;
+        }
+        self.items[self.len] = Some(v);
+        self.len += 1;
+        true
+    }
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.items[..self.len].iter().flatten().copied()
+    }
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().collect()
+    }
+}
+
+impl<T: Copy + Ord, const N: usize> PartialOrd for InlineVec<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Shorter ones come first, then compare item by item.
+impl<T: Copy + Ord, const N: usize> Ord for InlineVec<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.len
+            .cmp(&other.len)
+            .then_with(|| self.items.cmp(&other.items))
+    }
+}
+
+impl<T: Copy, const N: usize> TryFrom<&[T]> for InlineVec<T, N> {
+    type Error = CapacityError;
+
+    fn try_from(v: &[T]) -> Result<Self, Self::Error> {
+        Self::try_from_slice(v)
+    }
+}
+
+impl<T: Copy + Serialize, const N: usize> Serialize for InlineVec<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len))?;
+        for item in self.iter() {
+            seq.serialize_element(&item)?;
+        }
+        seq.end()
+    }
+}
+
+struct InlineVecVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: Copy + Deserialize<'de>, const N: usize> Visitor<'de> for InlineVecVisitor<T, N> {
+    type Value = InlineVec<T, N>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a sequence with at most {} items", N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut result = InlineVec::new();
+        while let Some(item) = seq.next_element()? {
+            if !result.push(item) {
+                return Err(serde::de::Error::invalid_length(N + 1, &self));
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<'de, T: Copy + Deserialize<'de>, const N: usize> Deserialize<'de> for InlineVec<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(InlineVecVisitor(PhantomData))
+    }
+}
@@ -1,7 +1,9 @@
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
+use crate::parse_error::ParseTextError;
 use crate::prelude::{Semitones, Syllable};
 
 // https://hellomusictheory.com/learn/intervals/
@@ -39,6 +41,13 @@ pub enum Interval {
     Minor7th,
     Major7th,
     Perfect8ve,
+    Minor9th,
+    Major9th,
+    Augmented9th,
+    Perfect11th,
+    Augmented11th,
+    Minor13th,
+    Major13th,
 }
 impl Display for Interval {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -66,6 +75,13 @@ impl From<Interval> for IntervalQuality {
             Interval::Minor7th => Self::Minor,
             Interval::Major7th => Self::Major,
             Interval::Perfect8ve => Self::Perfect,
+            Interval::Minor9th => Self::Minor,
+            Interval::Major9th => Self::Major,
+            Interval::Augmented9th => Self::Augmented,
+            Interval::Perfect11th => Self::Perfect,
+            Interval::Augmented11th => Self::Augmented,
+            Interval::Minor13th => Self::Minor,
+            Interval::Major13th => Self::Major,
         }
     }
 }
@@ -90,6 +106,13 @@ impl From<Interval> for Syllable {
             Interval::Minor7th => Self::Te,
             Interval::Major7th => Self::Ti,
             Interval::Perfect8ve => Self::Do,
+            Interval::Minor9th => Self::Ra,
+            Interval::Major9th => Self::Re,
+            Interval::Augmented9th => Self::Ri,
+            Interval::Perfect11th => Self::Fa,
+            Interval::Augmented11th => Self::Fi,
+            Interval::Minor13th => Self::Le,
+            Interval::Major13th => Self::La,
         }
     }
 }
@@ -114,14 +137,25 @@ impl From<Interval> for Semitones {
             Interval::Minor7th => 10,
             Interval::Major7th => 11,
             Interval::Perfect8ve => 12,
+            Interval::Minor9th => 13,
+            Interval::Major9th => 14,
+            Interval::Augmented9th => 15,
+            Interval::Perfect11th => 17,
+            Interval::Augmented11th => 18,
+            Interval::Minor13th => 20,
+            Interval::Major13th => 21,
         }
         .into()
     }
 }
 
+// Spelled with the degree counted from the root, e.g. a minor 3rd above Do is Me, not Ri.
 impl From<(Syllable, Interval)> for Syllable {
     fn from(v: (Syllable, Interval)) -> Self {
-        (Semitones::from(v.0) + Semitones::from(v.1)).into()
+        let semitones = Semitones::from(v.0) + Semitones::from(v.1);
+        let degree = v.0.to_degree_index() + v.1.dot_count() - 1;
+        let natural = Syllable::NATURALS[degree % 7];
+        Syllable::from_degree(degree, semitones - natural.into()).unwrap_or_else(|| semitones.into())
     }
 }
 
@@ -160,7 +194,11 @@ impl From<(Syllable, Syllable)> for Interval {
 
 impl Interval {
     pub fn is_matched(&self, root: Syllable, syllable: Syllable) -> bool {
-        Semitones::from(Interval::from((root, syllable))) == Semitones::from(*self)
+        let offset = Semitones::from(Interval::from((root, syllable))).0 % 12;
+        offset == Semitones::from(*self).0 % 12
+    }
+    pub fn is_compound(&self) -> bool {
+        Semitones::from(*self).0 > 12
     }
     pub fn dot_count(&self) -> usize {
         match self {
@@ -181,6 +219,13 @@ impl Interval {
             Interval::Minor7th => 7,
             Interval::Major7th => 7,
             Interval::Perfect8ve => 1,
+            Interval::Minor9th => 2,
+            Interval::Major9th => 2,
+            Interval::Augmented9th => 2,
+            Interval::Perfect11th => 4,
+            Interval::Augmented11th => 4,
+            Interval::Minor13th => 6,
+            Interval::Major13th => 6,
         }
     }
     pub fn to_text(&self) -> String {
@@ -202,11 +247,33 @@ impl Interval {
             Interval::Minor7th => "7-",
             Interval::Major7th => "7",
             Interval::Perfect8ve => "8",
+            Interval::Minor9th => "9-",
+            Interval::Major9th => "9",
+            Interval::Augmented9th => "9+",
+            Interval::Perfect11th => "11",
+            Interval::Augmented11th => "11+",
+            Interval::Minor13th => "13-",
+            Interval::Major13th => "13",
         }
         .into()
     }
     pub fn from_text(text: &str) -> Self {
-        match text {
+        text.parse().unwrap_or(Self::Tritone)
+    }
+    pub fn to_ident(&self) -> String {
+        format!("{:?}", self)
+    }
+    pub fn syllable_on_root(&self, root: &Syllable) -> Syllable {
+        (Semitones::from(*root) + Semitones::from(*self)).into()
+    }
+}
+
+/// Parses the text from `to_text()`, e.g. `3-`, `5o`, `11+`.
+impl FromStr for Interval {
+    type Err = ParseTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
             "1" => Self::Unison,
             "2-" => Self::Minor2nd,
             "2" => Self::Major2nd,
@@ -224,13 +291,25 @@ impl Interval {
             "7-" => Self::Minor7th,
             "7" => Self::Major7th,
             "8" => Self::Perfect8ve,
-            _ => Self::Tritone,
-        }
-    }
-    pub fn to_ident(&self) -> String {
-        format!("{:?}", self)
+            "9-" => Self::Minor9th,
+            "9" => Self::Major9th,
+            "9+" => Self::Augmented9th,
+            "11" => Self::Perfect11th,
+            "11+" => Self::Augmented11th,
+            "13-" => Self::Minor13th,
+            "13" => Self::Major13th,
+            _ => return Err(ParseTextError::new("Interval", s)),
+        })
     }
-    pub fn syllable_on_root(&self, root: &Syllable) -> Syllable {
-        (Semitones::from(*root) + Semitones::from(*self)).into()
+}
+
+#[test]
+fn test_interval_text_round_trip() {
+    for text in [
+        "1", "2-", "2", "3-", "3", "4", "4+", "t", "5o", "5", "5+", "6-", "6", "7o", "7-", "7", "8",
+        "9-", "9", "9+", "11", "11+", "13-", "13",
+    ] {
+        let interval = text.parse::<Interval>().unwrap();
+        assert_eq!(interval.to_text(), text);
     }
 }
@@ -2,98 +2,104 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
+use crate::inline_vec::{CapacityError, InlineVec};
 use crate::interval::Interval;
 
+// Enough for a full 13th chord, or an altered dominant.
+const MAX_INTERVALS: usize = 7;
+
 //https://en.wikipedia.org/wiki/Chord_(music)
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Debug)]
-pub enum Intervals {
-    Monad,
-    Dyad(Interval),
-    Triad(Interval, Interval),
-    Tetrad(Interval, Interval, Interval),
-    Pentad(Interval, Interval, Interval, Interval),
-}
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Debug, Default)]
+#[serde(transparent)]
+pub struct Intervals(InlineVec<Interval, MAX_INTERVALS>);
+
 impl Display for Intervals {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "<Intervals>({})", self.to_text())
     }
 }
 impl Intervals {
-    fn to_text(&self) -> String {
-        match self {
-            Self::Monad => format!(""),
-            Self::Dyad(n1) => format!("{}", n1),
-            Self::Triad(n1, n2) => format!("{}, {}", n1, n2),
-            Self::Tetrad(n1, n2, n3) => format!("{}, {}, {}", n1, n2, n3),
-            Self::Pentad(n1, n2, n3, n4) => format!("{}, {}, {}, {}", n1, n2, n3, n4),
-        }
+    pub const MAX_LEN: usize = MAX_INTERVALS;
+    pub const MONAD: Self = Self::new(&[]);
+
+    pub const fn new(intervals: &[Interval]) -> Self {
+        Self(InlineVec::from_slice(intervals))
+    }
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn get(&self, index: usize) -> Option<Interval> {
+        self.0.get(index)
+    }
+    fn to_text(self) -> String {
+        self.get_intervals()
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
     }
 }
 impl From<()> for Intervals {
     fn from(_: ()) -> Self {
-        Self::Monad
+        Self::MONAD
     }
 }
 
 impl From<Interval> for Intervals {
     fn from(v: Interval) -> Self {
-        Intervals::Dyad(v)
+        Self::new(&[v])
     }
 }
 
 impl From<(Interval, Interval)> for Intervals {
     fn from(v: (Interval, Interval)) -> Self {
-        Intervals::Triad(v.0, v.1)
+        Self::new(&[v.0, v.1])
     }
 }
 
 impl From<(Interval, Interval, Interval)> for Intervals {
     fn from(v: (Interval, Interval, Interval)) -> Self {
-        Intervals::Tetrad(v.0, v.1, v.2)
+        Self::new(&[v.0, v.1, v.2])
     }
 }
 
 impl From<(Interval, Interval, Interval, Interval)> for Intervals {
     fn from(v: (Interval, Interval, Interval, Interval)) -> Self {
-        Intervals::Pentad(v.0, v.1, v.2, v.3)
+        Self::new(&[v.0, v.1, v.2, v.3])
     }
 }
 
-impl From<Vec<Interval>> for Intervals {
-    fn from(v: Vec<Interval>) -> Self {
-        match v.len() {
-            1 => Self::from(v[0]),
-            2 => Self::from((v[0], v[1])),
-            3 => Self::from((v[0], v[1], v[2])),
-            4 => Self::from((v[0], v[1], v[2], v[3])),
-            _ => {
-                println!("Unsupported Intervals: {}", v.len());
-                Self::Monad
-            }
-        }
+impl TryFrom<&[Interval]> for Intervals {
+    type Error = CapacityError;
+
+    fn try_from(v: &[Interval]) -> Result<Self, Self::Error> {
+        InlineVec::try_from_slice(v).map(Self)
     }
 }
 
-impl From<Vec<Option<Interval>>> for Intervals {
-    fn from(v: Vec<Option<Interval>>) -> Self {
-        let notes = v
-            .iter()
-            .filter(|x| x.is_some())
-            .map(|x| x.unwrap())
-            .collect::<Vec<Interval>>();
-        notes.into()
+impl TryFrom<Vec<Interval>> for Intervals {
+    type Error = CapacityError;
+
+    fn try_from(v: Vec<Interval>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+
+impl TryFrom<Vec<Option<Interval>>> for Intervals {
+    type Error = CapacityError;
+
+    fn try_from(v: Vec<Option<Interval>>) -> Result<Self, Self::Error> {
+        let intervals = v.iter().flatten().copied().collect::<Vec<Interval>>();
+        Self::try_from(intervals)
     }
 }
 
 impl Intervals {
     pub fn get_intervals(&self) -> Vec<Interval> {
-        match *self {
-            Self::Monad => vec![],
-            Self::Dyad(n1) => vec![n1],
-            Self::Triad(n1, n2) => vec![n1, n2],
-            Self::Tetrad(n1, n2, n3) => vec![n1, n2, n3],
-            Self::Pentad(n1, n2, n3, n4) => vec![n1, n2, n3, n4],
-        }
+        self.0.to_vec()
     }
 }
 
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
+use std::str::FromStr;
 
-use crate::prelude::{PitchName, Semitones, Syllable};
+use crate::parse_error::ParseTextError;
+use crate::prelude::{Pitch, PitchName, PitchSign, Semitones, Syllable};
 
 // https://hellomusictheory.com/learn/music-scales-beginners-guide/
 #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
@@ -87,6 +89,36 @@ impl Key {
     }
 }
 
+/// Parses the text from `to_text()`, e.g. `C`, `#F`, `bB`,
+/// the sign can also follow the name, e.g. `Bb`.
+impl FromStr for Key {
+    type Err = ParseTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+        let pitch = match text.chars().next() {
+            Some(sign @ ('#' | 'b')) => match text[1..].parse::<Pitch>() {
+                Ok(x) if x.sign == PitchSign::Natural => {
+                    let sign = if sign == '#' {
+                        PitchSign::Sharp
+                    } else {
+                        PitchSign::Flat
+                    };
+                    Some(Pitch::new(x.name, sign))
+                }
+                _ => None,
+            },
+            _ => text.parse::<Pitch>().ok(),
+        };
+        match pitch.map(|x| (x.sign, x.name)) {
+            Some((PitchSign::Natural, name)) => Ok(Key::Natural(name)),
+            Some((PitchSign::Sharp, name)) => Ok(Key::Sharp(name)),
+            Some((PitchSign::Flat, name)) => Ok(Key::Flat(name)),
+            _ => Err(ParseTextError::new("Key", s)),
+        }
+    }
+}
+
 impl Key {
     pub fn to_ident(&self) -> String {
         match self {
@@ -95,8 +127,8 @@ impl Key {
             Key::Flat(p) => format!("{}_FLAT", p),
         }
     }
-    pub fn from_ident(ident: &str) -> Self {
-        match ident {
+    pub fn from_ident(ident: &str) -> Result<Self, ParseTextError> {
+        Ok(match ident {
             "C" => Self::C,
             "D" => Self::D,
             "E" => Self::E,
@@ -115,8 +147,8 @@ impl Key {
             "G_FLAT" => Self::G_FLAT,
             "A_FLAT" => Self::A_FLAT,
             "B_FLAT" => Self::B_FLAT,
-            _ => Self::default(),
-        }
+            _ => return Err(ParseTextError::new("Key", ident)),
+        })
     }
     pub fn transpose(&self, offset: Semitones) -> Self {
         Key::from(Semitones::from(*self) - offset)
@@ -165,3 +197,11 @@ impl From<Syllable> for Key {
         }
     }
 }
+
+#[test]
+fn test_key_text_round_trip() {
+    for key in Key::ALL {
+        assert_eq!(key.to_text().parse::<Key>(), Ok(key));
+        assert_eq!(Key::from_ident(&key.to_ident()), Ok(key));
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt::Display;
+
+use crate::prelude::{Key, Note, Scale, Semitones};
+
+// https://en.wikipedia.org/wiki/Key_finding (Krumhansl-Kessler probe tone profiles)
+const MAJOR_PROFILE: [f32; 12] = [
+    6.35, 2.23, 3.48, 2.33, 4.38, 4.09, 2.52, 5.19, 2.39, 3.66, 2.29, 2.88,
+];
+const MINOR_PROFILE: [f32; 12] = [
+    6.33, 2.68, 3.52, 5.38, 2.60, 3.53, 2.54, 4.75, 3.98, 2.69, 3.34, 3.17,
+];
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct KeyCandidate {
+    pub key: Key,
+    pub scale: Scale,
+    /// Pearson correlation between the weighted pitch classes and the key profile, from -1.0 to 1.0.
+    pub confidence: f32,
+}
+impl Display for KeyCandidate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} ({:.2})", self.key, self.scale, self.confidence)
+    }
+}
+
+impl KeyCandidate {
+    /// Whether the candidate shares the key signature with the given key and scale,
+    /// e.g. E Minor and A Dorian both match G Major.
+    pub fn is_relative_of(&self, key: &Key, scale: &Scale) -> bool {
+        let do_semitones = self.scale.calc_do_semitones(&self.key);
+        (do_semitones - scale.calc_do_semitones(key)).0.rem_euclid(12) == 0
+    }
+    /// Only major and minor are detected, so modal tabs are fine with the same tonic,
+    /// e.g. E Minor for E Dorian.
+    pub fn agrees_with(&self, key: &Key, scale: &Scale) -> bool {
+        let same_tonic = (Semitones::from(self.key) - Semitones::from(*key)).0.rem_euclid(12) == 0;
+        same_tonic || self.is_relative_of(key, scale)
+    }
+}
+
+fn correlation(histogram: &[f32; 12], profile: &[f32; 12], tonic: usize) -> f32 {
+    let histogram_mean = histogram.iter().sum::<f32>() / 12.0;
+    let profile_mean = profile.iter().sum::<f32>() / 12.0;
+    let mut sum = 0.0;
+    let mut histogram_sum = 0.0;
+    let mut profile_sum = 0.0;
+    for (pitch_class, weight) in histogram.iter().enumerate() {
+        let x = weight - histogram_mean;
+        let y = profile[(pitch_class + 12 - tonic) % 12] - profile_mean;
+        sum += x * y;
+        histogram_sum += x * x;
+        profile_sum += y * y;
+    }
+    if histogram_sum == 0.0 {
+        0.0
+    } else {
+        sum / (histogram_sum * profile_sum).sqrt()
+    }
+}
+
+impl Key {
+    /// Pitch classes from C, each weighted by how long the note is sounding.
+    pub fn calc_pitch_class_histogram(notes: &[(Note, f32)]) -> [f32; 12] {
+        let mut histogram = [0.0; 12];
+        for (note, weight) in notes.iter() {
+            histogram[Semitones::from(*note).0.rem_euclid(12) as usize] += weight;
+        }
+        histogram
+    }
+    /// Major and minor keys ranked by how well they fit the notes, best first.
+    pub fn detect_notes(notes: &[(Note, f32)]) -> Vec<KeyCandidate> {
+        Self::detect_histogram(&Self::calc_pitch_class_histogram(notes))
+    }
+    pub fn detect_histogram(histogram: &[f32; 12]) -> Vec<KeyCandidate> {
+        if histogram.iter().all(|x| *x <= 0.0) {
+            return vec![];
+        }
+        let mut candidates = Vec::new();
+        for tonic in 0..12 {
+            for (scale, profile) in [
+                (Scale::Major, &MAJOR_PROFILE),
+                (Scale::Minor, &MINOR_PROFILE),
+            ] {
+                candidates.push(KeyCandidate {
+                    key: scale.transpose_key(&Key::C, Semitones(tonic as i8)),
+                    scale,
+                    confidence: correlation(histogram, profile, tonic),
+                });
+            }
+        }
+        candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        candidates
+    }
+}
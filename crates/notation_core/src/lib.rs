@@ -1,57 +1,93 @@
+pub mod articulation;
 pub mod chord;
+pub mod chord_recognition;
 pub mod chord_symbol;
 pub mod core_entry;
+pub mod diatonic_chord;
 pub mod duration;
+pub mod dynamic;
 pub mod entry;
+pub mod inline_vec;
 pub mod interval;
 pub mod intervals;
 pub mod key;
+pub mod key_detection;
 pub mod meta_entry;
+pub mod naming_system;
 pub mod note;
+pub mod note_interval;
 pub mod octave;
+pub mod parse_error;
 pub mod pitch;
+pub mod roman_numeral;
 pub mod semitones;
 pub mod scale;
+pub mod scale_pattern;
 pub mod signature;
 pub mod syllable;
 pub mod tempo;
 pub mod tone;
+pub mod tuning;
 
 pub mod prelude {
+    #[doc(hidden)]
+    pub use crate::articulation::Articulation;
     #[doc(hidden)]
     pub use crate::chord::Chord;
     #[doc(hidden)]
+    pub use crate::chord_recognition::ChordCandidate;
+    #[doc(hidden)]
     pub use crate::chord_symbol::{ChordInversion, ChordQuality, ChordSymbol};
     #[doc(hidden)]
     pub use crate::core_entry::CoreEntry;
     #[doc(hidden)]
-    pub use crate::duration::{Duration, Unit, Units};
+    pub use crate::diatonic_chord::{DiatonicChord, DiatonicChordKind};
+    #[doc(hidden)]
+    pub use crate::duration::{Duration, Tuplet, Tuplets, Unit, Units};
+    #[doc(hidden)]
+    pub use crate::dynamic::{Dynamic, DynamicLevel};
     #[doc(hidden)]
     pub use crate::entry::{Entry, EntryPassMode};
     #[doc(hidden)]
+    pub use crate::inline_vec::{CapacityError, InlineVec};
+    #[doc(hidden)]
     pub use crate::interval::{Interval, IntervalQuality};
     #[doc(hidden)]
     pub use crate::intervals::Intervals;
     #[doc(hidden)]
     pub use crate::key::Key;
     #[doc(hidden)]
+    pub use crate::key_detection::KeyCandidate;
+    #[doc(hidden)]
     pub use crate::meta_entry::MetaEntry;
     #[doc(hidden)]
+    pub use crate::naming_system::NamingSystem;
+    #[doc(hidden)]
     pub use crate::note::Note;
     #[doc(hidden)]
+    pub use crate::note_interval::NoteInterval;
+    #[doc(hidden)]
     pub use crate::octave::Octave;
     #[doc(hidden)]
+    pub use crate::parse_error::ParseTextError;
+    #[doc(hidden)]
     pub use crate::pitch::{Pitch, PitchName, PitchSign};
     #[doc(hidden)]
+    pub use crate::roman_numeral::{HarmonicFunction, RomanNumeral, RomanNumeralKind};
+    #[doc(hidden)]
     pub use crate::semitones::Semitones;
     #[doc(hidden)]
     pub use crate::scale::Scale;
     #[doc(hidden)]
-    pub use crate::signature::{Beats, Signature};
+    pub use crate::scale_pattern::ScalePattern;
+    #[doc(hidden)]
+    pub use crate::signature::{BeatGroups, Beats, Signature};
     #[doc(hidden)]
     pub use crate::syllable::Syllable;
     #[doc(hidden)]
-    pub use crate::tempo::{Bpm, BpmRange, Tempo};
+    pub use crate::tempo::{Bpm, BpmRange, Tempo, TempoChange, TempoRamp};
+    #[doc(hidden)]
+    pub use crate::tone::{Tone, ToneNotes};
     #[doc(hidden)]
-    pub use crate::tone::Tone;
+    pub use crate::tuning::{Temperament, Tuning};
 }
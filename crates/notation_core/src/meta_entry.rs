@@ -2,12 +2,14 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
-use crate::prelude::{Entry, Key, Scale, Signature, Tempo};
+use crate::prelude::{Entry, Key, Scale, Signature, Tempo, TempoChange};
 
 #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub enum MetaEntry {
     Signature(Signature),
     Tempo(Tempo),
+    /// Applies from the position of the entry, without waiting for the next bar.
+    TempoChange(TempoChange),
     Scale(Scale),
     Key(Key),
 }
@@ -16,6 +18,7 @@ impl Display for MetaEntry {
         match self {
             MetaEntry::Signature(x) => write!(f, "Signature({})", x),
             MetaEntry::Tempo(x) => write!(f, "Tempo({})", x),
+            MetaEntry::TempoChange(x) => write!(f, "TempoChange({})", x),
             MetaEntry::Scale(x) => write!(f, "Scale({})", x),
             MetaEntry::Key(x) => write!(f, "Key({})", x),
         }
@@ -34,6 +37,11 @@ impl MetaEntry {
     pub fn is_tempo(&self) -> bool {
         matches!(self, Self::Tempo(..))
     }
+
+    /// Returns `true` if the entry is [`TempoChange`].
+    pub fn is_tempo_change(&self) -> bool {
+        matches!(self, Self::TempoChange(..))
+    }
 }
 
 impl From<Signature> for MetaEntry {
@@ -47,3 +55,9 @@ impl From<Tempo> for MetaEntry {
         Self::Tempo(v)
     }
 }
+
+impl From<TempoChange> for MetaEntry {
+    fn from(v: TempoChange) -> Self {
+        Self::TempoChange(v)
+    }
+}
@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{Key, Note, Octave, Scale, Semitones, Syllable};
+
+// https://en.wikipedia.org/wiki/Solf%C3%A8ge
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub enum NamingSystem {
+    /// Do follows the key, modes keep the do of their relative major, e.g. la-based minor.
+    #[default]
+    MoveableDo,
+    /// Do is always the tonic of the scale, e.g. do-based minor with Me, Le and Te.
+    TonicDo,
+    /// Do is always C.
+    FixedDo,
+    /// Numbered notation, with dots for the octaves away from the one starting from do.
+    Jianpu,
+    /// Letter names with H for B.
+    German,
+}
+impl Display for NamingSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl NamingSystem {
+    pub const ALL: [NamingSystem; 5] = [
+        NamingSystem::MoveableDo,
+        NamingSystem::TonicDo,
+        NamingSystem::FixedDo,
+        NamingSystem::Jianpu,
+        NamingSystem::German,
+    ];
+    /// Whether the names are the pitches themselves, so no need to show both.
+    pub fn is_pitch_name(&self) -> bool {
+        matches!(self, NamingSystem::German)
+    }
+    /// The syllable in this system for the moveable do syllable in the scale.
+    pub fn calc_syllable(&self, scale: &Scale, key: &Key, syllable: &Syllable) -> Syllable {
+        match self {
+            NamingSystem::TonicDo => {
+                let tonic = scale.calc_root_syllable();
+                let semitones = Semitones::from(*syllable) - Semitones::from(tonic);
+                let degree = (syllable.to_degree_index() as isize
+                    - tonic.to_degree_index() as isize)
+                    .rem_euclid(7) as usize;
+                let natural = Syllable::NATURALS[degree];
+                Syllable::from_degree(degree, semitones - natural.into())
+                    .unwrap_or_else(|| semitones.into())
+            }
+            NamingSystem::FixedDo => {
+                let pitch = scale.calc_pitch(key, syllable);
+                Syllable::from((pitch.sign, pitch.name))
+            }
+            _ => *syllable,
+        }
+    }
+    /// Octaves away from the one starting from do around middle C.
+    pub fn calc_jianpu_octave(scale: &Scale, key: &Key, note: &Note) -> i8 {
+        let do_semitones = Semitones::from(Octave::CENTER) + scale.calc_do_semitones(key);
+        (Semitones::from(*note) - do_semitones).0.div_euclid(12)
+    }
+    /// Syllables are shown as numbers or idents for the solfege systems, octave is only used
+    /// by jianpu, to add the dots.
+    pub fn calc_text(
+        &self,
+        scale: &Scale,
+        key: &Key,
+        syllable: &Syllable,
+        note: Option<&Note>,
+        as_num: bool,
+    ) -> String {
+        match self {
+            NamingSystem::Jianpu => {
+                let octave = note
+                    .map(|x| Self::calc_jianpu_octave(scale, key, x))
+                    .unwrap_or(0);
+                syllable.to_jianpu_text(octave)
+            }
+            NamingSystem::German => scale.calc_pitch(key, syllable).to_german_text(),
+            _ => {
+                let syllable = self.calc_syllable(scale, key, syllable);
+                if as_num {
+                    syllable.to_text()
+                } else {
+                    syllable.to_ident()
+                }
+            }
+        }
+    }
+}
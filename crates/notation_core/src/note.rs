@@ -1,7 +1,9 @@
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
+use crate::parse_error::ParseTextError;
 use crate::prelude::{Octave, Pitch, Syllable, Semitones};
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Debug)]
@@ -13,7 +15,7 @@ pub struct Note {
 
 impl Display for Note {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {} {}", self.octave, self.pitch, self.syllable)
+        write!(f, "{}", self.to_text())
     }
 }
 
@@ -21,6 +23,15 @@ impl Note {
     pub fn new(octave: Octave, pitch: Pitch, syllable: Syllable) -> Self {
         Self { octave, pitch, syllable }
     }
+    /// Pitch with octave number, then the syllable, e.g. `C#4 (1#)`.
+    pub fn to_text(&self) -> String {
+        let octave = self.octave.to_number();
+        format!("{}{} ({})", self.pitch, octave, self.syllable)
+    }
+    /// MIDI key number, C4 (middle C) is 60.
+    pub fn to_midi_key(&self) -> i16 {
+        Semitones::from(*self).0 as i16 + 12
+    }
 }
 
 impl From<(Octave, Pitch, Syllable)> for Note {
@@ -36,3 +47,40 @@ impl From<Note> for Semitones {
         Self(octave_val + pitch_val)
     }
 }
+
+/// Parses the text from `to_text()`, without the syllable it's taken as in C major, e.g. `Eb3`.
+impl FromStr for Note {
+    type Err = ParseTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseTextError::new("Note", s);
+        let (main, syllable) = match s.trim().split_once('(') {
+            Some((main, rest)) => {
+                let syllable = rest.strip_suffix(')').ok_or_else(err)?;
+                (main.trim(), Some(syllable.parse::<Syllable>().map_err(|_| err())?))
+            }
+            None => (s.trim(), None),
+        };
+        let (pitch, octave) = Pitch::split_text(main).ok_or_else(err)?;
+        let octave = octave
+            .parse::<i8>()
+            .ok()
+            .and_then(Octave::from_number)
+            .ok_or_else(err)?;
+        let syllable = syllable.unwrap_or_else(|| Syllable::from((pitch.sign, pitch.name)));
+        Ok(Self::new(octave, pitch, syllable))
+    }
+}
+
+#[test]
+fn test_note_text_round_trip() {
+    use crate::prelude::{Key, Scale};
+    for key in Key::ALL {
+        for scale in [Scale::Ionian, Scale::Aeolian, Scale::HarmonicMinor] {
+            for semitones in 12..120 {
+                let note = scale.calc_note_from_semitones(&key, Semitones(semitones));
+                assert_eq!(note.to_string().parse::<Note>(), Ok(note), "{}", note);
+            }
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use std::fmt::Display;
+use std::ops::{Add, Sub};
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{
+    Interval, IntervalQuality, Note, Octave, Pitch, PitchName, PitchSign, Semitones, Syllable,
+};
+
+// https://en.wikipedia.org/wiki/Interval_(music)
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct NoteInterval {
+    /// Diatonic steps between the two notes, 0 for unison, 7 for octave, 9 for a 10th.
+    pub steps: u8,
+    /// Semitones between the two notes, negative only for a few odd spellings, e.g. B# to Cb.
+    pub semitones: i8,
+    pub descending: bool,
+}
+impl Display for NoteInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_text())
+    }
+}
+
+impl NoteInterval {
+    // Semitones of the major or perfect interval for each simple number.
+    const NATURAL_SEMITONES: [i8; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+    pub const fn new(steps: u8, semitones: i8, descending: bool) -> Self {
+        Self {
+            steps,
+            semitones,
+            descending,
+        }
+    }
+    /// Count the notes from one to the other, 1 for unison, 8 for octave, 10 for a 10th.
+    pub fn number(&self) -> u8 {
+        self.steps + 1
+    }
+    pub fn octaves(&self) -> u8 {
+        self.steps / 7
+    }
+    /// Larger than an octave, e.g. 9th, 10th.
+    pub fn is_compound(&self) -> bool {
+        self.steps > 7
+    }
+    /// Within an octave, e.g. 3rd for 10th, octave is kept as octave.
+    pub fn to_simple(&self) -> Self {
+        if !self.is_compound() {
+            return *self;
+        }
+        let octaves = (self.steps - 1) / 7;
+        Self::new(
+            self.steps - octaves * 7,
+            self.semitones - octaves as i8 * 12,
+            self.descending,
+        )
+    }
+    pub fn is_perfect_number(&self) -> bool {
+        matches!(self.steps % 7, 0 | 3 | 4)
+    }
+    /// Semitones away from the major or perfect interval of the same number.
+    pub fn alteration(&self) -> i8 {
+        let natural =
+            Self::NATURAL_SEMITONES[(self.steps % 7) as usize] + self.octaves() as i8 * 12;
+        self.semitones - natural
+    }
+    /// Returns None for doubly diminished or augmented intervals.
+    pub fn quality(&self) -> Option<IntervalQuality> {
+        match (self.is_perfect_number(), self.alteration()) {
+            (true, -1) => Some(IntervalQuality::Diminished),
+            (true, 0) => Some(IntervalQuality::Perfect),
+            (false, -2) => Some(IntervalQuality::Diminished),
+            (false, -1) => Some(IntervalQuality::Minor),
+            (false, 0) => Some(IntervalQuality::Major),
+            (_, 1) => Some(IntervalQuality::Augmented),
+            _ => None,
+        }
+    }
+    pub fn to_semitones(&self) -> Semitones {
+        Semitones(if self.descending {
+            -self.semitones
+        } else {
+            self.semitones
+        })
+    }
+    /// Short name, e.g. "P5", "m10", "A4", with "-" in front when descending.
+    pub fn to_text(&self) -> String {
+        let alteration = self.alteration();
+        let quality = match self.quality() {
+            Some(IntervalQuality::Diminished) => "d".to_owned(),
+            Some(IntervalQuality::Minor) => "m".to_owned(),
+            Some(IntervalQuality::Perfect) => "P".to_owned(),
+            Some(IntervalQuality::Major) => "M".to_owned(),
+            Some(IntervalQuality::Augmented) => "A".to_owned(),
+            _ if alteration > 0 => "A".repeat(alteration as usize),
+            _ => {
+                let count = if self.is_perfect_number() { -alteration } else { -alteration - 1 };
+                "d".repeat(count as usize)
+            }
+        };
+        let direction = if self.descending { "-" } else { "" };
+        format!("{}{}{}", direction, quality, self.number())
+    }
+}
+
+impl NoteInterval {
+    fn diatonic_index(pitch: &Pitch, octave: &Octave) -> i16 {
+        let octave_index = Semitones::from(*octave).0 as i16 / 12;
+        octave_index * 7 + pitch.name.to_index() as i16
+    }
+    /// From one note to the other, descending if the second one is lower.
+    pub fn between(from: &Note, to: &Note) -> Self {
+        let steps = Self::diatonic_index(&to.pitch, &to.octave)
+            - Self::diatonic_index(&from.pitch, &from.octave);
+        let semitones = (Semitones::from(*to) - Semitones::from(*from)).0;
+        if steps < 0 || (steps == 0 && semitones < 0) {
+            Self::new(-steps as u8, -semitones, true)
+        } else {
+            Self::new(steps as u8, semitones, false)
+        }
+    }
+    /// From one pitch up to the next occurrence of the other, always within an octave.
+    pub fn between_pitches(from: &Pitch, to: &Pitch) -> Self {
+        let from_index = from.name.to_index() as i8;
+        let to_index = to.name.to_index() as i8;
+        let octave = if to_index < from_index { 12 } else { 0 };
+        let semitones = (Semitones::from(*to) - Semitones::from(*from)).0 + octave;
+        Self::new((to_index - from_index).rem_euclid(7) as u8, semitones, false)
+    }
+    /// Spelled from the degree of the note, e.g. a minor 3rd above A is C, not B#,
+    /// None if the result is out of the octave range.
+    pub fn add_to_note(&self, note: &Note) -> Option<Note> {
+        let (steps, semitones) = if self.descending {
+            (-(self.steps as i16), -(self.semitones as i16))
+        } else {
+            (self.steps as i16, self.semitones as i16)
+        };
+        let index = Self::diatonic_index(&note.pitch, &note.octave) + steps;
+        let octave = Octave::from_number(i8::try_from(index.div_euclid(7)).ok()?)?;
+        let name = PitchName::from_index(index as isize);
+        let target = Semitones(i8::try_from(Semitones::from(*note).0 as i16 + semitones).ok()?);
+        let natural = Semitones::from((Pitch::new(name, PitchSign::Natural), octave));
+        let alteration = (target - natural).0;
+        let (pitch, octave) = if (-2..=2).contains(&alteration) {
+            (Pitch::new(name, PitchSign::from(Semitones(alteration))), octave)
+        } else {
+            target.as_pitch_octave()
+        };
+        let syllable_semitones = Semitones::from(note.syllable).0 as i16 + semitones;
+        let degree = (note.syllable.to_degree_index() as i16 + steps).rem_euclid(7) as usize;
+        let syllable_natural = Semitones::from(Syllable::NATURALS[degree]).0 as i16;
+        let syllable_alteration =
+            Semitones(((syllable_semitones - syllable_natural + 6).rem_euclid(12) - 6) as i8);
+        let syllable = Syllable::from_degree(degree, syllable_alteration)
+            .unwrap_or_else(|| Semitones(syllable_semitones.rem_euclid(12) as i8).into());
+        Some(Note::new(octave, pitch, syllable))
+    }
+    pub fn reversed(&self) -> Self {
+        Self::new(self.steps, self.semitones, !self.descending)
+    }
+}
+
+impl From<Interval> for NoteInterval {
+    fn from(v: Interval) -> Self {
+        let semitones = Semitones::from(v).0;
+        let octave = if semitones >= 12 { 7 } else { 0 };
+        Self::new(v.dot_count() as u8 - 1 + octave, semitones, false)
+    }
+}
+
+impl Interval {
+    pub fn between(from: &Note, to: &Note) -> NoteInterval {
+        NoteInterval::between(from, to)
+    }
+}
+
+impl Add<NoteInterval> for Note {
+    type Output = Option<Note>;
+
+    fn add(self, rhs: NoteInterval) -> Self::Output {
+        rhs.add_to_note(&self)
+    }
+}
+
+impl Sub<NoteInterval> for Note {
+    type Output = Option<Note>;
+
+    fn sub(self, rhs: NoteInterval) -> Self::Output {
+        rhs.reversed().add_to_note(&self)
+    }
+}
+
+impl Add<Interval> for Note {
+    type Output = Option<Note>;
+
+    fn add(self, rhs: Interval) -> Self::Output {
+        NoteInterval::from(rhs).add_to_note(&self)
+    }
+}
+
+impl Sub<Interval> for Note {
+    type Output = Option<Note>;
+
+    fn sub(self, rhs: Interval) -> Self::Output {
+        NoteInterval::from(rhs).reversed().add_to_note(&self)
+    }
+}
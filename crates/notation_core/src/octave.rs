@@ -82,6 +82,27 @@ impl Octave {
             _ => Self::N1,
         }
     }
+    /// Number in scientific pitch notation, e.g. 4 for the octave of middle C.
+    pub fn to_number(&self) -> i8 {
+        Semitones::from(*self).0 / 12
+    }
+    pub fn from_number(number: i8) -> Option<Self> {
+        match number {
+            -1 => Some(Self::N1),
+            0 => Some(Self::P0),
+            1 => Some(Self::P1),
+            2 => Some(Self::P2),
+            3 => Some(Self::P3),
+            4 => Some(Self::P4),
+            5 => Some(Self::P5),
+            6 => Some(Self::P6),
+            7 => Some(Self::P7),
+            8 => Some(Self::P8),
+            9 => Some(Self::P9),
+            10 => Some(Self::P10),
+            _ => None,
+        }
+    }
     pub fn get_higher(&self) -> Self {
         (Semitones::from(*self) + Semitones(12)).into()
     }
@@ -0,0 +1,22 @@
+use std::fmt::Display;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseTextError {
+    pub type_name: &'static str,
+    pub text: String,
+}
+impl Display for ParseTextError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid {}: {:?}", self.type_name, self.text)
+    }
+}
+impl std::error::Error for ParseTextError {}
+
+impl ParseTextError {
+    pub fn new(type_name: &'static str, text: &str) -> Self {
+        Self {
+            type_name,
+            text: text.to_owned(),
+        }
+    }
+}
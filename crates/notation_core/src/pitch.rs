@@ -1,7 +1,9 @@
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
+use crate::parse_error::ParseTextError;
 use crate::prelude::{Key, Semitones};
 
 // https://hellomusictheory.com/learn/
@@ -23,6 +25,35 @@ impl Display for PitchName {
     }
 }
 
+impl PitchName {
+    pub const ALL: [PitchName; 7] = [
+        PitchName::C,
+        PitchName::D,
+        PitchName::E,
+        PitchName::F,
+        PitchName::G,
+        PitchName::A,
+        PitchName::B,
+    ];
+    pub fn to_index(&self) -> usize {
+        match self {
+            PitchName::C => 0,
+            PitchName::D => 1,
+            PitchName::E => 2,
+            PitchName::F => 3,
+            PitchName::G => 4,
+            PitchName::A => 5,
+            PitchName::B => 6,
+        }
+    }
+    pub fn from_index(index: isize) -> Self {
+        Self::ALL[index.rem_euclid(7) as usize]
+    }
+    pub fn offset(&self, steps: isize) -> Self {
+        Self::from_index(self.to_index() as isize + steps)
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Debug)]
 pub enum PitchSign {
     DoubleFlat,
@@ -214,6 +245,24 @@ impl Pitch {
     pub fn new(name: PitchName, sign: PitchSign) -> Self {
         Self { name, sign }
     }
+    /// The pitch of the semitones with the letter name, None if it needs more than two signs.
+    pub fn spell(name: PitchName, semitones: Semitones) -> Option<Self> {
+        match (semitones - Semitones::from(name)).0.rem_euclid(12) {
+            diff @ 0..=2 => Some(Pitch::new(name, PitchSign::from(Semitones(diff)))),
+            diff @ 10..=11 => Some(Pitch::new(name, PitchSign::from(Semitones(diff - 12)))),
+            _ => None,
+        }
+    }
+    /// Spellings of the same pitch, from the one with fewest signs.
+    pub fn enharmonics(&self) -> Vec<Self> {
+        let semitones = Semitones::from(*self);
+        let mut result: Vec<Self> = PitchName::ALL
+            .iter()
+            .filter_map(|x| Self::spell(*x, semitones))
+            .collect();
+        result.sort_by_key(|x| Semitones::from(x.sign).0.abs());
+        result
+    }
 }
 
 impl From<PitchName> for Semitones {
@@ -300,6 +349,25 @@ impl Pitch {
     pub fn to_text(&self) -> String {
         format!("{}", self)
     }
+    // https://en.wikipedia.org/wiki/Musical_note#12-tone_chromatic_scale
+    /// German names, B natural is H, B flat is B, flats and sharps are suffixed, e.g. Es, Fis.
+    pub fn to_german_text(&self) -> String {
+        let suffix = match self.sign {
+            PitchSign::DoubleFlat => "eses",
+            PitchSign::Flat => "es",
+            PitchSign::Natural => "",
+            PitchSign::Sharp => "is",
+            PitchSign::DoubleSharp => "isis",
+        };
+        match (self.name, self.sign) {
+            (PitchName::B, PitchSign::Flat) => "B".to_owned(),
+            (PitchName::B, _) => format!("H{}", suffix),
+            (PitchName::A | PitchName::E, PitchSign::Flat | PitchSign::DoubleFlat) => {
+                format!("{}{}", self.name, &suffix[1..])
+            }
+            _ => format!("{}{}", self.name, suffix),
+        }
+    }
     pub fn from_text(text: &str) -> Self {
         match text {
             "C" => Pitch::C,
@@ -341,3 +409,48 @@ impl Pitch {
         }
     }
 }
+
+impl Pitch {
+    /// Takes the pitch from the start of the text, returns it with the rest, e.g. `m7` for `C#m7`.
+    pub(crate) fn split_text(text: &str) -> Option<(Pitch, &str)> {
+        let mut chars = text.chars();
+        let name = match chars.next()? {
+            'C' => PitchName::C,
+            'D' => PitchName::D,
+            'E' => PitchName::E,
+            'F' => PitchName::F,
+            'G' => PitchName::G,
+            'A' => PitchName::A,
+            'B' => PitchName::B,
+            _ => return None,
+        };
+        let rest = chars.as_str();
+        for (prefix, sign) in [
+            ("##", PitchSign::DoubleSharp),
+            ("bb", PitchSign::DoubleFlat),
+            ("𝄪", PitchSign::DoubleSharp),
+            ("𝄫", PitchSign::DoubleFlat),
+            ("#", PitchSign::Sharp),
+            ("♯", PitchSign::Sharp),
+            ("b", PitchSign::Flat),
+            ("♭", PitchSign::Flat),
+        ] {
+            if let Some(rest) = rest.strip_prefix(prefix) {
+                return Some((Pitch::new(name, sign), rest));
+            }
+        }
+        Some((Pitch::new(name, PitchSign::Natural), rest))
+    }
+}
+
+/// Parses the text from `to_text()`, e.g. `C`, `F#`, `Bbb`, unicode signs are accepted too.
+impl FromStr for Pitch {
+    type Err = ParseTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Self::split_text(s.trim()) {
+            Some((pitch, "")) => Ok(pitch),
+            _ => Err(ParseTextError::new("Pitch", s)),
+        }
+    }
+}
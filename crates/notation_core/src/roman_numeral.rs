@@ -0,0 +1,239 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{Chord, ChordQuality, Interval, Scale, ScalePattern, Semitones, Syllable};
+
+// https://en.wikipedia.org/wiki/Function_(music)
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum HarmonicFunction {
+    Tonic,
+    Predominant,
+    Dominant,
+}
+impl Display for HarmonicFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl HarmonicFunction {
+    pub fn to_symbol(&self) -> &'static str {
+        match self {
+            HarmonicFunction::Tonic => "T",
+            HarmonicFunction::Predominant => "PD",
+            HarmonicFunction::Dominant => "D",
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum RomanNumeralKind {
+    Diatonic,
+    /// Dominant of another degree, e.g. V/V, target is the degree index it's resolving to.
+    SecondaryDominant(usize),
+    /// Borrowed from a parallel mode, e.g. bVII or iv in major.
+    Borrowed,
+    Chromatic,
+}
+impl Display for RomanNumeralKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+// https://en.wikipedia.org/wiki/Roman_numeral_analysis
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct RomanNumeral {
+    /// 0-based degree counted from the tonic of the scale.
+    pub degree: usize,
+    /// Offset from the scale degree, e.g. -1 for bVII in major.
+    pub alteration: Semitones,
+    pub minor: bool,
+    pub quality: Option<ChordQuality>,
+    pub kind: RomanNumeralKind,
+    pub function: Option<HarmonicFunction>,
+    /// Needed to show the case of the target in secondary dominants.
+    pub minor_target: bool,
+}
+impl Display for RomanNumeral {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = match self.alteration.0 {
+            x if x < 0 => "b".repeat(-x as usize),
+            x => "#".repeat(x as usize),
+        };
+        match self.kind {
+            RomanNumeralKind::SecondaryDominant(target) => write!(
+                f,
+                "V{}/{}",
+                self.suffix(),
+                Self::numeral(target, self.minor_target)
+            ),
+            _ => write!(
+                f,
+                "{}{}{}",
+                sign,
+                Self::numeral(self.degree, self.minor),
+                self.suffix()
+            ),
+        }
+    }
+}
+
+impl RomanNumeral {
+    pub const NUMERALS: [&'static str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+    // Parallel modes to borrow chords from, most common ones first.
+    pub const BORROW_FROM: [ScalePattern; 5] = [
+        ScalePattern::AEOLIAN,
+        ScalePattern::IONIAN,
+        ScalePattern::MIXOLYDIAN,
+        ScalePattern::DORIAN,
+        ScalePattern::HARMONIC_MINOR,
+    ];
+
+    fn numeral(degree: usize, minor: bool) -> String {
+        let numeral = Self::NUMERALS[degree % 7];
+        if minor {
+            numeral.to_lowercase()
+        } else {
+            numeral.to_owned()
+        }
+    }
+    pub fn suffix(&self) -> &'static str {
+        match self.quality {
+            None | Some(ChordQuality::Major) | Some(ChordQuality::Minor) => "",
+            Some(ChordQuality::Diminished) => "°",
+            Some(ChordQuality::Diminished7th) => "°7",
+            Some(ChordQuality::HaldDiminished7th) => "ø7",
+            Some(ChordQuality::Augmented) => "+",
+            Some(ChordQuality::AugmentedMajor7th) => "+maj7",
+            Some(ChordQuality::Minor7th) => "7",
+            Some(ChordQuality::MinorMajor7th) => "maj7",
+            Some(ChordQuality::Minor6th) => "6",
+            Some(ChordQuality::MinorAdd9th) => "add9",
+            Some(ChordQuality::Minor9th) => "9",
+            Some(ChordQuality::Minor11th) => "11",
+            Some(ChordQuality::Minor13th) => "13",
+            Some(quality) => quality.to_symbol(),
+        }
+    }
+    pub fn is_diatonic(&self) -> bool {
+        self.kind == RomanNumeralKind::Diatonic
+    }
+    /// Non-heptatonic scales are analysed with the church mode sharing the same tonic.
+    fn reference_pattern(scale: &Scale) -> Option<ScalePattern> {
+        let pattern = scale.get_pattern();
+        if pattern.len() == 7 {
+            Some(pattern)
+        } else {
+            scale.get_mode().map(|x| x.get_pattern())
+        }
+    }
+    // Pitch classes counted from the tonic.
+    fn pattern_classes(pattern: &ScalePattern) -> Vec<i8> {
+        pattern
+            .degrees
+            .iter()
+            .map(|x| Semitones::from(x).0.rem_euclid(12))
+            .collect()
+    }
+    fn chord_classes(chord: &Chord, tonic: Syllable) -> Vec<i8> {
+        let root = Semitones::from(chord.root) - Semitones::from(tonic);
+        let mut intervals = chord.intervals.get_intervals();
+        intervals.push(Interval::Unison);
+        if let Some(bass) = chord.bass {
+            intervals.push(bass);
+        }
+        intervals
+            .into_iter()
+            .map(|x| (root + x.into()).0.rem_euclid(12))
+            .collect()
+    }
+    fn degree_function(degree: usize, alteration: Semitones) -> HarmonicFunction {
+        match (degree, alteration.0) {
+            (1, -1) | (5, -1) => HarmonicFunction::Predominant,
+            (0, _) | (2, _) | (5, _) => HarmonicFunction::Tonic,
+            (1, _) | (3, _) => HarmonicFunction::Predominant,
+            _ => HarmonicFunction::Dominant,
+        }
+    }
+    // A dominant is major with a perfect fifth (can be omitted), and minor 7th if there is one.
+    fn is_dominant_shape(chord: &Chord) -> bool {
+        let classes = chord
+            .intervals
+            .get_intervals()
+            .iter()
+            .map(|x| Semitones::from(*x).0.rem_euclid(12))
+            .collect::<Vec<i8>>();
+        classes.contains(&4)
+            && !classes.contains(&3)
+            && !classes.contains(&6)
+            && !classes.contains(&8)
+            && !classes.contains(&11)
+    }
+    fn has_minor_third(chord: &Chord) -> bool {
+        chord
+            .intervals
+            .get_intervals()
+            .iter()
+            .any(|x| Semitones::from(*x) == Semitones(3))
+    }
+    // Only major and minor triads from ii to vi are tonicized, otherwise it's just V or borrowed.
+    fn can_be_target(classes: &[i8], degree: usize) -> bool {
+        (1..=5).contains(&degree)
+            && (classes[(degree + 4) % 7] - classes[degree]).rem_euclid(12) == 7
+    }
+    /// None for non-heptatonic scales without a church mode on the same tonic.
+    pub fn from_chord(chord: &Chord, scale: &Scale) -> Option<Self> {
+        let pattern = Self::reference_pattern(scale)?;
+        let classes = Self::pattern_classes(&pattern);
+        let tonic = pattern.tonic;
+        let degree = (chord.root.to_degree_index() as isize - tonic.to_degree_index() as isize)
+            .rem_euclid(7) as usize;
+        let root = (Semitones::from(chord.root) - Semitones::from(tonic))
+            .0
+            .rem_euclid(12);
+        let alteration = match (root - classes[degree]).rem_euclid(12) {
+            x if x > 6 => x - 12,
+            x => x,
+        };
+        let chord_classes = Self::chord_classes(chord, tonic);
+        let in_scale =
+            |scale_classes: &Vec<i8>| chord_classes.iter().all(|x| scale_classes.contains(x));
+        let target = classes
+            .iter()
+            .position(|x| *x == (root + 5).rem_euclid(12))
+            .filter(|x| Self::can_be_target(&classes, *x));
+        let kind = if in_scale(&classes) {
+            RomanNumeralKind::Diatonic
+        } else if let Some(target) = target.filter(|_| Self::is_dominant_shape(chord)) {
+            RomanNumeralKind::SecondaryDominant(target)
+        } else if Self::BORROW_FROM
+            .iter()
+            .any(|x| in_scale(&Self::pattern_classes(x)))
+        {
+            RomanNumeralKind::Borrowed
+        } else {
+            RomanNumeralKind::Chromatic
+        };
+        let function = match kind {
+            RomanNumeralKind::SecondaryDominant(_) => Some(HarmonicFunction::Dominant),
+            RomanNumeralKind::Chromatic => None,
+            _ => Some(Self::degree_function(degree, Semitones(alteration))),
+        };
+        let minor_target = match kind {
+            RomanNumeralKind::SecondaryDominant(target) => {
+                (classes[(target + 2) % 7] - classes[target]).rem_euclid(12) == 3
+            }
+            _ => false,
+        };
+        Some(Self {
+            degree,
+            alteration: Semitones(alteration),
+            minor: Self::has_minor_third(chord),
+            quality: ChordQuality::from_intervals(&chord.intervals),
+            kind,
+            function,
+            minor_target,
+        })
+    }
+}
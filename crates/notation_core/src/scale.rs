@@ -1,7 +1,12 @@
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt::Display;
+use std::str::FromStr;
 
-use crate::prelude::{Chord, Key, Note, Octave, Pitch, Semitones, Syllable};
+use crate::parse_error::ParseTextError;
+use crate::prelude::{
+    Chord, Key, Note, Octave, Pitch, PitchSign, ScalePattern, Semitones, Syllable,
+};
 use crate::tone::Tone;
 
 // https://hellomusictheory.com/learn/music-scales-beginners-guide/
@@ -14,12 +19,61 @@ pub enum Scale {
     Mixolydian,
     Aeolian,
     Locrian,
+    HarmonicMinor,
+    MelodicMinor,
+    MajorPentatonic,
+    MinorPentatonic,
+    Blues,
+    WholeTone,
+    Custom(ScalePattern),
 }
 impl Display for Scale {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Scale::Custom(pattern) => write!(f, "Custom({})", pattern),
+            _ => write!(f, "{:?}", self),
+        }
     }
 }
+
+/// Parses the text from `to_ident()`, e.g. `Dorian`, `Custom(6: 1 2 3- 4 5 6- 7)`,
+/// `Major`, `Minor` and `Pentatonic` are accepted as well.
+impl FromStr for Scale {
+    type Err = ParseTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+        if let Some(pattern) = text
+            .strip_prefix("Custom(")
+            .and_then(|x| x.strip_suffix(')'))
+        {
+            return pattern
+                .parse::<ScalePattern>()
+                .map(Scale::Custom)
+                .map_err(|_| ParseTextError::new("Scale", s));
+        }
+        Ok(match text {
+            "Major" => Self::Major,
+            "Minor" => Self::Minor,
+            "Ionian" => Self::Ionian,
+            "Dorian" => Self::Dorian,
+            "Phrygian" => Self::Phrygian,
+            "Lydian" => Self::Lydian,
+            "Mixolydian" => Self::Mixolydian,
+            "Aeolian" => Self::Aeolian,
+            "Locrian" => Self::Locrian,
+            "HarmonicMinor" => Self::HarmonicMinor,
+            "MelodicMinor" => Self::MelodicMinor,
+            "MajorPentatonic" => Self::MajorPentatonic,
+            "Pentatonic" => Self::MajorPentatonic,
+            "MinorPentatonic" => Self::MinorPentatonic,
+            "Blues" => Self::Blues,
+            "WholeTone" => Self::WholeTone,
+            _ => return Err(ParseTextError::new("Scale", s)),
+        })
+    }
+}
+
 impl Default for Scale {
     fn default() -> Self {
         Self::Ionian
@@ -30,94 +84,50 @@ impl Scale {
     pub const Major: Scale = Scale::Ionian;
     #[allow(non_upper_case_globals)]
     pub const Minor: Scale = Scale::Aeolian;
-    pub const ALL: [ Scale; 7 ] = [
+    pub const ALL: [ Scale; 13 ] = [
+        Scale::Ionian, Scale::Dorian, Scale::Phrygian, Scale::Lydian, Scale::Mixolydian, Scale::Aeolian, Scale::Locrian,
+        Scale::HarmonicMinor, Scale::MelodicMinor, Scale::MajorPentatonic, Scale::MinorPentatonic, Scale::Blues, Scale::WholeTone,
+    ];
+    pub const MODES: [ Scale; 7 ] = [
         Scale::Ionian, Scale::Dorian, Scale::Phrygian, Scale::Lydian, Scale::Mixolydian, Scale::Aeolian, Scale::Locrian,
     ];
 
     pub fn to_ident(&self) -> String {
         format!("{}", self)
     }
-    pub fn from_ident(ident: &str) -> Self {
-        match ident {
-            "Major" => Self::Major,
-            "Minor" => Self::Minor,
-            "Ionian" => Self::Ionian,
-            "Dorian" => Self::Dorian,
-            "Phrygian" => Self::Phrygian,
-            "Lydian" => Self::Lydian,
-            "Mixolydian" => Self::Mixolydian,
-            "Aeolian" => Self::Aeolian,
-            "Locrian" => Self::Locrian,
-            _ => Self::default(),
-        }
+    pub fn from_ident(ident: &str) -> Result<Self, ParseTextError> {
+        ident.parse()
     }
-    pub fn get_syllables(&self) -> Vec<Syllable> {
+    pub fn get_pattern(&self) -> ScalePattern {
         match self {
-            Scale::Ionian => vec![
-                Syllable::Do,
-                Syllable::Re,
-                Syllable::Mi,
-                Syllable::Fa,
-                Syllable::So,
-                Syllable::La,
-                Syllable::Ti,
-            ],
-            Scale::Dorian => vec![
-                Syllable::Re,
-                Syllable::Mi,
-                Syllable::Fa,
-                Syllable::So,
-                Syllable::La,
-                Syllable::Ti,
-                Syllable::Do,
-            ],
-            Scale::Phrygian => vec![
-                Syllable::Mi,
-                Syllable::Fa,
-                Syllable::So,
-                Syllable::La,
-                Syllable::Ti,
-                Syllable::Do,
-                Syllable::Re,
-            ],
-            Scale::Lydian => vec![
-                Syllable::Fa,
-                Syllable::So,
-                Syllable::La,
-                Syllable::Ti,
-                Syllable::Do,
-                Syllable::Re,
-                Syllable::Mi,
-            ],
-            Scale::Mixolydian => vec![
-                Syllable::So,
-                Syllable::La,
-                Syllable::Ti,
-                Syllable::Do,
-                Syllable::Re,
-                Syllable::Mi,
-                Syllable::Fa,
-            ],
-            Scale::Aeolian => vec![
-                Syllable::La,
-                Syllable::Ti,
-                Syllable::Do,
-                Syllable::Re,
-                Syllable::Mi,
-                Syllable::Fa,
-                Syllable::So,
-            ],
-            Scale::Locrian => vec![
-                Syllable::Ti,
-                Syllable::Do,
-                Syllable::Re,
-                Syllable::Mi,
-                Syllable::Fa,
-                Syllable::So,
-                Syllable::La,
-            ],
+            Scale::Ionian => ScalePattern::IONIAN,
+            Scale::Dorian => ScalePattern::DORIAN,
+            Scale::Phrygian => ScalePattern::PHRYGIAN,
+            Scale::Lydian => ScalePattern::LYDIAN,
+            Scale::Mixolydian => ScalePattern::MIXOLYDIAN,
+            Scale::Aeolian => ScalePattern::AEOLIAN,
+            Scale::Locrian => ScalePattern::LOCRIAN,
+            Scale::HarmonicMinor => ScalePattern::HARMONIC_MINOR,
+            Scale::MelodicMinor => ScalePattern::MELODIC_MINOR,
+            Scale::MajorPentatonic => ScalePattern::MAJOR_PENTATONIC,
+            Scale::MinorPentatonic => ScalePattern::MINOR_PENTATONIC,
+            Scale::Blues => ScalePattern::BLUES,
+            Scale::WholeTone => ScalePattern::WHOLE_TONE,
+            Scale::Custom(pattern) => *pattern,
         }
     }
+    // The church mode sharing the same tonic, which decides the key signature,
+    // None if the tonic is not on a major scale degree.
+    pub fn get_mode(&self) -> Option<Scale> {
+        let tonic = Semitones::from(self.get_pattern().tonic);
+        Self::MODES
+            .iter()
+            .find(|x| Semitones::from(x.get_pattern().tonic) == tonic)
+            .copied()
+    }
+    pub fn get_syllables(&self) -> Vec<Syllable> {
+        self.get_pattern().get_syllables()
+    }
     pub fn calc_key_index(&self, key: Key) -> usize {
         let offset = Semitones::from(key) - Semitones::from(self.get_keys()[0]);
         let offset_val = if offset.0 >= 0 { offset.0 % 12 } else { offset.0 % 12 + 12 };
@@ -137,6 +147,11 @@ impl Scale {
             _ => 0,
         }
     }
+    // Pick the commonly used spelling of the new key, e.g. Bb rather than A#.
+    pub fn transpose_key(&self, key: &Key, offset: Semitones) -> Key {
+        let key = Key::from(Semitones::from(*key) + offset);
+        self.get_keys()[self.calc_key_index(key)]
+    }
     //https://www.hooktheory.com/cheat-sheet
     pub fn get_keys(&self) -> [Key; 12] {
         match self {
@@ -238,28 +253,39 @@ impl Scale {
                 Key::A,
                 Key::E,
             ],
+            // Custom tonics off the major scale degrees follow the major keys of their do.
+            _ => match self.get_mode() {
+                Some(mode) => mode.get_keys(),
+                None => Scale::Ionian.get_keys().map(|x| self.calc_tonic_key(&x)),
+            },
+        }
+    }
+    // Key of the tonic with the do key, e.g. Gb for Fi in C, sharp spelled if it needs two signs.
+    fn calc_tonic_key(&self, do_key: &Key) -> Key {
+        let tonic = self.calc_root_syllable();
+        let name = Pitch::from(*do_key)
+            .name
+            .offset(tonic.to_degree_index() as isize);
+        let semitones = Semitones::from(*do_key) + Semitones::from(tonic);
+        match Pitch::spell(name, semitones).map(|x| x.sign) {
+            Some(PitchSign::Natural) => Key::Natural(name),
+            Some(PitchSign::Sharp) => Key::Sharp(name),
+            Some(PitchSign::Flat) => Key::Flat(name),
+            _ => Key::from(semitones),
         }
     }
 }
 
 impl Scale {
     pub fn calc_do_offset(&self) -> i8 {
-        match self {
-            Scale::Ionian => 0,
-            Scale::Dorian => -2,
-            Scale::Phrygian => -4,
-            Scale::Lydian => -5,
-            Scale::Mixolydian => 5,
-            Scale::Aeolian => 3,
-            Scale::Locrian => 1,
-        }
+        self.get_pattern().calc_do_offset()
     }
     pub fn calc_do_semitones(&self, key: &Key) -> Semitones {
         let semitones = Semitones::from(*key).0 + self.calc_do_offset();
         Semitones(semitones)
     }
     pub fn calc_root_syllable(&self) -> Syllable {
-        Semitones(0 - self.calc_do_offset()).into()
+        self.get_pattern().tonic
     }
     pub fn calc_syllable_for_sort(&self, syllable: &Syllable) -> Syllable {
         let semitones = Semitones::from(*syllable).0 + self.calc_do_offset();
@@ -276,25 +302,73 @@ impl Scale {
             bass: chord.bass.clone(),
         }
     }
+    // Letter name of do is counted back from the key by the tonic degree, e.g. C for La in A.
+    pub fn calc_do_pitch(&self, key: &Key) -> Pitch {
+        let tonic = self.calc_root_syllable();
+        let name = Pitch::from(*key)
+            .name
+            .offset(-(tonic.to_degree_index() as isize));
+        let semitones = self.calc_do_semitones(key);
+        Pitch::spell(name, semitones).unwrap_or_else(|| {
+            Scale::Ionian.get_keys()[Scale::Ionian.calc_key_index(Key::from(semitones))].into()
+        })
+    }
+    // Out of scale tones take the spelling with fewer accidentals, e.g. B rather than Cb in F,
+    // on a tie flat keys go with flats, sharp keys with sharps except for Me and Te.
+    pub fn spell_syllable(&self, key: &Key, semitones: Semitones) -> Syllable {
+        let sharp: Syllable = semitones.into();
+        if let Some(x) = self
+            .get_syllables()
+            .into_iter()
+            .find(|x| Semitones::from(*x) == Semitones::from(sharp))
+        {
+            return x;
+        }
+        let flat = match sharp {
+            Syllable::Di => Syllable::Ra,
+            Syllable::Ri => Syllable::Me,
+            Syllable::Fi => Syllable::Se,
+            Syllable::Si => Syllable::Le,
+            Syllable::Li => Syllable::Te,
+            _ => return sharp,
+        };
+        let accidentals = |x: Syllable| Semitones::from(self.calc_pitch(key, &x).sign).0.abs();
+        match accidentals(sharp).cmp(&accidentals(flat)) {
+            Ordering::Less => sharp,
+            Ordering::Greater => flat,
+            Ordering::Equal => {
+                let do_pitch = self.calc_do_pitch(key);
+                let flat_key = do_pitch.sign == PitchSign::Flat || do_pitch == Pitch::F;
+                if flat_key || flat == Syllable::Me || flat == Syllable::Te {
+                    flat
+                } else {
+                    sharp
+                }
+            }
+        }
+    }
+    // Pitch spelling is kept, e.g. D# is Ri in E major, while Eb is Me.
     pub fn calc_syllable(&self, key: &Key, pitch: &Pitch) -> Syllable {
-        (Semitones::from(*pitch) - self.calc_do_semitones(key)).into()
+        let do_pitch = self.calc_do_pitch(key);
+        let semitones = Semitones::from(*pitch) - Semitones::from(do_pitch);
+        let degree = (pitch.name.to_index() as isize - do_pitch.name.to_index() as isize)
+            .rem_euclid(7) as usize;
+        let natural = Syllable::NATURALS[degree];
+        Syllable::from_degree(degree, semitones - natural.into())
+            .unwrap_or_else(|| self.spell_syllable(key, semitones))
     }
+    // Letter name moves with the syllable, so that Si in G# minor is F##, not G.
     pub fn calc_pitch(&self, key: &Key, syllable: &Syllable) -> Pitch {
-        let key_index = self.calc_key_index(key.clone());
-        if let Some(keys) = match syllable {
-            Syllable::Do => Some(Scale::Ionian.get_keys()),
-            Syllable::Re => Some(Scale::Dorian.get_keys()),
-            Syllable::Mi => Some(Scale::Phrygian.get_keys()),
-            Syllable::Fa => Some(Scale::Lydian.get_keys()),
-            Syllable::So => Some(Scale::Mixolydian.get_keys()),
-            Syllable::La => Some(Scale::Aeolian.get_keys()),
-            Syllable::Ti => Some(Scale::Locrian.get_keys()),
-            _ => None,
-        } {
-            keys[key_index].into()
-        } else {
-            (Semitones::from(*syllable) + self.calc_do_semitones(key)).into()
-        }
+        let do_pitch = self.calc_do_pitch(key);
+        let name = do_pitch.name.offset(syllable.to_degree_index() as isize);
+        let target = Semitones::from(do_pitch) + Semitones::from(*syllable);
+        Pitch::spell(name, target).unwrap_or_else(|| target.into())
+    }
+    pub fn calc_pitch_octave(&self, key: &Key, semitones: Semitones) -> (Pitch, Octave) {
+        let do_semitones = self.calc_do_semitones(key);
+        let syllable = self.spell_syllable(key, semitones - do_semitones);
+        let pitch = self.calc_pitch(key, &syllable);
+        (pitch, Octave::from(semitones - Semitones::from(pitch)))
     }
     pub fn calc_note_from_pitch(&self, key: &Key, pitch: &Pitch, octave: &Octave) -> Note {
         let syllable = self.calc_syllable(key, pitch);
@@ -303,17 +377,40 @@ impl Scale {
     pub fn calc_note_from_syllable(&self, key: &Key, syllable: &Syllable, octave: &Octave) -> Note {
         let semitones = Semitones::from(*octave) + self.calc_do_semitones(key) + Semitones::from(*syllable);
         let pitch = self.calc_pitch(key, syllable);
-        Note::new(Octave::from(semitones), pitch, *syllable)
+        Note::new(Octave::from(semitones - Semitones::from(pitch)), pitch, *syllable)
     }
     pub fn calc_note_from_semitones(&self, key: &Key, semitones: Semitones) -> Note {
-        let (pitch, octave) = semitones.as_pitch_octave();
-        self.calc_note_from_pitch(key, &pitch, &octave)
+        let syllable = self.spell_syllable(key, semitones - self.calc_do_semitones(key));
+        let (pitch, octave) = self.calc_pitch_octave(key, semitones);
+        Note::new(octave, pitch, syllable)
     }
     pub fn calc_click_note(&self, key: &Key, octave: &Octave, syllable: &Syllable) -> Note {
         let pitch = self.calc_pitch(key, syllable);
         Note::new(*octave, pitch, *syllable)
     }
     pub fn calc_click_tone(&self, key: &Key, octave: &Octave, syllable: &Syllable) -> Tone {
-        Tone::Single(self.calc_click_note(key, octave, syllable))
+        Tone::from(self.calc_click_note(key, octave, syllable))
+    }
+}
+
+#[test]
+fn test_scale_ident_round_trip() {
+    for scale in Scale::ALL {
+        assert_eq!(Scale::from_ident(&scale.to_ident()), Ok(scale));
+    }
+    let custom = "Custom(6: 1 2 3- 4 5 6- 7)".parse::<Scale>().unwrap();
+    assert_eq!(Scale::from_ident(&custom.to_ident()), Ok(custom));
+}
+
+#[test]
+fn test_custom_tonic_spelling() {
+    let scale = "Custom(4#: 1 2 3 4+ 5 6 7)".parse::<Scale>().unwrap();
+    let tonic = scale.calc_root_syllable();
+    assert_eq!(scale.calc_do_pitch(&Key::A), Pitch::E_FLAT);
+    assert_eq!(scale.calc_pitch(&Key::A, &tonic), Pitch::A);
+    let note = scale.calc_note_from_semitones(&Key::A, Semitones(57));
+    assert_eq!((note.pitch, note.syllable), (Pitch::A, tonic));
+    for key in scale.get_keys() {
+        assert_eq!(scale.calc_pitch(&key, &tonic), Pitch::from(key), "{}", key);
     }
 }
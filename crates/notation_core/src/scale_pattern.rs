@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::inline_vec::{CapacityError, InlineVec};
+use crate::parse_error::ParseTextError;
+use crate::prelude::{Interval, Semitones, Syllable};
+
+// A chromatic scale got 12 degrees, no scale can be longer than that.
+const MAX_DEGREES: usize = 12;
+
+// Tonic is the syllable of the first degree in moveable do, degrees are intervals from the tonic,
+// e.g. harmonic minor is `6: 1 2 3- 4 5 6- 7`, which gives La Ti Do Re Mi Fa Si.
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct ScalePattern {
+    pub tonic: Syllable,
+    pub degrees: InlineVec<Interval, MAX_DEGREES>,
+}
+impl Display for ScalePattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.tonic, self.to_text())
+    }
+}
+impl ScalePattern {
+    pub const MAX_LEN: usize = MAX_DEGREES;
+
+    pub const IONIAN: Self = Self::new(
+        Syllable::Do,
+        &[
+            Interval::Unison,
+            Interval::Major2nd,
+            Interval::Major3nd,
+            Interval::Perfect4th,
+            Interval::Perfect5th,
+            Interval::Major6th,
+            Interval::Major7th,
+        ],
+    );
+    pub const DORIAN: Self = Self::new(
+        Syllable::Re,
+        &[
+            Interval::Unison,
+            Interval::Major2nd,
+            Interval::Minor3nd,
+            Interval::Perfect4th,
+            Interval::Perfect5th,
+            Interval::Major6th,
+            Interval::Minor7th,
+        ],
+    );
+    pub const PHRYGIAN: Self = Self::new(
+        Syllable::Mi,
+        &[
+            Interval::Unison,
+            Interval::Minor2nd,
+            Interval::Minor3nd,
+            Interval::Perfect4th,
+            Interval::Perfect5th,
+            Interval::Minor6th,
+            Interval::Minor7th,
+        ],
+    );
+    pub const LYDIAN: Self = Self::new(
+        Syllable::Fa,
+        &[
+            Interval::Unison,
+            Interval::Major2nd,
+            Interval::Major3nd,
+            Interval::Augmented4th,
+            Interval::Perfect5th,
+            Interval::Major6th,
+            Interval::Major7th,
+        ],
+    );
+    pub const MIXOLYDIAN: Self = Self::new(
+        Syllable::So,
+        &[
+            Interval::Unison,
+            Interval::Major2nd,
+            Interval::Major3nd,
+            Interval::Perfect4th,
+            Interval::Perfect5th,
+            Interval::Major6th,
+            Interval::Minor7th,
+        ],
+    );
+    pub const AEOLIAN: Self = Self::new(
+        Syllable::La,
+        &[
+            Interval::Unison,
+            Interval::Major2nd,
+            Interval::Minor3nd,
+            Interval::Perfect4th,
+            Interval::Perfect5th,
+            Interval::Minor6th,
+            Interval::Minor7th,
+        ],
+    );
+    pub const LOCRIAN: Self = Self::new(
+        Syllable::Ti,
+        &[
+            Interval::Unison,
+            Interval::Minor2nd,
+            Interval::Minor3nd,
+            Interval::Perfect4th,
+            Interval::Diminished5th,
+            Interval::Minor6th,
+            Interval::Minor7th,
+        ],
+    );
+    pub const HARMONIC_MINOR: Self = Self::new(
+        Syllable::La,
+        &[
+            Interval::Unison,
+            Interval::Major2nd,
+            Interval::Minor3nd,
+            Interval::Perfect4th,
+            Interval::Perfect5th,
+            Interval::Minor6th,
+            Interval::Major7th,
+        ],
+    );
+    pub const MELODIC_MINOR: Self = Self::new(
+        Syllable::La,
+        &[
+            Interval::Unison,
+            Interval::Major2nd,
+            Interval::Minor3nd,
+            Interval::Perfect4th,
+            Interval::Perfect5th,
+            Interval::Major6th,
+            Interval::Major7th,
+        ],
+    );
+    pub const MAJOR_PENTATONIC: Self = Self::new(
+        Syllable::Do,
+        &[
+            Interval::Unison,
+            Interval::Major2nd,
+            Interval::Major3nd,
+            Interval::Perfect5th,
+            Interval::Major6th,
+        ],
+    );
+    pub const MINOR_PENTATONIC: Self = Self::new(
+        Syllable::La,
+        &[
+            Interval::Unison,
+            Interval::Minor3nd,
+            Interval::Perfect4th,
+            Interval::Perfect5th,
+            Interval::Minor7th,
+        ],
+    );
+    pub const BLUES: Self = Self::new(
+        Syllable::La,
+        &[
+            Interval::Unison,
+            Interval::Minor3nd,
+            Interval::Perfect4th,
+            Interval::Diminished5th,
+            Interval::Perfect5th,
+            Interval::Minor7th,
+        ],
+    );
+    pub const WHOLE_TONE: Self = Self::new(
+        Syllable::Do,
+        &[
+            Interval::Unison,
+            Interval::Major2nd,
+            Interval::Major3nd,
+            Interval::Augmented4th,
+            Interval::Augmented5th,
+            Interval::Minor7th,
+        ],
+    );
+
+    pub const fn new(tonic: Syllable, degrees: &[Interval]) -> Self {
+        Self {
+            tonic,
+            degrees: InlineVec::from_slice(degrees),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.degrees.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.degrees.is_empty()
+    }
+    pub fn get_degrees(&self) -> Vec<Interval> {
+        self.degrees.to_vec()
+    }
+    pub fn to_text(&self) -> String {
+        self.degrees
+            .iter()
+            .map(|x| x.to_text())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+    pub fn get_syllables(&self) -> Vec<Syllable> {
+        self.degrees
+            .iter()
+            .map(|x| Syllable::from((self.tonic, x)))
+            .collect()
+    }
+    // Semitones from the tonic to do, kept within a tritone so the tonic stays close to the key.
+    pub fn calc_do_offset(&self) -> i8 {
+        let tonic = Semitones::from(self.tonic).0;
+        if tonic > 5 {
+            12 - tonic
+        } else {
+            -tonic
+        }
+    }
+}
+
+impl TryFrom<(Syllable, Vec<Interval>)> for ScalePattern {
+    type Error = CapacityError;
+
+    fn try_from(v: (Syllable, Vec<Interval>)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            tonic: v.0,
+            degrees: InlineVec::try_from_slice(&v.1)?,
+        })
+    }
+}
+
+/// Parses the text from `Display`, e.g. `6: 1 2 3- 4 5 6- 7`.
+impl FromStr for ScalePattern {
+    type Err = ParseTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseTextError::new("ScalePattern", s);
+        let (tonic, degrees) = s.split_once(':').ok_or_else(err)?;
+        let tonic = tonic.parse::<Syllable>().map_err(|_| err())?;
+        let degrees = degrees
+            .split_whitespace()
+            .map(|x| x.parse::<Interval>())
+            .collect::<Result<Vec<Interval>, ParseTextError>>()
+            .map_err(|_| err())?;
+        if degrees.is_empty() || degrees.len() > Self::MAX_LEN {
+            return Err(err());
+        }
+        Ok(Self::new(tonic, &degrees))
+    }
+}
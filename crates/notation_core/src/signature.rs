@@ -1,16 +1,24 @@
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
-use crate::prelude::{Unit, Units};
+use crate::parse_error::ParseTextError;
+use crate::prelude::{InlineVec, Unit, Units};
 
 #[derive(Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Debug)]
 pub struct Beats(pub f32);
 
+/// Beats in each group, e.g. `[2, 2, 3]` for 7/8 played as 2+2+3.
+pub type BeatGroups = InlineVec<u8, { Signature::MAX_GROUPS }>;
+
 #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub struct Signature {
     pub beat_unit: Unit,
     pub bar_beats: u8,
+    /// Empty for the default grouping, see `get_groups()`.
+    #[serde(default)]
+    pub groups: BeatGroups,
 }
 impl Display for Signature {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -21,16 +29,119 @@ impl Display for Signature {
             Unit::Eighth => 8,
             Unit::Sixteenth => 16,
             Unit::ThirtySecondth => 32,
-        })
+        })?;
+        if !self.groups.is_empty() {
+            let groups: Vec<String> = self.groups.iter().map(|x| x.to_string()).collect();
+            write!(f, " ({})", groups.join("+"))?;
+        }
+        Ok(())
     }
 }
 
 impl Signature {
+    pub const MAX_GROUPS: usize = 8;
+
     pub fn new(beat_unit: Unit, bar_beats: u8) -> Self {
         Self {
             beat_unit,
             bar_beats,
+            groups: BeatGroups::new(),
+        }
+    }
+    /// Only takes groups passing `is_valid_groups()`, or empty ones for the default grouping,
+    /// checked in debug builds and ignored otherwise.
+    pub fn with_groups(&self, groups: &[u8]) -> Self {
+        let valid = groups.is_empty() || self.is_valid_groups(groups);
+        debug_assert!(valid, "Invalid beat groups for {}: {:?}", self, groups);
+        let groups = if valid {
+            BeatGroups::from_slice(groups)
+        } else {
+            BeatGroups::new()
+        };
+        Self { groups, ..*self }
+    }
+    pub fn is_valid_groups(&self, groups: &[u8]) -> bool {
+        let sum: usize = groups.iter().map(|x| *x as usize).sum();
+        sum == self.bar_beats as usize && groups.len() <= Self::MAX_GROUPS && !groups.contains(&0)
+    }
+    /// 6/8, 9/8 and 12/8 are felt in groups of three beats.
+    pub fn is_compound(&self) -> bool {
+        if self.groups.is_empty() {
+            self.bar_beats >= 6 && self.bar_beats.is_multiple_of(3)
+        } else {
+            self.groups.len() > 1 && self.groups.iter().all(|x| x == 3)
+        }
+    }
+    /// Every beat is in its own group for simple meters.
+    pub fn get_groups(&self) -> Vec<u8> {
+        if !self.groups.is_empty() {
+            self.groups.to_vec()
+        } else if self.is_compound() {
+            vec![3; (self.bar_beats / 3) as usize]
+        } else {
+            vec![1; self.bar_beats as usize]
+        }
+    }
+    pub fn is_grouped(&self) -> bool {
+        self.get_groups().iter().any(|x| *x > 1)
+    }
+    /// First beats of each group, along with the beats in the group.
+    pub fn get_group_starts(&self) -> Vec<(u8, u8)> {
+        let mut beat = 0;
+        self.get_groups()
+            .into_iter()
+            .map(|group| {
+                let start = beat;
+                beat += group;
+                (start, group)
+            })
+            .collect()
+    }
+    pub fn is_group_start(&self, beat: u8) -> bool {
+        self.get_group_starts().iter().any(|(x, _)| *x == beat)
+    }
+}
+
+/// Parses the text from `Display`, e.g. `3/4`, or `7/8 (2+2+3)` with beat groups.
+impl FromStr for Signature {
+    type Err = ParseTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseTextError::new("Signature", s);
+        let (main, groups) = match s.trim().split_once('(') {
+            Some((main, groups)) => {
+                let groups = groups.strip_suffix(')').ok_or_else(err)?;
+                (main.trim(), Some(groups))
+            }
+            None => (s.trim(), None),
+        };
+        let (bar_beats, beat_unit) = main.split_once('/').ok_or_else(err)?;
+        let bar_beats = bar_beats.trim().parse::<u8>().map_err(|_| err())?;
+        let beat_unit = match beat_unit.trim() {
+            "1" => Unit::Whole,
+            "2" => Unit::Half,
+            "4" => Unit::Quarter,
+            "8" => Unit::Eighth,
+            "16" => Unit::Sixteenth,
+            "32" => Unit::ThirtySecondth,
+            _ => return Err(err()),
+        };
+        if bar_beats == 0 {
+            return Err(err());
+        }
+        let signature = Self::new(beat_unit, bar_beats);
+        let groups = match groups {
+            Some(groups) => groups
+                .split('+')
+                .map(|x| x.trim().parse::<u8>())
+                .collect::<Result<Vec<u8>, _>>()
+                .map_err(|_| err())?,
+            None => return Ok(signature),
+        };
+        if !signature.is_valid_groups(&groups) {
+            return Err(err());
         }
+        Ok(signature.with_groups(&groups))
     }
 }
 
@@ -38,18 +149,22 @@ impl Signature {
     pub const _4_4: Self = Self {
         beat_unit: Unit::Quarter,
         bar_beats: 4,
+        groups: BeatGroups::new(),
     };
     pub const _3_4: Self = Self {
         beat_unit: Unit::Quarter,
         bar_beats: 3,
+        groups: BeatGroups::new(),
     };
     pub const _2_4: Self = Self {
         beat_unit: Unit::Quarter,
         bar_beats: 2,
+        groups: BeatGroups::new(),
     };
     pub const _6_8: Self = Self {
         beat_unit: Unit::Eighth,
         bar_beats: 6,
+        groups: BeatGroups::new(),
     };
 }
 
@@ -61,7 +176,7 @@ impl From<f32> for Beats {
 
 impl From<Signature> for Units {
     fn from(v: Signature) -> Self {
-        Self::from(Units::from(v.beat_unit).0 * (v.bar_beats as f32))
+        Units::from(v.beat_unit) * v.bar_beats as usize
     }
 }
 
@@ -73,6 +188,6 @@ impl From<Signature> for Beats {
 
 impl From<(Signature, Units)> for Beats {
     fn from((signature, units): (Signature, Units)) -> Self {
-        Self::from(units.0 / Units::from(signature.beat_unit).0)
+        Self::from(units.ratio(&Units::from(signature.beat_unit)))
     }
 }
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
+use std::str::FromStr;
 
+use crate::parse_error::ParseTextError;
 use crate::prelude::{PitchName, PitchSign, Semitones};
 
 // https://en.wikipedia.org/wiki/Solf%C3%A8ge
@@ -65,7 +67,16 @@ impl Syllable {
         .to_owned()
     }
     pub fn from_text(text: &str) -> Self {
-        match text {
+        text.parse().unwrap_or(Syllable::Do)
+    }
+}
+
+/// Parses the text from `to_text()`, e.g. `1`, `4#`, `7b`.
+impl FromStr for Syllable {
+    type Err = ParseTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
             //Natural
             "1" => Syllable::Do,
             "2" => Syllable::Re,
@@ -86,7 +97,24 @@ impl Syllable {
             "5b" => Syllable::Se,
             "6b" => Syllable::Le,
             "7b" => Syllable::Te,
-            _ => Syllable::Do,
+            _ => return Err(ParseTextError::new("Syllable", s)),
+        })
+    }
+}
+
+impl Syllable {
+    // https://en.wikipedia.org/wiki/Numbered_musical_notation
+    /// Accidental goes before the number, octaves are shown as dots above or below it.
+    pub fn to_jianpu_text(&self, octave: i8) -> String {
+        let text = self.to_text();
+        let (number, sign) = text.split_at(1);
+        let dots = "·".repeat(octave.unsigned_abs() as usize);
+        if octave > 0 {
+            format!("{}\n{}{}", dots, sign, number)
+        } else if octave < 0 {
+            format!("{}{}\n{}", sign, number, dots)
+        } else {
+            format!("{}{}", sign, number)
         }
     }
 }
@@ -122,6 +150,51 @@ impl Syllable {
     }
 }
 
+impl Syllable {
+    pub const NATURALS: [Syllable; 7] = [
+        Syllable::Do,
+        Syllable::Re,
+        Syllable::Mi,
+        Syllable::Fa,
+        Syllable::So,
+        Syllable::La,
+        Syllable::Ti,
+    ];
+    /// Index of the natural syllable it's altered from, e.g. both Me and Mi are 2.
+    pub fn to_degree_index(&self) -> usize {
+        match self {
+            Syllable::Do | Syllable::Di => 0,
+            Syllable::Ra | Syllable::Re | Syllable::Ri => 1,
+            Syllable::Me | Syllable::Mi => 2,
+            Syllable::Fa | Syllable::Fi => 3,
+            Syllable::Se | Syllable::So | Syllable::Si => 4,
+            Syllable::Le | Syllable::La | Syllable::Li => 5,
+            Syllable::Te | Syllable::Ti => 6,
+        }
+    }
+    /// Returns None if the alteration can't be written as a syllable, e.g. Mi sharp.
+    pub fn from_degree(degree: usize, alteration: Semitones) -> Option<Self> {
+        let natural = Self::NATURALS[degree % 7];
+        match (natural, alteration.0.rem_euclid(12)) {
+            (_, 0) => Some(natural),
+            (Syllable::Do, 1) => Some(Syllable::Di),
+            (Syllable::Re, 1) => Some(Syllable::Ri),
+            (Syllable::Fa, 1) => Some(Syllable::Fi),
+            (Syllable::So, 1) => Some(Syllable::Si),
+            (Syllable::La, 1) => Some(Syllable::Li),
+            (Syllable::Re, 11) => Some(Syllable::Ra),
+            (Syllable::Mi, 11) => Some(Syllable::Me),
+            (Syllable::So, 11) => Some(Syllable::Se),
+            (Syllable::La, 11) => Some(Syllable::Le),
+            (Syllable::Ti, 11) => Some(Syllable::Te),
+            _ => None,
+        }
+    }
+    pub fn is_natural(&self) -> bool {
+        Self::NATURALS.contains(self)
+    }
+}
+
 impl From<Syllable> for Semitones {
     fn from(v: Syllable) -> Self {
         match v {
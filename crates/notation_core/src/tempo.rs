@@ -1,7 +1,11 @@
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
+use crate::parse_error::ParseTextError;
+use crate::prelude::Units;
+
 // https://hellomusictheory.com/learn/musical-term-for-slow/
 // https://hellomusictheory.com/learn/musical-term-for-fast/
 
@@ -96,8 +100,18 @@ impl Tempo {
     pub fn to_ident(&self) -> String {
         format!("{}", self)
     }
-    pub fn from_ident(ident: &str) -> Self {
-        match ident {
+    pub fn from_ident(ident: &str) -> Result<Self, ParseTextError> {
+        ident.parse()
+    }
+}
+
+/// Parses the text from `to_ident()`, either a tempo marking, e.g. `Allegro`, or the bpm.
+impl FromStr for Tempo {
+    type Err = ParseTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+        Ok(match text {
             "Larghissimo" => Tempo::Larghissimo,
             "Adagissimo" => Tempo::Adagissimo,
             "Grave" => Tempo::Grave,
@@ -118,10 +132,66 @@ impl Tempo {
             "Allegrissimo" => Tempo::Allegrissimo,
             "Presto" => Tempo::Presto,
             "Prestissimo" => Tempo::Prestissimo,
-            _ => {
-                let bpm = ident.parse::<u16>().unwrap();
-                Tempo::Bpm(bpm)
+            _ => match text.parse::<u16>() {
+                Ok(bpm) if bpm > 0 => Tempo::Bpm(bpm),
+                _ => return Err(ParseTextError::new("Tempo", s)),
+            },
+        })
+    }
+}
+
+/// How the tempo moves from one to the other during accelerando or ritardando.
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub enum TempoRamp {
+    #[default]
+    Linear,
+    /// Changing slowly at first, then faster towards the end.
+    EaseIn,
+    /// Changing fast at first, then settling into the new tempo.
+    EaseOut,
+}
+impl Display for TempoRamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl TempoRamp {
+    /// Portion of the tempo change done at the progress, both from 0.0 to 1.0.
+    pub fn calc_portion(&self, progress: f32) -> f32 {
+        let progress = progress.clamp(0.0, 1.0);
+        match self {
+            TempoRamp::Linear => progress,
+            TempoRamp::EaseIn => progress * progress,
+            TempoRamp::EaseOut => 1.0 - (1.0 - progress) * (1.0 - progress),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum TempoChange {
+    /// Accelerando or ritardando towards the tempo over the span, which is kept afterwards.
+    Ramp(Tempo, Units, TempoRamp),
+    /// Time is stretched over the span in percentage, e.g. 200 to hold twice as long.
+    Fermata(Units, u16),
+    /// Back to the tempo of the tab.
+    ATempo,
+}
+impl Display for TempoChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TempoChange::Ramp(tempo, span, ramp) => {
+                write!(f, "Ramp({}, {}, {})", tempo, span, ramp)
             }
+            TempoChange::Fermata(span, percent) => write!(f, "Fermata({}, {}%)", span, percent),
+            TempoChange::ATempo => write!(f, "ATempo"),
         }
     }
 }
+
+#[test]
+fn test_tempo_ident_round_trip() {
+    for tempo in [Tempo::Larghissimo, Tempo::MarciaModerato, Tempo::Allegro, Tempo::Bpm(72)] {
+        assert_eq!(Tempo::from_ident(&tempo.to_ident()), Ok(tempo));
+    }
+    assert!(Tempo::from_ident("0").is_err());
+}
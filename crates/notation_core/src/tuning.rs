@@ -0,0 +1,139 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{Key, Note, Octave, Pitch, Semitones};
+
+// https://en.wikipedia.org/wiki/Musical_temperament
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub enum Temperament {
+    #[default]
+    Equal,
+    /// 5-limit just intonation, with the key as 1/1.
+    Just,
+    /// Pure fifths stacked from the key.
+    Pythagorean,
+    /// Quarter-comma meantone, with the key in the middle of the chain of fifths.
+    Meantone,
+}
+impl Display for Temperament {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl Temperament {
+    pub const ALL: [Temperament; 4] = [
+        Temperament::Equal,
+        Temperament::Just,
+        Temperament::Pythagorean,
+        Temperament::Meantone,
+    ];
+    pub const JUST_RATIOS: [(u32, u32); 12] = [
+        (1, 1),
+        (16, 15),
+        (9, 8),
+        (6, 5),
+        (5, 4),
+        (4, 3),
+        (45, 32),
+        (3, 2),
+        (8, 5),
+        (5, 3),
+        (9, 5),
+        (15, 8),
+    ];
+    pub const PYTHAGOREAN_RATIOS: [(u32, u32); 12] = [
+        (1, 1),
+        (256, 243),
+        (9, 8),
+        (32, 27),
+        (81, 64),
+        (4, 3),
+        (729, 512),
+        (3, 2),
+        (128, 81),
+        (27, 16),
+        (16, 9),
+        (243, 128),
+    ];
+    // A fifth narrowed by a quarter of the syntonic comma.
+    pub const MEANTONE_FIFTH_CENTS: f64 = 696.578_428_466_208_7;
+
+    fn ratio_cents(ratio: (u32, u32)) -> f64 {
+        1200.0 * (ratio.0 as f64 / ratio.1 as f64).log2()
+    }
+    /// Cents away from equal temperament, for a degree counted in semitones from the key.
+    pub fn calc_degree_cents(&self, degree: Semitones) -> f64 {
+        let degree = degree.0.rem_euclid(12) as usize;
+        let cents = match self {
+            Temperament::Equal => return 0.0,
+            Temperament::Just => Self::ratio_cents(Self::JUST_RATIOS[degree]),
+            Temperament::Pythagorean => Self::ratio_cents(Self::PYTHAGOREAN_RATIOS[degree]),
+            Temperament::Meantone => {
+                // From 3 fifths below to 8 fifths above, e.g. Eb to G# in C.
+                let fifths = (degree as i32 * 7 + 3).rem_euclid(12) - 3;
+                (fifths as f64 * Self::MEANTONE_FIFTH_CENTS).rem_euclid(1200.0)
+            }
+        };
+        cents - 100.0 * degree as f64
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub struct Tuning {
+    /// Frequency of A4 in Hz.
+    pub reference: f64,
+    pub temperament: Temperament,
+}
+impl Display for Tuning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "A={} {}", self.reference, self.temperament)
+    }
+}
+impl Default for Tuning {
+    fn default() -> Self {
+        Self::A440
+    }
+}
+impl Tuning {
+    pub const A440: Self = Self::new(440.0, Temperament::Equal);
+    pub const A432: Self = Self::new(432.0, Temperament::Equal);
+    /// Common pitch for baroque music.
+    pub const A415: Self = Self::new(415.0, Temperament::Equal);
+
+    pub const fn new(reference: f64, temperament: Temperament) -> Self {
+        Self {
+            reference,
+            temperament,
+        }
+    }
+    pub fn with_temperament(&self, temperament: Temperament) -> Self {
+        Self {
+            temperament,
+            ..*self
+        }
+    }
+    pub fn reference_semitones() -> Semitones {
+        Semitones::from((Pitch::A, Octave::P4))
+    }
+    /// Cents away from equal temperament, A is kept at the reference pitch in all temperaments,
+    /// so that an instrument can be tuned against it.
+    pub fn calc_cents(&self, key: &Key, semitones: Semitones) -> f64 {
+        let key = Semitones::from(*key);
+        self.temperament.calc_degree_cents(semitones - key)
+            - self
+                .temperament
+                .calc_degree_cents(Self::reference_semitones() - key)
+    }
+    pub fn calc_frequency(&self, key: &Key, semitones: Semitones) -> f64 {
+        let cents = (semitones - Self::reference_semitones()).0 as f64 * 100.0
+            + self.calc_cents(key, semitones);
+        self.reference * (cents / 1200.0).exp2()
+    }
+    pub fn calc_note_cents(&self, key: &Key, note: &Note) -> f64 {
+        self.calc_cents(key, Semitones::from(*note))
+    }
+    pub fn calc_note_frequency(&self, key: &Key, note: &Note) -> f64 {
+        self.calc_frequency(key, Semitones::from(*note))
+    }
+}
@@ -2,13 +2,14 @@ use std::sync::RwLock;
 
 use crate::core::duration::DurationTweakDsl;
 use crate::core::octave::OctaveTweakDsl;
+use crate::core::scale::ScaleDsl;
 use fehler::{throw, throws};
 use notation_proto::prelude::{
-    Duration, Key, Note, Octave, Scale, Syllable, GUITAR_STRING_NUM, Pitch,
+    Duration, Fretboard, Key, Note, Octave, Scale, Syllable, Tempo, GUITAR_STRING_NUM, Pitch,
 };
 use notation_proto::proto_entry::ProtoEntry;
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::parse::{Error, Parse, ParseStream};
 use syn::{Ident, LitInt, Token};
 
@@ -19,40 +20,40 @@ lazy_static! {
 #[derive(Copy, Clone, Debug)]
 pub struct FrettedContext {
     pub string_num: usize,
+    /// Set while parsing `string_num`, tunings of fretboards must match it.
+    pub explicit_string_num: Option<usize>,
+    /// Set while parsing fretboards, from their tunings.
+    pub tuning_string_num: Option<usize>,
 }
 impl Default for FrettedContext {
     fn default() -> Self {
         Self {
             string_num: GUITAR_STRING_NUM,
+            explicit_string_num: None,
+            tuning_string_num: None,
         }
     }
 }
 impl FrettedContext {
-    pub fn fretted_entry_quote(&self) -> TokenStream {
-        if self.string_num == 6 {
-            quote! { FrettedEntry6 }
-        } else if self.string_num == 4 {
-            quote! { FrettedEntry4 }
-        } else {
-            panic!("Unsupported string_num: {}", self.string_num);
-        }
-    }
-    pub fn fretboard_quote(&self) -> TokenStream {
-        if self.string_num == 6 {
-            quote! { Fretboard6 }
-        } else if self.string_num == 4 {
-            quote! { Fretboard4 }
-        } else {
-            panic!("Unsupported string_num: {}", self.string_num);
-        }
+    /// String count of the shapes while parsing, 6 if neither `string_num` nor a tuning is set.
+    pub fn parsed_string_num(&self) -> usize {
+        self.explicit_string_num
+            .or(self.tuning_string_num)
+            .unwrap_or(GUITAR_STRING_NUM)
     }
-    pub fn hand_shape_quote(&self) -> TokenStream {
-        if self.string_num == 6 {
-            quote! { HandShape6 }
-        } else if self.string_num == 4 {
-            quote! { HandShape4 }
-        } else {
-            panic!("Unsupported string_num: {}", self.string_num);
+}
+
+/// Set while parsing, unlike the others which are set at codegen, to check `accel` and `rit`.
+#[derive(Copy, Clone, Debug)]
+pub struct TempoContext {
+    pub tab: Tempo,
+    pub current: Tempo,
+}
+impl Default for TempoContext {
+    fn default() -> Self {
+        Self {
+            tab: Tempo::Moderato,
+            current: Tempo::Moderato,
         }
     }
 }
@@ -64,6 +65,7 @@ pub struct Context {
     pub duration: Duration,
     pub octave: Octave,
     pub fretted: FrettedContext,
+    pub tempo: TempoContext,
 }
 
 impl Default for Context {
@@ -74,6 +76,7 @@ impl Default for Context {
             duration: Duration::default(),
             octave: Octave::default(),
             fretted: FrettedContext::default(),
+            tempo: TempoContext::default(),
         }
     }
 }
@@ -94,6 +97,9 @@ impl Context {
     pub fn fretted() -> FrettedContext {
         CONTEXT.read().unwrap().fretted
     }
+    pub fn tempo() -> TempoContext {
+        CONTEXT.read().unwrap().tempo
+    }
     pub fn set_key(key: Key) {
         CONTEXT.write().unwrap().key = key;
     }
@@ -106,6 +112,26 @@ impl Context {
     pub fn set_octave(octave: Octave) {
         CONTEXT.write().unwrap().octave = octave;
     }
+    pub fn set_explicit_string_num(string_num: usize) {
+        CONTEXT.write().unwrap().fretted.explicit_string_num = Some(string_num);
+    }
+    pub fn set_tuning_string_num(string_num: usize) {
+        CONTEXT.write().unwrap().fretted.tuning_string_num = Some(string_num);
+    }
+    pub fn reset_parsed_string_num() {
+        let fretted = &mut CONTEXT.write().unwrap().fretted;
+        fretted.explicit_string_num = None;
+        fretted.tuning_string_num = None;
+    }
+    pub fn set_tab_tempo(tempo: Tempo) {
+        CONTEXT.write().unwrap().tempo = TempoContext {
+            tab: tempo,
+            current: tempo,
+        };
+    }
+    pub fn set_current_tempo(tempo: Tempo) {
+        CONTEXT.write().unwrap().tempo.current = tempo;
+    }
 }
 
 impl Context {
@@ -115,9 +141,23 @@ impl Context {
     }
     pub fn duration_quote(tweak: &Option<DurationTweakDsl>) -> TokenStream {
         let duration = Self::tweaked_duration(tweak);
-        let ident = duration.to_ident();
-        quote! {
-            Duration::from_ident(#ident)
+        match duration.unit() {
+            None => quote! { Duration::Zero },
+            Some(unit) => {
+                let unit_ident = format_ident!("{}", unit.to_ident());
+                let dots = duration.dots();
+                let tuplets = duration
+                    .tuplets()
+                    .iter()
+                    .map(|x| {
+                        let (notes, in_time_of) = (x.notes, x.in_time_of);
+                        quote! { Tuplet::new(#notes, #in_time_of) }
+                    })
+                    .collect::<Vec<TokenStream>>();
+                quote! {
+                    Duration::new(Unit::#unit_ident, #dots, &[#(#tuplets),*])
+                }
+            }
         }
     }
     pub fn tweaked_octave(tweak: &Option<OctaveTweakDsl>) -> Octave {
@@ -145,9 +185,9 @@ impl Context {
 }
 
 pub enum ContextDsl {
-    Key(Ident),
-    Scale(Ident),
-    Duration(Ident),
+    Key(Key),
+    Scale(ScaleDsl),
+    Duration(Duration),
     Octave(Ident),
     StringNum(usize),
 }
@@ -159,7 +199,10 @@ impl Parse for ContextDsl {
         match input.parse::<Ident>()?.to_string().as_str() {
             "key" => {
                 input.parse::<Token![=]>()?;
-                Self::Key(input.parse()?)
+                let ident = input.parse::<Ident>()?;
+                let key = Key::from_ident(ident.to_string().as_str())
+                    .map_err(|err| Error::new(ident.span(), err))?;
+                Self::Key(key)
             }
             "scale" => {
                 input.parse::<Token![=]>()?;
@@ -167,7 +210,10 @@ impl Parse for ContextDsl {
             }
             "duration" => {
                 input.parse::<Token![=]>()?;
-                Self::Duration(input.parse()?)
+                let ident = input.parse::<Ident>()?;
+                let duration = Duration::from_ident(ident.to_string().as_str())
+                    .map_err(|err| Error::new(ident.span(), err))?;
+                Self::Duration(duration)
             }
             "octave" => {
                 input.parse::<Token![=]>()?;
@@ -175,7 +221,12 @@ impl Parse for ContextDsl {
             }
             "string_num" => {
                 input.parse::<Token![=]>()?;
-                let string_num = input.parse::<LitInt>()?.base10_parse::<usize>()?;
+                let lit = input.parse::<LitInt>()?;
+                let string_num = lit.base10_parse::<usize>()?;
+                if string_num == 0 || string_num > Fretboard::MAX_STRINGS {
+                    throw!(Error::new(lit.span(), "Unsupported string_num"));
+                }
+                Context::set_explicit_string_num(string_num);
                 Self::StringNum(string_num)
             }
             _ => throw!(Error::new(input.span(), "Invalid Context")),
@@ -193,21 +244,21 @@ impl ToTokens for ContextDsl {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.extend(match self {
             Self::Key(x) => {
-                Context::set_key(Key::from_ident(x.to_string().as_str()));
+                Context::set_key(*x);
                 let comment = format!("{}", Context::key());
                 quote! {
                     ProtoEntry::from(("dsl::context::key", #comment))
                 }
             }
             Self::Scale(x) => {
-                Context::set_scale(Scale::from_ident(x.to_string().as_str()));
+                Context::set_scale(x.to_proto());
                 let comment = format!("{}", Context::scale());
                 quote! {
                     ProtoEntry::from(("dsl::context::scale", #comment))
                 }
             }
             Self::Duration(x) => {
-                CONTEXT.write().unwrap().duration = Duration::from_ident(x.to_string().as_str());
+                CONTEXT.write().unwrap().duration = *x;
                 let comment = format!("{}", Context::duration());
                 quote! {
                     ProtoEntry::from(("dsl::context::duration", #comment))
@@ -235,17 +286,17 @@ impl ContextDsl {
     pub fn to_proto(&self) -> ProtoEntry {
         match self {
             Self::Key(x) => {
-                Context::set_key(Key::from_ident(x.to_string().as_str()));
+                Context::set_key(*x);
                 let comment = format!("{}", Context::key());
                 ProtoEntry::from(("dsl::context::key", comment))
             }
             Self::Scale(x) => {
-                Context::set_scale(Scale::from_ident(x.to_string().as_str()));
+                Context::set_scale(x.to_proto());
                 let comment = format!("{}", Context::scale());
                 ProtoEntry::from(("dsl::context::scale", comment))
             }
             Self::Duration(x) => {
-                CONTEXT.write().unwrap().duration = Duration::from_ident(x.to_string().as_str());
+                CONTEXT.write().unwrap().duration = *x;
                 let comment = format!("{}", Context::duration());
                 ProtoEntry::from(("dsl::context::duration", comment))
             }
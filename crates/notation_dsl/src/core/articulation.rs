@@ -0,0 +1,39 @@
+use fehler::{throw, throws};
+use notation_proto::prelude::{Articulation, CoreEntry};
+use notation_proto::proto_entry::ProtoEntry;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::{Error, Parse, ParseStream};
+use syn::Ident;
+
+pub struct ArticulationDsl {
+    pub articulation: Articulation,
+}
+
+impl Parse for ArticulationDsl {
+    #[throws(Error)]
+    fn parse(input: ParseStream) -> Self {
+        let ident = input.parse::<Ident>()?.to_string();
+        let articulation = match Articulation::from_ident(ident.as_str()) {
+            Some(articulation) => articulation,
+            None => throw!(Error::new(input.span(), "Invalid Articulation")),
+        };
+        ArticulationDsl { articulation }
+    }
+}
+
+impl ToTokens for ArticulationDsl {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ArticulationDsl { articulation } = self;
+        let ident = articulation.to_ident();
+        tokens.extend(quote! {
+            ProtoEntry::from(CoreEntry::from(Articulation::from_ident(#ident).unwrap()))
+        });
+    }
+}
+
+impl ArticulationDsl {
+    pub fn to_proto(&self) -> ProtoEntry {
+        ProtoEntry::from(CoreEntry::from(self.articulation))
+    }
+}
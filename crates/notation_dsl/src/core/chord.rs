@@ -1,5 +1,5 @@
 use fehler::{throw, throws};
-use notation_proto::prelude::{Chord, CoreEntry, Interval};
+use notation_proto::prelude::{Chord, CoreEntry, Interval, Intervals};
 use notation_proto::proto_entry::ProtoEntry;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
@@ -61,13 +61,19 @@ impl ChordDsl {
         while IntervalDsl::peek(input) {
             intervals.push(input.parse()?);
         }
+        if intervals.len() > Intervals::MAX_LEN {
+            throw!(Error::new(
+                input.span(),
+                format!("at most {} intervals in a chord", Intervals::MAX_LEN)
+            ));
+        }
         let base: Option<IntervalDsl> = if input.peek(Token![/]) {
             input.parse::<Token![/]>()?;
             Some(input.parse()?)
         } else {
             None
         };
-        let duration_tweak = DurationTweakDsl::try_parse(input);
+        let duration_tweak = DurationTweakDsl::try_parse(input)?;
         ChordDsl::chord(root, intervals, base, duration_tweak)
     }
 }
@@ -89,16 +95,16 @@ impl ToTokens for ChordDsl {
             tokens.extend(match bass {
                 Some(base) => quote! {
                     ProtoEntry::from(CoreEntry::from(
-                        (Chord::from((#root, vec![
+                        (Chord::from((#root, Intervals::new(&[
                             #(#intervals_quote),*
-                        ], #base)), #duration_quote)
+                        ]), #base)), #duration_quote)
                     ))
                 },
                 None => quote! {
                     ProtoEntry::from(CoreEntry::from(
-                        (Chord::from((#root, vec![
+                        (Chord::from((#root, Intervals::new(&[
                             #(#intervals_quote),*
-                        ])), #duration_quote)
+                        ]))), #duration_quote)
                     ))
                 },
             })
@@ -125,7 +131,7 @@ impl ChordDsl {
                 .collect::<Vec<Interval>>();
             let bass = bass.as_ref().map(|x| x.to_proto().clone());
             ProtoEntry::from(CoreEntry::from((
-                Chord::new(root.to_proto(), intervals.into(), bass),
+                Chord::new(root.to_proto(), Intervals::new(&intervals), bass),
                 duration,
             )))
         } else {
@@ -1,15 +1,17 @@
 use std::cmp::{max, min};
 
-use fehler::throws;
-use notation_proto::prelude::Duration;
+use fehler::{throw, throws};
+use notation_proto::prelude::{Duration, Tuplet, Units};
 use syn::parse::{Error, Parse, ParseStream};
-use syn::Token;
+use syn::{LitInt, Token};
+
+use crate::context::Context;
 
 #[derive(Debug)]
 pub struct DurationTweakDsl {
     pub half_num: i8,
-    pub dotted: bool,
-    pub triplet: bool,
+    pub dots: u8,
+    pub tuplets: Vec<Tuplet>,
 }
 
 impl Parse for DurationTweakDsl {
@@ -26,23 +28,57 @@ impl Parse for DurationTweakDsl {
             }
         }
         half_num = min(max(half_num, -4), 4);
-        let mut dotted = false;
-        let mut triplet = false;
-        if input.peek(Token![+]) {
-            input.parse::<Token![+]>()?;
-            dotted = true;
-        } else if input.peek(Token![=]) {
-            input.parse::<Token![=]>()?;
-            dotted = true;
-            triplet = true;
-        } else if input.peek(Token![-]) {
-            input.parse::<Token![-]>()?;
-            triplet = true;
+        let mut dots = 0;
+        let mut tuplets = vec![];
+        loop {
+            if input.peek(Token![+]) {
+                input.parse::<Token![+]>()?;
+                dots += 1;
+            } else if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                dots += 1;
+                tuplets.push(Tuplet::TRIPLET);
+            } else if input.peek(Token![-]) {
+                input.parse::<Token![-]>()?;
+                tuplets.push(Tuplet::TRIPLET);
+            } else if input.peek(Token![/]) && input.peek2(LitInt) {
+                // `/5` for quintuplet, or `/6:4` with explicit ratio.
+                input.parse::<Token![/]>()?;
+                let notes = input.parse::<LitInt>()?.base10_parse::<u8>()?;
+                let tuplet = if input.peek(Token![:]) && input.peek2(LitInt) {
+                    input.parse::<Token![:]>()?;
+                    let in_time_of = input.parse::<LitInt>()?.base10_parse::<u8>()?;
+                    Tuplet::new(notes, in_time_of)
+                } else {
+                    Tuplet::of_notes(notes)
+                };
+                if !tuplet.is_valid() {
+                    throw!(Error::new(input.span(), "Invalid Tuplet"));
+                }
+                tuplets.push(tuplet);
+            } else {
+                break;
+            }
+        }
+        if tuplets.len() > Duration::MAX_TUPLET_DEPTH {
+            throw!(Error::new(
+                input.span(),
+                format!(
+                    "at most {} tuplets in a duration",
+                    Duration::MAX_TUPLET_DEPTH
+                )
+            ));
+        }
+        if dots > Duration::MAX_DOTS {
+            throw!(Error::new(
+                input.span(),
+                format!("at most {} dots in a duration", Duration::MAX_DOTS)
+            ));
         }
         DurationTweakDsl {
             half_num,
-            dotted,
-            triplet,
+            dots,
+            tuplets,
         }
     }
 }
@@ -54,17 +90,20 @@ impl DurationTweakDsl {
             || input.peek(Token![+])
             || input.peek(Token![=])
             || input.peek(Token![-])
+            || (input.peek(Token![/]) && input.peek2(LitInt))
     }
+    #[throws(Error)]
     pub fn try_parse(input: ParseStream) -> Option<Self> {
         if Self::peek(input) {
-            Self::parse(input).ok()
+            Some(Self::parse(input)?)
         } else {
             None
         }
     }
+    /// Tuplets are nested within the ones of base.
     pub fn tweak(&self, base: &Duration) -> Duration {
-        if let Some(base_unit) = base.as_simple() {
-            let mut unit = base_unit.clone();
+        if let Some(base_unit) = base.unit() {
+            let mut unit = base_unit;
             if self.half_num > 0 {
                 for _ in 0..self.half_num {
                     unit = unit.halfed();
@@ -74,15 +113,45 @@ impl DurationTweakDsl {
                     unit = unit.doubled();
                 }
             }
-            match (self.dotted, self.triplet) {
-                (false, false) => Duration::Simple(unit),
-                (true, false) => Duration::Dotted(unit),
-                (false, true) => Duration::Triplet(unit),
-                (true, true) => Duration::DottedTriplet(unit),
+            let mut duration = base.with_unit(unit).with_dots(base.dots() + self.dots);
+            for tuplet in self.tuplets.iter() {
+                duration = duration.within_tuplet(*tuplet);
             }
+            duration
+        } else {
+            println!("Can not tweak zero duration: {} - {:?}", base, self);
+            *base
+        }
+    }
+}
+
+/// Span of marks like hairpins and tempo changes, e.g. `4` for 4 times of the current
+/// duration, or `3 *` for 3 times of the doubled one.
+#[derive(Debug)]
+pub struct SpanDsl {
+    pub count: usize,
+    pub duration_tweak: Option<DurationTweakDsl>,
+}
+
+impl Parse for SpanDsl {
+    #[throws(Error)]
+    fn parse(input: ParseStream) -> Self {
+        let count = if input.peek(LitInt) {
+            input.parse::<LitInt>()?.base10_parse::<usize>()?
         } else {
-            println!("Can only tweak simple duration: {} - {:?}", base, self);
-            return base.clone();
+            1
+        };
+        let duration_tweak = DurationTweakDsl::try_parse(input)?;
+        SpanDsl {
+            count,
+            duration_tweak,
         }
     }
 }
+
+impl SpanDsl {
+    /// Only valid after the context entries before it are handled.
+    pub fn to_proto(&self) -> Units {
+        Units::from(Context::tweaked_duration(&self.duration_tweak)) * self.count
+    }
+}
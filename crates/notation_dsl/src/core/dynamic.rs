@@ -0,0 +1,67 @@
+use fehler::{throw, throws};
+use notation_proto::prelude::{CoreEntry, Dynamic, DynamicLevel};
+use notation_proto::proto_entry::ProtoEntry;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::{Error, Parse, ParseStream};
+use syn::Ident;
+
+use crate::core::duration::SpanDsl;
+
+pub enum DynamicDsl {
+    Level(DynamicLevel),
+    Crescendo(SpanDsl),
+    Decrescendo(SpanDsl),
+}
+
+impl Parse for DynamicDsl {
+    #[throws(Error)]
+    fn parse(input: ParseStream) -> Self {
+        let ident = input.parse::<Ident>()?.to_string();
+        match ident.as_str() {
+            // `cresc 4` spans 4 times of the current duration, e.g. `cresc 3 *`.
+            "cresc" => DynamicDsl::Crescendo(input.parse()?),
+            "decresc" => DynamicDsl::Decrescendo(input.parse()?),
+            _ => match DynamicLevel::from_text(ident.as_str()) {
+                Some(level) => DynamicDsl::Level(level),
+                None => throw!(Error::new(input.span(), "Invalid Dynamic")),
+            },
+        }
+    }
+}
+
+impl ToTokens for DynamicDsl {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let dynamic_quote = match self.to_dynamic() {
+            Dynamic::Level(level) => {
+                let text = level.to_text();
+                quote! { Dynamic::Level(DynamicLevel::from_text(#text).unwrap()) }
+            }
+            Dynamic::Crescendo(span) => {
+                let (numer, denom) = (span.numer(), span.denom());
+                quote! { Dynamic::Crescendo(Units::new(#numer, #denom)) }
+            }
+            Dynamic::Decrescendo(span) => {
+                let (numer, denom) = (span.numer(), span.denom());
+                quote! { Dynamic::Decrescendo(Units::new(#numer, #denom)) }
+            }
+        };
+        tokens.extend(quote! {
+            ProtoEntry::from(CoreEntry::from(#dynamic_quote))
+        });
+    }
+}
+
+impl DynamicDsl {
+    // Spans are calculated here, when the duration context is set.
+    fn to_dynamic(&self) -> Dynamic {
+        match self {
+            DynamicDsl::Level(level) => Dynamic::Level(*level),
+            DynamicDsl::Crescendo(span) => Dynamic::Crescendo(span.to_proto()),
+            DynamicDsl::Decrescendo(span) => Dynamic::Decrescendo(span.to_proto()),
+        }
+    }
+    pub fn to_proto(&self) -> ProtoEntry {
+        ProtoEntry::from(CoreEntry::from(self.to_dynamic()))
+    }
+}
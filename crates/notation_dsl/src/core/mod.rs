@@ -1,13 +1,17 @@
+pub mod articulation;
 pub mod chord;
 pub mod duration;
+pub mod dynamic;
 pub mod empty;
 pub mod interval;
 pub mod note;
 pub mod octave;
 pub mod pitch_name;
 pub mod pitch_sign;
+pub mod scale;
 pub mod signature;
 pub mod syllable;
 pub mod tempo;
+pub mod tempo_change;
 pub mod tone;
 pub mod word;
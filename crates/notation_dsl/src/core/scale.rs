@@ -0,0 +1,73 @@
+use fehler::{throw, throws};
+
+use notation_proto::prelude::{Scale, ScalePattern};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::parse::{Error, Parse, ParseStream};
+use syn::{parenthesized, token, Ident, Token};
+
+use super::interval::IntervalDsl;
+use super::syllable::SyllableDsl;
+
+pub struct ScaleDsl {
+    pub scale: Scale,
+}
+
+impl Parse for ScaleDsl {
+    #[throws(Error)]
+    fn parse(input: ParseStream) -> Self {
+        let ident = input.parse::<Ident>()?;
+        let scale = if ident == "Custom" && input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            let tonic = content.parse::<SyllableDsl>()?;
+            content.parse::<Token![:]>()?;
+            let mut degrees = Vec::new();
+            while IntervalDsl::peek(&content) {
+                degrees.push(content.parse::<IntervalDsl>()?.to_proto());
+            }
+            match ScalePattern::try_from((tonic.to_proto(), degrees)) {
+                Ok(pattern) => Scale::Custom(pattern),
+                Err(err) => throw!(Error::new(ident.span(), err)),
+            }
+        } else {
+            Scale::from_ident(ident.to_string().as_str())
+                .map_err(|err| Error::new(ident.span(), err))?
+        };
+        ScaleDsl { scale }
+    }
+}
+
+impl ToTokens for ScaleDsl {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ScaleDsl { scale } = self;
+        tokens.extend(match scale {
+            Scale::Custom(pattern) => {
+                let tonic_text = pattern.tonic.to_text();
+                let degrees_text = pattern
+                    .get_degrees()
+                    .iter()
+                    .map(|x| x.to_text())
+                    .collect::<Vec<String>>();
+                quote! {
+                    Scale::Custom(ScalePattern::new(
+                        Syllable::from_text(#tonic_text),
+                        &[#(Interval::from_text(#degrees_text)),*],
+                    ))
+                }
+            }
+            _ => {
+                let scale_ident = format_ident!("{}", scale.to_ident());
+                quote! {
+                    Scale::#scale_ident
+                }
+            }
+        });
+    }
+}
+
+impl ScaleDsl {
+    pub fn to_proto(&self) -> Scale {
+        self.scale
+    }
+}
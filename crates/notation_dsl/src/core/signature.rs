@@ -1,14 +1,15 @@
-use fehler::throws;
+use fehler::{throw, throws};
 
 use notation_proto::prelude::{Signature, Unit};
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::parse::{Error, Parse, ParseStream};
-use syn::{Ident, LitInt};
+use syn::{bracketed, token, Ident, LitInt};
 
 pub struct SignatureDsl {
     pub bar_beats: u8,
     pub beat_unit: Unit,
+    pub groups: Vec<u8>,
 }
 
 impl Parse for SignatureDsl {
@@ -16,10 +17,27 @@ impl Parse for SignatureDsl {
     fn parse(input: ParseStream) -> Self {
         let bar_beats = input.parse::<LitInt>()?.base10_parse::<u8>()?;
         let ident = input.parse::<Ident>()?;
-        let beat_unit = Unit::from_ident(ident.to_string().as_str());
+        let beat_unit = Unit::from_ident(ident.to_string().as_str())
+            .map_err(|err| Error::new(ident.span(), err))?;
+        // Optional beat groups, e.g. `7 _8 [ 2 2 3 ]`
+        let mut groups = vec![];
+        if input.peek(token::Bracket) {
+            let content;
+            let bracket = bracketed!(content in input);
+            while !content.is_empty() {
+                groups.push(content.parse::<LitInt>()?.base10_parse::<u8>()?);
+            }
+            if !Signature::new(beat_unit, bar_beats).is_valid_groups(&groups) {
+                throw!(Error::new(
+                    bracket.span,
+                    format!("Invalid beat groups for {} beats: {:?}", bar_beats, groups)
+                ));
+            }
+        }
         SignatureDsl {
             bar_beats,
             beat_unit,
+            groups,
         }
     }
 }
@@ -35,16 +53,18 @@ impl ToTokens for SignatureDsl {
         let SignatureDsl {
             bar_beats,
             beat_unit,
+            groups,
         } = self;
-        let beat_unit_ident = beat_unit.to_ident();
+        let beat_unit_ident = format_ident!("{}", beat_unit.to_ident());
         tokens.extend(quote! {
-            Signature::new(Unit::from_ident(#beat_unit_ident), #bar_beats)
+            Signature::new(Unit::#beat_unit_ident, #bar_beats)
+                .with_groups(&[#(#groups),*])
         });
     }
 }
 
 impl SignatureDsl {
     pub fn to_proto(&self) -> Signature {
-        Signature::new(self.beat_unit, self.bar_beats)
+        Signature::new(self.beat_unit, self.bar_beats).with_groups(&self.groups)
     }
 }
@@ -2,7 +2,7 @@ use fehler::throws;
 
 use notation_proto::prelude::Tempo;
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::parse::{Error, Parse, ParseStream};
 use syn::{Ident, LitInt};
 
@@ -14,11 +14,12 @@ impl Parse for TempoDsl {
     #[throws(Error)]
     fn parse(input: ParseStream) -> Self {
         let tempo = if input.peek(LitInt) {
-            let bpm = input.parse::<LitInt>()?.base10_parse::<u16>()?;
-            Tempo::Bpm(bpm)
+            let lit = input.parse::<LitInt>()?;
+            Tempo::from_ident(lit.base10_digits()).map_err(|err| Error::new(lit.span(), err))?
         } else {
             let ident = input.parse::<Ident>()?;
             Tempo::from_ident(ident.to_string().as_str())
+                .map_err(|err| Error::new(ident.span(), err))?
         };
         TempoDsl { tempo }
     }
@@ -28,15 +29,20 @@ impl TempoDsl {
     pub fn peek(input: ParseStream) -> bool {
         input.peek(LitInt) || input.peek(Ident)
     }
+    pub fn quote(tempo: &Tempo) -> TokenStream {
+        match tempo.as_bpm() {
+            Some(bpm) => quote! { Tempo::Bpm(#bpm) },
+            None => {
+                let ident = format_ident!("{}", tempo.to_ident());
+                quote! { Tempo::#ident }
+            }
+        }
+    }
 }
 
 impl ToTokens for TempoDsl {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let TempoDsl { tempo } = self;
-        let tempo_ident = tempo.to_ident();
-        tokens.extend(quote! {
-            Tempo::from_ident(#tempo_ident)
-        });
+        tokens.extend(Self::quote(&self.tempo));
     }
 }
 
@@ -0,0 +1,152 @@
+use fehler::{throw, throws};
+use notation_proto::prelude::{Bpm, MetaEntry, Tempo, TempoChange, TempoRamp};
+use notation_proto::proto_entry::ProtoEntry;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::{Error, Parse, ParseStream};
+use syn::{Ident, LitInt};
+
+use crate::context::Context;
+use crate::core::duration::SpanDsl;
+use crate::core::tempo::TempoDsl;
+
+pub enum TempoChangeDsl {
+    Tempo(Tempo),
+    Ramp(Tempo, SpanDsl, TempoRamp),
+    Fermata(SpanDsl, u16),
+    ATempo,
+}
+
+impl TempoChangeDsl {
+    #[throws(Error)]
+    fn parse_ramp(input: ParseStream) -> TempoRamp {
+        if input.peek(Ident) {
+            let fork = input.fork();
+            let ramp = match fork.parse::<Ident>()?.to_string().as_str() {
+                "EaseIn" => Some(TempoRamp::EaseIn),
+                "EaseOut" => Some(TempoRamp::EaseOut),
+                "Linear" => Some(TempoRamp::Linear),
+                _ => None,
+            };
+            if let Some(ramp) = ramp {
+                input.parse::<Ident>()?;
+                return ramp;
+            }
+        }
+        TempoRamp::default()
+    }
+}
+
+impl Parse for TempoChangeDsl {
+    #[throws(Error)]
+    fn parse(input: ParseStream) -> Self {
+        let keyword = if input.peek(Ident) {
+            let fork = input.fork();
+            let ident = fork.parse::<Ident>()?.to_string();
+            match ident.as_str() {
+                "accel" | "rit" | "fermata" | "a_tempo" => {
+                    input.parse::<Ident>()?;
+                    Some(ident)
+                }
+                _ => None,
+            }
+        } else {
+            None
+        };
+        let current = Context::tempo().current;
+        let change = match keyword.as_deref() {
+            // `accel 120 4` spans 4 times of the current duration, e.g. `rit 60 2 * EaseOut`.
+            Some(keyword @ ("accel" | "rit")) => {
+                let span = input.span();
+                let tempo = input.parse::<TempoDsl>()?.to_proto();
+                let (target, from) = (Bpm::from(tempo), Bpm::from(current));
+                if keyword == "accel" && target <= from {
+                    throw!(Error::new(
+                        span,
+                        format!("accel needs a tempo faster than {}", from)
+                    ));
+                }
+                if keyword == "rit" && target >= from {
+                    throw!(Error::new(
+                        span,
+                        format!("rit needs a tempo slower than {}", from)
+                    ));
+                }
+                TempoChangeDsl::Ramp(tempo, input.parse()?, Self::parse_ramp(input)?)
+            }
+            Some("fermata") => {
+                let percent = input.parse::<LitInt>()?.base10_parse::<u16>()?;
+                TempoChangeDsl::Fermata(input.parse()?, percent)
+            }
+            Some(_) => TempoChangeDsl::ATempo,
+            None => TempoChangeDsl::Tempo(input.parse::<TempoDsl>()?.to_proto()),
+        };
+        match change {
+            TempoChangeDsl::Tempo(tempo) | TempoChangeDsl::Ramp(tempo, _, _) => {
+                Context::set_current_tempo(tempo)
+            }
+            TempoChangeDsl::ATempo => Context::set_current_tempo(Context::tempo().tab),
+            TempoChangeDsl::Fermata(_, _) => (),
+        }
+        change
+    }
+}
+
+impl TempoChangeDsl {
+    // Spans are calculated here, when the duration context is set.
+    fn to_entry(&self) -> MetaEntry {
+        match self {
+            TempoChangeDsl::Tempo(tempo) => MetaEntry::Tempo(*tempo),
+            TempoChangeDsl::Ramp(tempo, span, ramp) => {
+                MetaEntry::from(TempoChange::Ramp(*tempo, span.to_proto(), *ramp))
+            }
+            TempoChangeDsl::Fermata(span, percent) => {
+                MetaEntry::from(TempoChange::Fermata(span.to_proto(), *percent))
+            }
+            TempoChangeDsl::ATempo => MetaEntry::from(TempoChange::ATempo),
+        }
+    }
+}
+
+impl ToTokens for TempoChangeDsl {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let entry_quote = match self.to_entry() {
+            MetaEntry::Tempo(tempo) => {
+                let tempo_quote = TempoDsl::quote(&tempo);
+                quote! { MetaEntry::Tempo(#tempo_quote) }
+            }
+            MetaEntry::TempoChange(TempoChange::Ramp(tempo, span, ramp)) => {
+                let tempo_quote = TempoDsl::quote(&tempo);
+                let (numer, denom) = (span.numer(), span.denom());
+                let ramp_quote = match ramp {
+                    TempoRamp::Linear => quote! { TempoRamp::Linear },
+                    TempoRamp::EaseIn => quote! { TempoRamp::EaseIn },
+                    TempoRamp::EaseOut => quote! { TempoRamp::EaseOut },
+                };
+                quote! {
+                    MetaEntry::from(TempoChange::Ramp(
+                        #tempo_quote,
+                        Units::new(#numer, #denom),
+                        #ramp_quote,
+                    ))
+                }
+            }
+            MetaEntry::TempoChange(TempoChange::Fermata(span, percent)) => {
+                let (numer, denom) = (span.numer(), span.denom());
+                quote! {
+                    MetaEntry::from(TempoChange::Fermata(Units::new(#numer, #denom), #percent))
+                }
+            }
+            _ => quote! { MetaEntry::from(TempoChange::ATempo) },
+        };
+        tokens.extend(quote! {
+            ProtoEntry::from(#entry_quote)
+        });
+    }
+}
+
+impl TempoChangeDsl {
+    pub fn to_proto(&self) -> ProtoEntry {
+        ProtoEntry::from(self.to_entry())
+    }
+}
@@ -1,4 +1,4 @@
-use fehler::throws;
+use fehler::{throw, throws};
 use notation_proto::prelude::{CoreEntry, Note, Tone};
 use notation_proto::proto_entry::ProtoEntry;
 use proc_macro2::TokenStream;
@@ -31,8 +31,14 @@ impl ToneDsl {
                     break;
                 }
             }
+            if notes.len() > Tone::MAX_NOTES {
+                throw!(Error::new(
+                    input.span(),
+                    format!("at most {} notes in a tone", Tone::MAX_NOTES)
+                ));
+            }
         }
-        let duration_tweak = DurationTweakDsl::try_parse(input);
+        let duration_tweak = DurationTweakDsl::try_parse(input)?;
         ToneDsl {
             empty,
             notes,
@@ -55,7 +61,7 @@ impl ToTokens for ToneDsl {
             let notes_quote: Vec<_> = notes.iter().map(|x| quote! { #x }).collect();
             tokens.extend(quote! {
                 ProtoEntry::from(CoreEntry::from(
-                    (Tone::from(vec![
+                    (Tone::new(&[
                         #(#notes_quote),*
                     ]), #duration_quote)
                 ))
@@ -80,7 +86,7 @@ impl ToneDsl {
                 .map(|x| x.to_proto())
                 .collect::<Vec<Note>>();
             ProtoEntry::from(CoreEntry::from((
-                Tone::from(notes),
+                Tone::new(&notes),
                 duration,
             )))
         }
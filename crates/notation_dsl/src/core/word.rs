@@ -27,7 +27,7 @@ impl WordDsl {
         } else {
             word = Some(input.parse()?);
         }
-        let duration_tweak = DurationTweakDsl::try_parse(input);
+        let duration_tweak = DurationTweakDsl::try_parse(input)?;
         WordDsl {
             empty,
             word,
@@ -1,18 +1,15 @@
-use fehler::throws;
-use notation_proto::prelude::{
-    Fretboard4, Fretboard6, FrettedEntry4, FrettedEntry6, GuitarTuning,
-    GUITAR_FRET_NUM_ACOUSTIC, Pitch, Octave,
-};
+use fehler::{throw, throws};
+use notation_proto::prelude::{Fretboard, FrettedEntry, StringTuning, GUITAR_FRET_NUM_ACOUSTIC};
 use notation_proto::proto_entry::ProtoEntry;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::parse::{Error, Parse, ParseStream};
-use syn::{Ident, LitInt, Token};
+use syn::{Ident, LitInt, LitStr, Token};
 
 use crate::context::Context;
 
 pub struct FretboardDsl {
-    pub tuning: Option<Ident>,
+    pub tuning: StringTuning,
     pub fret_num: Option<usize>,
     pub capo: Option<u8>,
 }
@@ -33,7 +30,7 @@ impl Parse for FretboardDsl {
             if input.peek(kw::tuning) {
                 input.parse::<kw::tuning>()?;
                 input.parse::<Token![:]>()?;
-                tuning = Some(input.parse::<Ident>()?);
+                tuning = Some(Self::parse_checked_tuning(input)?);
             } else if input.peek(kw::fret_num) {
                 input.parse::<kw::fret_num>()?;
                 input.parse::<Token![:]>()?;
@@ -46,6 +43,11 @@ impl Parse for FretboardDsl {
                 break;
             }
         }
+        let tuning = match tuning {
+            Some(tuning) => tuning,
+            None => Self::default_tuning(input)?,
+        };
+        Context::set_tuning_string_num(tuning.string_num());
         FretboardDsl {
             tuning,
             fret_num,
@@ -54,69 +56,69 @@ impl Parse for FretboardDsl {
     }
 }
 
+impl FretboardDsl {
+    /// Preset name, e.g. `DADGAD`, or the pitches as text, e.g. `"D4 A3 G3 D3 A2 D2"`.
+    #[throws(Error)]
+    fn parse_checked_tuning(input: ParseStream) -> StringTuning {
+        let (text, span) = if input.peek(LitStr) {
+            let lit = input.parse::<LitStr>()?;
+            (lit.value(), lit.span())
+        } else {
+            let ident = input.parse::<Ident>()?;
+            (ident.to_string(), ident.span())
+        };
+        let tuning = text
+            .parse::<StringTuning>()
+            .map_err(|err| Error::new(span, err.to_string()))?;
+        if let Some(string_num) = Context::fretted().explicit_string_num {
+            if tuning.string_num() != string_num {
+                throw!(Error::new(
+                    span,
+                    format!(
+                        "Tuning {} has {} strings, but string_num is {}",
+                        tuning,
+                        tuning.string_num(),
+                        string_num
+                    )
+                ));
+            }
+        }
+        tuning
+    }
+    // Common tuning of the string count, from `string_num` or the previous tuning.
+    #[throws(Error)]
+    fn default_tuning(input: ParseStream) -> StringTuning {
+        let string_num = Context::fretted().parsed_string_num();
+        match StringTuning::default_of(string_num) {
+            Some(tuning) => tuning,
+            None => throw!(Error::new(
+                input.span(),
+                format!("No default tuning for {} strings", string_num)
+            )),
+        }
+    }
+}
+
 impl ToTokens for FretboardDsl {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let FretboardDsl {
-            tuning,
-            fret_num,
-            capo,
-        } = self;
-        let string_num = Context::fretted().string_num;
-        let fret_num = fret_num.unwrap_or(match string_num {
-            6 => GUITAR_FRET_NUM_ACOUSTIC,
-            _ => GUITAR_FRET_NUM_ACOUSTIC,
-        });
-        let capo = capo.unwrap_or(0);
-        let tuning_quote = match tuning {
-            Some(tuning) => {
-                let tuning_quote = tuning.to_string();
-                match string_num {
-                    _ => quote! { GuitarTuning::from_ident(#tuning_quote)},
-                }
-            }
-            None => match string_num {
-                _ => quote! { GuitarTuning::Standard },
-            },
-        };
-        let fretted_entry_quote = Context::fretted().fretted_entry_quote();
-        let fretboard_quote = Context::fretted().fretboard_quote();
+        let fret_num = self.fret_num.unwrap_or(GUITAR_FRET_NUM_ACOUSTIC);
+        let capo = self.capo.unwrap_or(0);
+        let tuning = self.tuning.to_ident();
         tokens.extend(quote! {
-            ProtoEntry::from(#fretted_entry_quote::from(
-                #fretboard_quote::new(#fret_num, #tuning_quote.into(), #capo)
-            ))
+            ProtoEntry::from(FrettedEntry::from(Fretboard::new(
+                #fret_num,
+                #tuning.parse::<StringTuning>().unwrap(),
+                #capo,
+            )))
         });
     }
 }
 
 impl FretboardDsl {
     pub fn to_proto(&self) -> ProtoEntry {
-        let FretboardDsl {
-            tuning,
-            fret_num,
-            capo,
-        } = self;
-        let string_num = Context::fretted().string_num;
-        let fret_num = fret_num.unwrap_or(match string_num {
-            6 => GUITAR_FRET_NUM_ACOUSTIC,
-            _ => GUITAR_FRET_NUM_ACOUSTIC,
-        });
-        let capo = capo.unwrap_or(0);
-        match string_num {
-            4 => {
-                let tuning = [(Pitch::E, Octave::P2).into(); 4]; //todo;
-                ProtoEntry::from(FrettedEntry4::from(Fretboard4::new(fret_num, tuning, capo)))
-            }
-            _ => {
-                let tuning = match tuning {
-                    Some(ident) => GuitarTuning::from_ident(ident.to_string().as_str()),
-                    None => GuitarTuning::Standard,
-                };
-                ProtoEntry::from(FrettedEntry6::from(Fretboard6::new(
-                    fret_num,
-                    tuning.into(),
-                    capo,
-                )))
-            }
-        }
+        let fret_num = self.fret_num.unwrap_or(GUITAR_FRET_NUM_ACOUSTIC);
+        let capo = self.capo.unwrap_or(0);
+        let tuning = self.tuning;
+        ProtoEntry::from(FrettedEntry::from(Fretboard::new(fret_num, tuning, capo)))
     }
 }
@@ -1,5 +1,5 @@
-use fehler::throws;
-use notation_proto::prelude::{CoreEntry, FrettedEntry4, FrettedEntry6, Pick};
+use fehler::{throw, throws};
+use notation_proto::prelude::{CoreEntry, FrettedEntry, Pick};
 use notation_proto::proto_entry::ProtoEntry;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
@@ -24,13 +24,19 @@ impl PickDsl {
             input.parse::<Token![_]>()?;
         } else {
             while input.peek(LitInt) {
-                notes.push(input.parse()?);
+                notes.push(PickNoteDsl::parse_note(input, multied || with_paren)?);
                 if multied && !with_paren {
                     break;
                 }
             }
+            if notes.len() > Pick::MAX_NOTES {
+                throw!(Error::new(
+                    input.span(),
+                    format!("at most {} notes in a pick", Pick::MAX_NOTES)
+                ));
+            }
         }
-        let duration_tweak = DurationTweakDsl::try_parse(input);
+        let duration_tweak = DurationTweakDsl::try_parse(input)?;
         PickDsl {
             notes,
             duration_tweak,
@@ -50,12 +56,10 @@ impl ToTokens for PickDsl {
                 ProtoEntry::from(CoreEntry::from(#duration_quote))
             });
         } else {
-            let _string_num = Context::fretted().string_num;
             let notes_quote: Vec<_> = notes.iter().map(|x| quote! { #x }).collect();
-            let fretted_entry_quote = Context::fretted().fretted_entry_quote();
             tokens.extend(quote! {
-                ProtoEntry::from(#fretted_entry_quote::from(
-                    (Pick::from(vec![
+                ProtoEntry::from(FrettedEntry::from(
+                    (Pick::new(&[
                         #(#notes_quote),*
                     ]), #duration_quote)
                 ))
@@ -74,12 +78,8 @@ impl PickDsl {
         if notes.len() == 0 {
             ProtoEntry::from(CoreEntry::from(duration))
         } else {
-            let _string_num = Context::fretted().string_num;
             let notes: Vec<_> = notes.iter().map(|x| x.to_proto()).collect();
-            match Context::fretted().string_num {
-                4 => ProtoEntry::from(FrettedEntry4::from((Pick::from(notes), duration))),
-                _ => ProtoEntry::from(FrettedEntry6::from((Pick::from(notes), duration))),
-            }
+            ProtoEntry::from(FrettedEntry::from((Pick::new(&notes), duration)))
         }
     }
 }
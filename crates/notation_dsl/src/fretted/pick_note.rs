@@ -1,41 +1,147 @@
-use fehler::throws;
-use notation_proto::prelude::PickNote;
+use fehler::{throw, throws};
+use notation_proto::prelude::{PickNote, PickTechniques};
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::parse::{Error, Parse, ParseStream};
-use syn::{LitInt, Token};
+use syn::{Ident, LitInt, Token};
+
+use crate::context::Context;
 
 pub struct PickNoteDsl {
     pub string: u8,
     pub fret: Option<u8>,
+    pub techniques: PickTechniques,
 }
 
 impl Parse for PickNoteDsl {
     #[throws(Error)]
     fn parse(input: ParseStream) -> Self {
-        let string = input.parse::<LitInt>()?.base10_parse::<u8>()?;
+        Self::parse_note(input, true)?
+    }
+}
+
+impl PickNoteDsl {
+    /// Idents after the fret are techniques, unknown ones are errors when `strict`,
+    /// otherwise left for the next entry, e.g. `Pick 6 Shape (...)`.
+    #[throws(Error)]
+    pub fn parse_note(input: ParseStream, strict: bool) -> Self {
+        let string_lit = input.parse::<LitInt>()?;
+        let string = string_lit.base10_parse::<u8>()?;
+        let string_num = Context::fretted().parsed_string_num();
+        if string == 0 || string as usize > string_num {
+            throw!(Error::new(
+                string_lit.span(),
+                format!("Invalid string {}, should be 1 to {}", string, string_num)
+            ));
+        }
         let fret = if input.peek(Token![@]) {
             input.parse::<Token![@]>()?;
             Some(input.parse::<LitInt>()?.base10_parse::<u8>()?)
         } else {
             None
         };
-        PickNoteDsl { string, fret }
+        let mut techniques = PickTechniques::default();
+        while input.peek(Ident) {
+            let ident = input.fork().parse::<Ident>()?;
+            let text = ident.to_string();
+            match techniques.with_ident(&text) {
+                Some(x) => {
+                    input.parse::<Ident>()?;
+                    techniques = x;
+                }
+                None if PickTechniques::default().with_ident(&text).is_some() => {
+                    throw!(Error::new(
+                        ident.span(),
+                        format!("Duplicated technique: {}", text)
+                    ));
+                }
+                None if strict => {
+                    throw!(Error::new(
+                        ident.span(),
+                        format!("Invalid technique: {}", text)
+                    ));
+                }
+                None => break,
+            }
+        }
+        PickNoteDsl {
+            string,
+            fret,
+            techniques,
+        }
     }
 }
 
 impl ToTokens for PickNoteDsl {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let PickNoteDsl { string, fret } = self;
+        let PickNoteDsl {
+            string,
+            fret,
+            techniques,
+        } = self;
         let fret_quote = fret.map(|f| quote! { Some(#f) }).unwrap_or(quote! {None});
-        tokens.extend(quote! {
-            PickNote::new(#string, #fret_quote, None, None, None)
-        });
+        if techniques.is_empty() {
+            tokens.extend(quote! {
+                PickNote::new(#string, #fret_quote, None, None, None)
+            });
+        } else {
+            let techniques_quote = Self::quote_techniques(techniques);
+            tokens.extend(quote! {
+                PickNote::new(#string, #fret_quote, None, None, None)
+                    .with_techniques(#techniques_quote)
+            });
+        }
+    }
+}
+
+impl PickNoteDsl {
+    fn quote_techniques(techniques: &PickTechniques) -> TokenStream {
+        let PickTechniques {
+            link,
+            bend,
+            harmonic,
+            vibrato,
+            palm_mute,
+            dead,
+            let_ring,
+        } = techniques;
+        let link_quote = match link {
+            Some(link) => {
+                let ident = format_ident!("{}", format!("{:?}", link));
+                quote! { Some(PickLink::#ident) }
+            }
+            None => quote! { None },
+        };
+        let bend_quote = match bend {
+            Some(bend) => {
+                let (semitones, release) = (bend.semitones, bend.release);
+                quote! { Some(Bend { semitones: #semitones, release: #release }) }
+            }
+            None => quote! { None },
+        };
+        let harmonic_quote = match harmonic {
+            Some(harmonic) => {
+                let ident = format_ident!("{}", format!("{:?}", harmonic));
+                quote! { Some(Harmonic::#ident) }
+            }
+            None => quote! { None },
+        };
+        quote! {
+            PickTechniques {
+                link: #link_quote,
+                bend: #bend_quote,
+                harmonic: #harmonic_quote,
+                vibrato: #vibrato,
+                palm_mute: #palm_mute,
+                dead: #dead,
+                let_ring: #let_ring,
+            }
+        }
     }
 }
 
 impl PickNoteDsl {
     pub fn to_proto(&self) -> PickNote {
-        PickNote::new(self.string, self.fret, None, None, None)
+        PickNote::new(self.string, self.fret, None, None, None).with_techniques(self.techniques)
     }
 }
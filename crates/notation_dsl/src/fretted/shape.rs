@@ -1,5 +1,5 @@
-use fehler::throws;
-use notation_proto::prelude::{FrettedEntry4, FrettedEntry6, HandShape4, HandShape6};
+use fehler::{throw, throws};
+use notation_proto::prelude::{FrettedEntry, HandShape};
 use notation_proto::proto_entry::ProtoEntry;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
@@ -10,6 +10,7 @@ use crate::context::Context;
 use crate::core::duration::DurationTweakDsl;
 
 pub struct ShapeDsl {
+    pub string_num: usize,
     pub barre: Option<u8>,
     pub frets: Vec<Option<u8>>,
     pub duration_tweak: Option<DurationTweakDsl>,
@@ -18,10 +19,11 @@ pub struct ShapeDsl {
 impl Parse for ShapeDsl {
     #[throws(Error)]
     fn parse(input: ParseStream) -> Self {
+        let string_num = Context::fretted().parsed_string_num();
         let mut frets = vec![];
         if input.peek(token::Paren) {
             let content;
-            parenthesized!(content in input);
+            let paren = parenthesized!(content in input);
             while content.peek(LitInt) || content.peek(Token![_]) {
                 if content.peek(LitInt) {
                     frets.push(Some(content.parse::<LitInt>()?.base10_parse::<u8>()?));
@@ -31,6 +33,16 @@ impl Parse for ShapeDsl {
                 }
             }
             frets.reverse();
+            if frets.len() != string_num {
+                throw!(Error::new(
+                    paren.span,
+                    format!(
+                        "{} frets in a shape for {} strings",
+                        frets.len(),
+                        string_num
+                    )
+                ));
+            }
         }
         let mut barre = None;
         if input.peek(Token![+]) {
@@ -38,8 +50,9 @@ impl Parse for ShapeDsl {
             barre = Some(input.parse::<LitInt>()?.base10_parse::<u8>()?);
         }
 
-        let duration_tweak = DurationTweakDsl::try_parse(input);
+        let duration_tweak = DurationTweakDsl::try_parse(input)?;
         ShapeDsl {
+            string_num,
             barre,
             frets,
             duration_tweak,
@@ -47,17 +60,26 @@ impl Parse for ShapeDsl {
     }
 }
 
+impl ShapeDsl {
+    // No frets given means none of the strings is played.
+    fn string_frets(&self) -> Vec<Option<u8>> {
+        (0..self.string_num)
+            .map(|i| self.frets.get(i).copied().flatten())
+            .collect()
+    }
+}
+
 impl ToTokens for ShapeDsl {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let ShapeDsl {
+            string_num: _,
             barre,
-            frets,
+            frets: _,
             duration_tweak,
         } = self;
-        //let string_num = Context::fretted().string_num;
         let mut frets_quote: Vec<TokenStream> = vec![];
         let mut fingers_quote: Vec<TokenStream> = vec![];
-        for fret in frets {
+        for fret in self.string_frets() {
             frets_quote.push(match fret {
                 Some(fret) => quote! { Some(#fret) },
                 None => quote! { None },
@@ -65,17 +87,15 @@ impl ToTokens for ShapeDsl {
             fingers_quote.push(quote! { None });
         }
         let duration_quote = Context::duration_quote(duration_tweak);
-        let fretted_entry_quote = Context::fretted().fretted_entry_quote();
-        let hand_shape_quote = Context::fretted().hand_shape_quote();
         let barre = barre.unwrap_or(0);
         tokens.extend(quote! {
-            ProtoEntry::from(#fretted_entry_quote::from(
-                (#hand_shape_quote::new_barre(
-                    #barre, [
+            ProtoEntry::from(FrettedEntry::from(
+                (HandShape::new_barre(
+                    #barre, &[
                     #(#frets_quote),*
-                ], [
+                ], &[
                     #(#fingers_quote),*
-                ]), #duration_quote)
+                ]).unwrap(), #duration_quote)
             ))
         });
     }
@@ -84,35 +104,18 @@ impl ToTokens for ShapeDsl {
 impl ShapeDsl {
     pub fn to_proto(&self) -> ProtoEntry {
         let ShapeDsl {
+            string_num: _,
             barre,
             frets: _,
             duration_tweak,
         } = self;
         let barre = barre.unwrap_or(0);
         let duration = Context::tweaked_duration(duration_tweak);
-        match Context::fretted().string_num {
-            4 => {
-                let mut frets = [None; 4];
-                for i in 0..4 {
-                    frets[i] = self.frets.get(i).unwrap().clone();
-                }
-                let fingers = [None; 4];
-                ProtoEntry::from(FrettedEntry4::from((
-                    HandShape4::new_barre(barre, frets, fingers),
-                    duration,
-                )))
-            }
-            _ => {
-                let mut frets = [None; 6];
-                for i in 0..6 {
-                    frets[i] = self.frets.get(i).unwrap().clone();
-                }
-                let fingers = [None; 6];
-                ProtoEntry::from(FrettedEntry6::from((
-                    HandShape6::new_barre(barre, frets, fingers),
-                    duration,
-                )))
-            }
-        }
+        let frets = self.string_frets();
+        let fingers = vec![None; frets.len()];
+        ProtoEntry::from(FrettedEntry::from((
+            HandShape::new_barre(barre, &frets, &fingers).unwrap(),
+            duration,
+        )))
     }
 }
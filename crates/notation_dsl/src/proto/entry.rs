@@ -7,7 +7,10 @@ use syn::Ident;
 
 use crate::context::ContextDsl;
 
+use crate::core::articulation::ArticulationDsl;
 use crate::core::chord::ChordDsl;
+use crate::core::dynamic::DynamicDsl;
+use crate::core::tempo_change::TempoChangeDsl;
 use crate::core::tone::ToneDsl;
 use crate::core::word::WordDsl;
 use crate::fretted::fretboard::FretboardDsl;
@@ -24,6 +27,9 @@ pub enum EntryDsl {
     Mark(MarkDsl),
     Tone(MultibleDsl<ToneDsl>),
     Chord(MultibleDsl<ChordDsl>),
+    Dynamic(DynamicDsl),
+    Articulation(ArticulationDsl),
+    Tempo(TempoChangeDsl),
     Word(MultibleDsl<WordDsl>),
     Pick(MultibleDsl<PickDsl>),
     Shape(ShapeDsl),
@@ -41,6 +47,9 @@ impl EntryDsl {
             match input.parse::<Ident>()?.to_string().as_str() {
                 "Tone" => Self::Tone(input.parse()?),
                 "Chord" => Self::Chord(input.parse()?),
+                "Dynamic" => Self::Dynamic(input.parse()?),
+                "Articulation" => Self::Articulation(input.parse()?),
+                "Tempo" => Self::Tempo(input.parse()?),
                 "Word" => Self::Word(input.parse()?),
                 "Pick" => Self::Pick(input.parse()?),
                 "Shape" => Self::Shape(input.parse()?),
@@ -58,6 +67,9 @@ impl ToTokens for EntryDsl {
             Self::Context(x) => quote! { #x },
             Self::Tone(x) => quote! { #x },
             Self::Chord(x) => quote! { #x },
+            Self::Dynamic(x) => quote! { #x },
+            Self::Articulation(x) => quote! { #x },
+            Self::Tempo(x) => quote! { #x },
             Self::Word(x) => quote! { #x },
             Self::Pick(x) => quote! { #x },
             Self::Shape(x) => quote! { #x },
@@ -72,6 +84,9 @@ impl EntryDsl {
             EntryDsl::Mark(x) => entries.push(ProtoEntry::from(x.mark.clone())),
             EntryDsl::Tone(x) => x.add_proto(entries),
             EntryDsl::Chord(x) => x.add_proto(entries),
+            EntryDsl::Dynamic(x) => entries.push(x.to_proto()),
+            EntryDsl::Articulation(x) => entries.push(x.to_proto()),
+            EntryDsl::Tempo(x) => entries.push(x.to_proto()),
             EntryDsl::Word(x) => x.add_proto(entries),
             EntryDsl::Pick(x) => x.add_proto(entries),
             EntryDsl::Shape(x) => entries.push(x.to_proto()),
@@ -1,18 +1,19 @@
 use fehler::throws;
 
-use notation_proto::prelude::{Key, Scale, TabMeta};
+use notation_proto::prelude::{Key, TabMeta};
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::parse::{Error, Parse, ParseStream};
 use syn::Ident;
 
 use crate::context::Context;
+use crate::core::scale::ScaleDsl;
 use crate::core::signature::SignatureDsl;
 use crate::core::tempo::TempoDsl;
 
 pub struct MetaDsl {
-    pub key: Ident,
-    pub scale: Ident,
+    pub key: Key,
+    pub scale: ScaleDsl,
     pub signature: SignatureDsl,
     pub tempo: TempoDsl,
 }
@@ -20,10 +21,13 @@ pub struct MetaDsl {
 impl Parse for MetaDsl {
     #[throws(Error)]
     fn parse(input: ParseStream) -> Self {
-        let key = input.parse()?;
+        let ident = input.parse::<Ident>()?;
+        let key = Key::from_ident(ident.to_string().as_str())
+            .map_err(|err| Error::new(ident.span(), err))?;
         let scale = input.parse()?;
         let signature = input.parse()?;
-        let tempo = input.parse()?;
+        let tempo = input.parse::<TempoDsl>()?;
+        Context::set_tab_tempo(tempo.to_proto());
         MetaDsl {
             key,
             scale,
@@ -40,14 +44,13 @@ impl ToTokens for MetaDsl {
             signature,
             tempo,
         } = self;
-        let key_ident = key.to_string();
-        let scale_ident = scale.to_string();
-        Context::set_key(Key::from_ident(key_ident.as_str()));
-        Context::set_scale(Scale::from_ident(scale_ident.as_str()));
+        let key_ident = format_ident!("{}", key.to_ident());
+        Context::set_key(*key);
+        Context::set_scale(scale.to_proto());
         tokens.extend(quote! {
             TabMeta::new(
-                Key::from_ident(#key_ident),
-                Scale::from_ident(#scale_ident),
+                Key::#key_ident,
+                #scale,
                 #signature,
                 #tempo,
             )
@@ -56,8 +59,8 @@ impl ToTokens for MetaDsl {
 }
 impl MetaDsl {
     pub fn to_proto(&self) -> TabMeta {
-        let key = Key::from_ident(self.key.to_string().as_str());
-        let scale = Scale::from_ident(self.scale.to_string().as_str());
+        let key = self.key;
+        let scale = self.scale.to_proto();
         Context::set_key(key);
         Context::set_scale(scale);
         TabMeta::new(key, scale, self.signature.to_proto(), self.tempo.to_proto())
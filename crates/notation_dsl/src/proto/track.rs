@@ -6,6 +6,7 @@ use quote::{quote, ToTokens};
 use syn::parse::{Error, ParseStream};
 use syn::Ident;
 
+use crate::context::Context;
 use crate::proto::entry::EntryDsl;
 
 use super::id::IdDsl;
@@ -21,6 +22,8 @@ impl TrackDsl {
     pub fn parse_without_brace(input: ParseStream) -> Self {
         let id = input.parse()?;
         let kind = input.parse()?;
+        Context::set_current_tempo(Context::tempo().tab);
+        Context::reset_parsed_string_num();
         let entries = EntryDsl::parse_vec(input)?;
         TrackDsl { id, kind, entries }
     }
@@ -0,0 +1,310 @@
+use std::cmp::Reverse;
+
+use crate::prelude::{Finger, Fretboard, FrettedEntry, HandShape, Pick, PickNote, ShapeFingers};
+
+impl HandShape {
+    pub fn has_fingers(&self) -> bool {
+        self.fingers.iter().any(|x| x.is_some())
+    }
+    /// Fret under the index finger, which is the barre, or the lowest fretted one.
+    pub fn index_fret(&self) -> Option<u8> {
+        if self.barre() > 0 {
+            Some(self.barre())
+        } else {
+            self.frets.iter().flatten().filter(|x| *x > 0).min()
+        }
+    }
+    /// Index to pinky from the lowest fret and string, the index finger holds the barre,
+    /// or the lowest fret when there are more notes than fingers.
+    pub fn calc_fingers(&self) -> Vec<Option<Finger>> {
+        let mut fretted: Vec<(usize, u8)> = (1..=self.string_num() as u8)
+            .filter_map(|string| {
+                self.string_fret_with_barre(string)
+                    .filter(|x| *x > 0)
+                    .map(|x| (string as usize - 1, x))
+            })
+            .collect();
+        fretted.sort_by_key(|(string, fret)| (*fret, Reverse(*string)));
+        let barre = if self.barre() > 0 {
+            Some(self.barre())
+        } else if fretted.len() > Finger::FRETTING.len() {
+            fretted.first().map(|x| x.1)
+        } else {
+            None
+        };
+        let mut fingers = vec![None; self.string_num()];
+        let mut free = Finger::FRETTING.iter();
+        if let Some(barre) = barre {
+            free.next();
+            for (string, _) in fretted.iter().filter(|(_, fret)| *fret == barre) {
+                fingers[*string] = Some(Finger::Index);
+            }
+        }
+        for ((string, _), finger) in fretted
+            .iter()
+            .filter(|(_, fret)| Some(*fret) != barre)
+            .zip(free)
+        {
+            fingers[*string] = Some(*finger);
+        }
+        fingers
+    }
+    /// Fingers written in the tab are kept.
+    pub fn with_auto_fingers(&self) -> Self {
+        if self.has_fingers() {
+            *self
+        } else {
+            Self {
+                fingers: ShapeFingers::from_slice(&self.calc_fingers()),
+                ..*self
+            }
+        }
+    }
+}
+
+// Something the fretting hand has to do, with the entry it comes from.
+enum HandStep {
+    /// Holding a shape with the index finger on the fret.
+    Shape(u8),
+    /// Fretted notes of a pick, with the fingers written in the tab.
+    Pick(usize, Vec<(u8, Option<Finger>)>),
+}
+impl HandStep {
+    // Cost to play the step with the index finger on the position.
+    fn calc_cost(&self, position: u8) -> Option<u32> {
+        match self {
+            HandStep::Shape(fret) => (*fret == position).then_some(0),
+            HandStep::Pick(_, notes) => {
+                let mut cost = 0;
+                for (fret, finger) in notes.iter() {
+                    let offset = *fret as i32 - position as i32;
+                    let fixed = finger
+                        .and_then(|x| Finger::FRETTING.iter().position(|y| *y == x))
+                        .map(|x| x as i32);
+                    match fixed {
+                        Some(fixed) if fixed != offset => return None,
+                        _ if !(0..Finger::FRETTING.len() as i32).contains(&offset) => return None,
+                        _ => (),
+                    }
+                    // Pinky is the weakest one.
+                    if offset == Finger::FRETTING.len() as i32 - 1 {
+                        cost += 1;
+                    }
+                }
+                Some(cost)
+            }
+        }
+    }
+    // Index finger on the lowest note when the notes are too far apart for one position.
+    fn fallback_position(&self) -> u8 {
+        match self {
+            HandStep::Shape(fret) => *fret,
+            HandStep::Pick(_, notes) => notes.iter().map(|x| x.0).min().unwrap_or(1),
+        }
+    }
+}
+
+impl Fretboard {
+    pub const MOVE_COST: u32 = 2;
+    pub const STRETCH_COST: u32 = 10;
+
+    /// Fills the missing fretting fingers of the shapes and picks, which are in playing order.
+    ///
+    /// Shapes get fingers from their frets, picks with frets get them from the hand positions
+    /// chosen to move the hand the least, and picks on the shape strings follow the shape.
+    pub fn assign_fingers(&self, entries: &mut [FrettedEntry]) {
+        let mut steps = vec![];
+        for (index, entry) in entries.iter_mut().enumerate() {
+            match entry {
+                FrettedEntry::Shape(shape, _) => {
+                    *shape = shape.with_auto_fingers();
+                    if let Some(fret) = shape.index_fret() {
+                        steps.push(HandStep::Shape(fret));
+                    }
+                }
+                FrettedEntry::Pick(pick, _) => {
+                    let notes: Vec<(u8, Option<Finger>)> = pick
+                        .get_notes()
+                        .iter()
+                        .filter_map(|x| x.fret.filter(|f| *f > 0).map(|f| (f, x.fret_finger)))
+                        .collect();
+                    if !notes.is_empty() {
+                        steps.push(HandStep::Pick(index, notes));
+                    }
+                }
+                _ => (),
+            }
+        }
+        let positions = self.calc_hand_positions(&steps);
+        let mut step_positions = vec![None; entries.len()];
+        for (step, position) in steps.iter().zip(positions.iter()) {
+            if let HandStep::Pick(index, _) = step {
+                step_positions[*index] = Some(*position);
+            }
+        }
+        let mut shape: Option<HandShape> = None;
+        for (index, entry) in entries.iter_mut().enumerate() {
+            match entry {
+                FrettedEntry::Shape(current, _) => shape = Some(*current),
+                FrettedEntry::Pick(pick, _) => {
+                    let notes: Vec<PickNote> = pick
+                        .get_notes()
+                        .into_iter()
+                        .map(|note| PickNote {
+                            fret_finger: note.fret_finger.or_else(|| match note.fret {
+                                Some(0) => None,
+                                Some(fret) => step_positions[index].map(|position| {
+                                    let offset = fret.saturating_sub(position) as usize;
+                                    Finger::FRETTING[offset.min(Finger::FRETTING.len() - 1)]
+                                }),
+                                None => shape.and_then(|x| x.string_finger(note.string)),
+                            }),
+                            ..note
+                        })
+                        .collect();
+                    **pick = Pick::new(&notes);
+                }
+                _ => (),
+            }
+        }
+    }
+    // Shortest path through the index finger positions, each step can be played at the
+    // positions with a cost, and moving the hand between steps costs by the frets.
+    fn calc_hand_positions(&self, steps: &[HandStep]) -> Vec<u8> {
+        let max_position = self.fret_num().saturating_sub(1).max(1) as u8;
+        let positions: Vec<u8> = (1..=max_position).collect();
+        let mut costs: Vec<Option<u32>> = vec![Some(0); positions.len()];
+        let mut from: Vec<Vec<usize>> = vec![];
+        for step in steps.iter() {
+            let mut step_costs: Vec<Option<u32>> =
+                positions.iter().map(|x| step.calc_cost(*x)).collect();
+            if step_costs.iter().all(|x| x.is_none()) {
+                let fallback = step.fallback_position().clamp(1, max_position);
+                step_costs[(fallback - 1) as usize] = Some(Self::STRETCH_COST);
+            }
+            let mut next_costs = vec![None; positions.len()];
+            let mut next_from = vec![0; positions.len()];
+            for (i, step_cost) in step_costs.iter().enumerate() {
+                let step_cost = match step_cost {
+                    Some(x) => *x,
+                    None => continue,
+                };
+                for (j, cost) in costs.iter().enumerate() {
+                    if let Some(cost) = cost {
+                        let moving = if from.is_empty() {
+                            0
+                        } else {
+                            (i as i32 - j as i32).unsigned_abs() * Self::MOVE_COST
+                        };
+                        let total = cost + moving + step_cost;
+                        if next_costs[i].map(|x| total < x).unwrap_or(true) {
+                            next_costs[i] = Some(total);
+                            next_from[i] = j;
+                        }
+                    }
+                }
+            }
+            costs = next_costs;
+            from.push(next_from);
+        }
+        let mut result = vec![];
+        let mut current = costs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, x)| x.map(|cost| (cost, i)))
+            .min()
+            .map(|x| x.1)
+            .unwrap_or(0);
+        for step_from in from.iter().rev() {
+            result.push(positions[current]);
+            current = step_from[current];
+        }
+        result.reverse();
+        result
+    }
+}
+
+// Fretting fingers of the picks after assigning, one list for each pick.
+#[cfg(test)]
+fn test_pick_fingers(picks: &[&[(u8, u8, Option<Finger>)]]) -> Vec<Vec<Option<Finger>>> {
+    use notation_core::prelude::Duration;
+    let mut entries: Vec<FrettedEntry> = picks
+        .iter()
+        .map(|notes| {
+            let notes: Vec<PickNote> = notes
+                .iter()
+                .map(|(string, fret, finger)| {
+                    PickNote::new(*string, Some(*fret), *finger, None, None)
+                })
+                .collect();
+            FrettedEntry::from((Pick::new(&notes), Duration::_1_4))
+        })
+        .collect();
+    Fretboard::new(22, Default::default(), 0).assign_fingers(&mut entries);
+    entries
+        .iter()
+        .filter_map(|x| match x {
+            FrettedEntry::Pick(pick, _) => {
+                Some(pick.get_notes().iter().map(|x| x.fret_finger).collect())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_barre_fingers() {
+    use notation_core::prelude::Duration;
+    use Finger::*;
+    // F major, barred on the 1st fret.
+    let frets = [Some(0), Some(0), Some(1), Some(2), Some(2), Some(0)];
+    let shape = HandShape::new_barre(1, &frets, &[]).unwrap();
+    let mut entries = vec![
+        FrettedEntry::from((shape, Duration::_1_4)),
+        FrettedEntry::from((Pick::new(&[PickNote::new_string(6)]), Duration::_1_4)),
+    ];
+    Fretboard::new(22, Default::default(), 0).assign_fingers(&mut entries);
+    let fingers = vec![
+        Some(Index),
+        Some(Index),
+        Some(Middle),
+        Some(Pinky),
+        Some(Ring),
+        Some(Index),
+    ];
+    match (&entries[0], &entries[1]) {
+        (FrettedEntry::Shape(shape, _), FrettedEntry::Pick(pick, _)) => {
+            assert_eq!(shape.fingers.to_vec(), fingers);
+            assert_eq!(pick.get_notes()[0].fret_finger, Some(Index));
+        }
+        _ => panic!("entries changed"),
+    }
+}
+
+#[test]
+fn test_written_fingers() {
+    use Finger::*;
+    let frets = [Some(3), Some(0), Some(0), Some(0), Some(2), Some(3)];
+    let fingers = [Some(Pinky), None, None, None, Some(Index), Some(Middle)];
+    let shape = HandShape::new(&frets, &fingers).unwrap();
+    assert_eq!(shape.with_auto_fingers(), shape);
+    // Ring on the 5th fret puts the index on the 3rd.
+    let fingers = test_pick_fingers(&[&[(2, 5, Some(Ring)), (3, 3, None)]]);
+    assert_eq!(fingers, vec![vec![Some(Ring), Some(Index)]]);
+}
+
+#[test]
+fn test_run_fingers() {
+    use Finger::*;
+    let fingers = test_pick_fingers(&[
+        &[(3, 5, None)],
+        &[(3, 7, None)],
+        &[(2, 5, None)],
+        &[(2, 6, None)],
+        &[(2, 8, None)],
+        &[(1, 5, None)],
+    ]);
+    let expected = [Index, Ring, Index, Middle, Pinky, Index];
+    let expected: Vec<Vec<Option<Finger>>> = expected.iter().map(|x| vec![Some(*x)]).collect();
+    assert_eq!(fingers, expected);
+}
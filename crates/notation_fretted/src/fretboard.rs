@@ -2,118 +2,192 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
 use crate::pick::PickNote;
-use crate::prelude::Pick;
+use crate::prelude::{HandShape, Harmonic, Pick, StringTuning};
 
-use super::prelude::{HandShape4, HandShape6};
-use notation_core::prelude::{Note, Semitones, Tone, Scale, Key};
+use notation_core::prelude::{Key, Note, Scale, Semitones, Tone};
 
-macro_rules! impl_fretboard {
-    ($type:ident, $strings:literal, $hand_shape:ident) => {
-        #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
-        pub struct $type {
-            pub total_fret_num: usize,
-            #[serde(with = "serde_arrays")]
-            pub string_notes: [Semitones; $strings],
-            pub capo: u8,
+/// Strings are counted from the highest one, which is string 1.
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct Fretboard {
+    pub total_fret_num: usize,
+    pub tuning: StringTuning,
+    pub capo: u8,
+}
+impl Display for Fretboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "F: {}, C: {}, {})",
+            self.total_fret_num, self.capo, self.tuning
+        )
+    }
+}
+
+impl Fretboard {
+    /// Enough for 12-string guitars.
+    pub const MAX_STRINGS: usize = 12;
+
+    pub fn new(total_fret_num: usize, tuning: StringTuning, capo: u8) -> Self {
+        Self {
+            total_fret_num,
+            tuning,
+            capo,
+        }
+    }
+    pub fn with_capo(&self, capo: u8) -> Self {
+        Self { capo, ..*self }
+    }
+    /// Returns None if the capo would fall off the neck.
+    pub fn transpose_capo(&self, offset: i8) -> Option<Self> {
+        let capo = self.capo as i8 + offset;
+        if capo < 0 || capo as usize >= self.total_fret_num {
+            None
+        } else {
+            Some(self.with_capo(capo as u8))
+        }
+    }
+    /// string is 1-based.
+    pub fn string_note(&self, string: u8) -> Option<Semitones> {
+        self.tuning.string_note(string)
+    }
+    pub fn fretted_note(&self, scale: &Scale, key: &Key, string: u8, fret: u8) -> Option<Note> {
+        if fret as usize >= self.fret_num() {
+            None
+        } else if fret == 0 {
+            self.open_note(scale, key, string)
+        } else {
+            let semitones = self.tuning.fret_semitones(string, fret)?;
+            self.string_note(string)
+                .map(|x| self.get_capo_note(scale, key, x + Semitones(semitones as i8)))
         }
-        impl Display for $type {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(
-                    f,
-                    "F: {}, C: {}, {:?})",
-                    self.total_fret_num, self.capo, self.string_notes
+    }
+    /// Sounding note of the harmonic over the fret.
+    pub fn harmonic_note(
+        &self,
+        scale: &Scale,
+        key: &Key,
+        string: u8,
+        fret: u8,
+        harmonic: Harmonic,
+    ) -> Option<Note> {
+        if fret as usize >= self.fret_num() {
+            None
+        } else {
+            let fret = self.tuning.fret_semitones(string, fret)?;
+            self.string_note(string).map(|x| {
+                self.get_capo_note(
+                    scale,
+                    key,
+                    x + Semitones(harmonic.calc_semitones(fret) as i8),
                 )
-            }
+            })
         }
-
-        impl $type {
-            pub fn new(total_fret_num: usize, string_notes: [Semitones; $strings], capo: u8) -> Self {
-                Self {
-                    total_fret_num,
-                    string_notes,
-                    capo,
-                }
-            }
-            pub fn with_capo(&self, capo: u8) -> Self {
-                Self { capo, ..*self }
-            }
-            pub fn fretted_note(&self, scale: &Scale, key: &Key, string: u8, fret: u8) -> Option<Note> {
-                if fret as usize >= self.fret_num() {
-                    None
-                } else if fret == 0 {
-                    self.open_note(scale, key, string)
-                } else {
-                    if string == 0 || string as usize > self.string_notes.len() {
-                        None
-                    } else {
-                        let semitones = self.string_notes[(string - 1) as usize];
-                        Some(self.get_capo_note(scale, key, semitones + Semitones(fret as i8)))
-                    }
-                }
-            }
-            pub fn string_num(&self) -> usize {
-                self.string_notes.len()
-            }
-            pub fn fret_num(&self) -> usize {
-                self.total_fret_num - self.capo as usize
-            }
-            fn get_capo_note(&self, scale: &Scale, key: &Key, note: Semitones) -> Note {
-                scale.calc_note_from_semitones(key, note + Semitones(self.capo as i8))
-            }
-            pub fn open_notes(&self, scale: &Scale, key: &Key) -> [Note; $strings] {
-                self.string_notes.map(|x| self.get_capo_note(scale, key, x))
-            }
-            /// string is 1-based.
-            pub fn open_note(&self, scale: &Scale, key: &Key, string: u8) -> Option<Note> {
-                if string == 0 || string as usize > self.string_notes.len() {
-                    None
-                } else {
-                    Some(self.get_capo_note(scale, key, self.string_notes[(string - 1) as usize]))
-                }
-            }
-            pub fn shape_note(&self, scale: &Scale, key: &Key, shape: &$hand_shape, string: u8) -> Option<Note> {
-                shape
-                    .string_fret_with_barre(string)
-                    .and_then(|fret| self.fretted_note(scale, key, string, fret))
-            }
-            pub fn shape_fret_note(&self, scale: &Scale, key: &Key, shape: &$hand_shape, string: u8) -> Option<(u8, Note)> {
-                shape
-                    .string_fret_with_barre(string)
-                    .and_then(|fret| self.fretted_note(scale, key, string, fret).map(|n| (fret, n)))
-            }
-            pub fn shape_pick_note(
-                &self, scale: &Scale, key: &Key,
-                shape: &$hand_shape,
-                pick_note: PickNote,
-            ) -> Option<Note> {
-                match pick_note.fret {
-                    Some(fret) => self.fretted_note(scale, key, pick_note.string, fret),
-                    None => self.shape_note(scale, key, shape, pick_note.string),
-                }
-            }
-            pub fn shape_pick_fret_note(
-                &self, scale: &Scale, key: &Key,
-                shape: &$hand_shape,
-                pick_note: PickNote,
-            ) -> Option<(u8, Note)> {
-                match pick_note.fret {
-                    Some(fret) => self
-                        .fretted_note(scale, key, pick_note.string, fret)
-                        .map(|note| (fret, note)),
-                    None => self.shape_fret_note(scale, key, shape, pick_note.string),
-                }
-            }
-            pub fn pick_tone(&self, scale: &Scale, key: &Key, shape: &$hand_shape, pick: &Pick) -> Tone {
-                let notes: Vec<Option<Note>> = pick
-                    .get_notes()
-                    .into_iter()
-                    .map(|x| self.shape_pick_note(scale, key, shape, x))
-                    .collect();
-                notes.into()
-            }
+    }
+    pub fn string_num(&self) -> usize {
+        self.tuning.string_num()
+    }
+    pub fn fret_num(&self) -> usize {
+        self.total_fret_num - self.capo as usize
+    }
+    fn get_capo_note(&self, scale: &Scale, key: &Key, note: Semitones) -> Note {
+        scale.calc_note_from_semitones(key, note + Semitones(self.capo as i8))
+    }
+    pub fn open_notes(&self, scale: &Scale, key: &Key) -> Vec<Note> {
+        self.tuning
+            .string_notes()
+            .into_iter()
+            .map(|x| self.get_capo_note(scale, key, x))
+            .collect()
+    }
+    /// string is 1-based.
+    pub fn open_note(&self, scale: &Scale, key: &Key, string: u8) -> Option<Note> {
+        self.string_note(string)
+            .map(|x| self.get_capo_note(scale, key, x))
+    }
+    pub fn shape_note(
+        &self,
+        scale: &Scale,
+        key: &Key,
+        shape: &HandShape,
+        string: u8,
+    ) -> Option<Note> {
+        shape
+            .string_fret_with_barre(string)
+            .and_then(|fret| self.fretted_note(scale, key, string, fret))
+    }
+    pub fn shape_fret_note(
+        &self,
+        scale: &Scale,
+        key: &Key,
+        shape: &HandShape,
+        string: u8,
+    ) -> Option<(u8, Note)> {
+        shape.string_fret_with_barre(string).and_then(|fret| {
+            self.fretted_note(scale, key, string, fret)
+                .map(|n| (fret, n))
+        })
+    }
+    pub fn shape_pick_note(
+        &self,
+        scale: &Scale,
+        key: &Key,
+        shape: &HandShape,
+        pick_note: PickNote,
+    ) -> Option<Note> {
+        if pick_note.techniques.harmonic.is_some() {
+            return self
+                .shape_pick_fret_note(scale, key, shape, pick_note)
+                .map(|(_, note)| note);
         }
-    };
+        match pick_note.fret {
+            Some(fret) => self.fretted_note(scale, key, pick_note.string, fret),
+            None => self.shape_note(scale, key, shape, pick_note.string),
+        }
+    }
+    pub fn shape_pick_fret_note(
+        &self,
+        scale: &Scale,
+        key: &Key,
+        shape: &HandShape,
+        pick_note: PickNote,
+    ) -> Option<(u8, Note)> {
+        let fret_note = match pick_note.fret {
+            Some(fret) => self
+                .fretted_note(scale, key, pick_note.string, fret)
+                .map(|note| (fret, note)),
+            None => self.shape_fret_note(scale, key, shape, pick_note.string),
+        };
+        // Harmonics are shown at the touched fret, but sounding higher.
+        match (fret_note, pick_note.techniques.harmonic) {
+            (Some((fret, _)), Some(harmonic)) => self
+                .harmonic_note(scale, key, pick_note.string, fret, harmonic)
+                .map(|note| (fret, note)),
+            _ => fret_note,
+        }
+    }
+    pub fn pick_tone(&self, scale: &Scale, key: &Key, shape: &HandShape, pick: &Pick) -> Tone {
+        let notes: Vec<Note> = pick
+            .get_notes()
+            .into_iter()
+            .filter_map(|x| self.shape_pick_note(scale, key, shape, x))
+            .collect();
+        Tone::new(&notes)
+    }
 }
 
-impl_fretboard!(Fretboard6, 6, HandShape6);
-impl_fretboard!(Fretboard4, 4, HandShape4);
+#[test]
+fn test_banjo_short_string() {
+    let fretboard = Fretboard::new(22, StringTuning::Banjo, 0);
+    let (scale, key) = (Scale::Ionian, Key::G);
+    let note = |string, fret| {
+        fretboard
+            .fretted_note(&scale, &key, string, fret)
+            .map(Semitones::from)
+    };
+    let expected = |text: &str| text.parse::<Note>().ok().map(Semitones::from);
+    assert_eq!(note(5, 0), expected("G4"));
+    assert_eq!(note(5, 3), None);
+    assert_eq!(note(5, 5), None);
+    assert_eq!(note(5, 7), expected("A4"));
+    assert_eq!(note(4, 5), expected("G3"));
+}
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
+use notation_core::prelude::{CapacityError, InlineVec};
+
+use crate::prelude::Fretboard;
+
 #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub enum Finger {
     Thumb,
@@ -21,118 +25,153 @@ impl Display for Finger {
     }
 }
 
-macro_rules! impl_hand_shape {
-    ($type:ident, $strings:literal) => {
-        #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
-        pub struct $type {
-            pub barre: Option<u8>,
-            #[serde(with = "serde_arrays")]
-            pub frets: [Option<u8>; $strings],
-            #[serde(with = "serde_arrays")]
-            pub fingers: [Option<Finger>; $strings],
+impl Finger {
+    /// Fingers pressing the strings, from the index one.
+    pub const FRETTING: [Finger; 4] = [Finger::Index, Finger::Middle, Finger::Ring, Finger::Pinky];
+
+    /// Numbers used in tabs, 1 for the index finger to 4 for the pinky.
+    pub fn to_number_text(&self) -> String {
+        match self {
+            Finger::Thumb => "T".to_owned(),
+            _ => Self::FRETTING
+                .iter()
+                .position(|x| x == self)
+                .map(|x| (x + 1).to_string())
+                .unwrap_or_default(),
         }
-        impl Display for $type {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "[")?;
-                for index in 0..self.frets.len() {
-                    let fret = self.frets[self.frets.len() - 1 - index];
-                    match fret {
-                        Some(fret) => write!(f, "{}", fret)?,
-                        None => write!(f, "x")?,
-                    }
-                }
-                write!(f, "]")?;
-                Ok(())
+    }
+}
+
+pub type ShapeFrets = InlineVec<Option<u8>, { Fretboard::MAX_STRINGS }>;
+pub type ShapeFingers = InlineVec<Option<Finger>, { Fretboard::MAX_STRINGS }>;
+
+/// Frets and fingers for each string, from the highest one.
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct HandShape {
+    pub barre: Option<u8>,
+    pub frets: ShapeFrets,
+    pub fingers: ShapeFingers,
+}
+impl Display for HandShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for fret in self.frets.to_vec().into_iter().rev() {
+            match fret {
+                Some(fret) => write!(f, "{}", fret)?,
+                None => write!(f, "x")?,
             }
         }
-        impl Default for $type {
-            fn default() -> Self {
-                let frets = [Some(0); $strings];
-                let fingers = [None; $strings];
-                Self::new(frets, fingers)
-            }
+        write!(f, "]")?;
+        Ok(())
+    }
+}
+impl HandShape {
+    /// Errors if there are more strings than `Fretboard::MAX_STRINGS`.
+    pub fn new_barre(
+        barre: u8,
+        frets: &[Option<u8>],
+        fingers: &[Option<Finger>],
+    ) -> Result<Self, CapacityError> {
+        Ok(Self {
+            barre: if barre == 0 { None } else { Some(barre) },
+            frets: ShapeFrets::try_from_slice(frets)?,
+            fingers: ShapeFingers::try_from_slice(fingers)?,
+        })
+    }
+    pub fn new(frets: &[Option<u8>], fingers: &[Option<Finger>]) -> Result<Self, CapacityError> {
+        Self::new_barre(0, frets, fingers)
+    }
+    /// All strings played open, the count is the one of a fretboard, so always fits.
+    pub fn open(string_num: usize) -> Self {
+        Self {
+            barre: None,
+            frets: ShapeFrets::from_slice(&vec![Some(0); string_num]),
+            fingers: ShapeFingers::from_slice(&vec![None; string_num]),
         }
-        impl $type {
-            pub fn new_barre(
-                barre: u8,
-                frets: [Option<u8>; $strings],
-                fingers: [Option<Finger>; $strings],
-            ) -> Self {
-                if barre == 0 {
-                    Self {
-                        barre: None,
-                        frets,
-                        fingers,
-                    }
-                } else {
-                    Self {
-                        barre: Some(barre),
-                        frets,
-                        fingers,
-                    }
-                }
-            }
-            pub fn new(frets: [Option<u8>; $strings], fingers: [Option<Finger>; $strings]) -> Self {
-                Self {
-                    barre: None,
-                    frets,
-                    fingers,
-                }
-            }
-            pub fn barre(&self) -> u8 {
-                self.barre.unwrap_or(0)
-            }
-            pub fn string_fret(&self, string: u8) -> Option<u8> {
-                if string == 0 || string as usize > self.frets.len() {
-                    None
-                } else {
-                    self.frets[string as usize - 1]
-                }
-            }
-            pub fn string_fret_with_barre(&self, string: u8) -> Option<u8> {
-                self.string_fret(string).map(|x| x + self.barre())
-            }
-            pub fn max_fret(&self) -> u8 {
-                let mut max = 0;
-                for index in 0..self.frets.len() {
-                    if let Some(fret) = self.frets[index] {
-                        if fret > max {
-                            max = fret
-                        }
-                    }
-                }
-                max
-            }
-            pub fn max_fret_with_barre(&self) -> u8 {
-                self.max_fret() + self.barre()
-            }
+    }
+    pub fn string_num(&self) -> usize {
+        self.frets.len()
+    }
+    pub fn barre(&self) -> u8 {
+        self.barre.unwrap_or(0)
+    }
+    pub fn string_fret(&self, string: u8) -> Option<u8> {
+        if string == 0 {
+            None
+        } else {
+            self.frets.get(string as usize - 1).flatten()
         }
-
-        impl From<([Option<u8>; $strings], [Option<Finger>; $strings])> for $type {
-            fn from(v: ([Option<u8>; $strings], [Option<Finger>; $strings])) -> Self {
-                Self::new(v.0, v.1)
-            }
+    }
+    pub fn string_finger(&self, string: u8) -> Option<Finger> {
+        if string == 0 {
+            None
+        } else {
+            self.fingers.get(string as usize - 1).flatten()
         }
-
-        impl From<[Option<u8>; $strings]> for $type {
-            fn from(v: [Option<u8>; $strings]) -> Self {
-                Self::new(v, [None; $strings])
-            }
+    }
+    pub fn string_fret_with_barre(&self, string: u8) -> Option<u8> {
+        self.string_fret(string).map(|x| x + self.barre())
+    }
+    pub fn max_fret(&self) -> u8 {
+        self.frets.iter().flatten().max().unwrap_or(0)
+    }
+    pub fn max_fret_with_barre(&self) -> u8 {
+        self.max_fret() + self.barre()
+    }
+    /// Move the shape along the neck, open shapes got barred when moving up,
+    /// returns None if any string would go below the nut.
+    pub fn transpose(&self, offset: i8) -> Option<Self> {
+        let barre = self.barre() as i8 + offset;
+        if barre >= 0 {
+            return Some(Self {
+                barre: if barre == 0 { None } else { Some(barre as u8) },
+                ..*self
+            });
         }
-
-        impl From<(u8, [Option<u8>; $strings], [Option<Finger>; $strings])> for $type {
-            fn from(v: (u8, [Option<u8>; $strings], [Option<Finger>; $strings])) -> Self {
-                Self::new_barre(v.0, v.1, v.2)
-            }
+        let mut frets = Vec::new();
+        for fret in self.frets.iter() {
+            frets.push(match fret {
+                Some(x) if (x as i8) + barre < 0 => return None,
+                Some(x) => Some((x as i8 + barre) as u8),
+                None => None,
+            });
         }
+        Some(Self {
+            barre: None,
+            frets: ShapeFrets::from_slice(&frets),
+            ..*self
+        })
+    }
+}
 
-        impl From<(u8, [Option<u8>; $strings])> for $type {
-            fn from(v: (u8, [Option<u8>; $strings])) -> Self {
-                Self::new_barre(v.0, v.1, [None; $strings])
-            }
-        }
-    };
+impl<const S: usize> TryFrom<([Option<u8>; S], [Option<Finger>; S])> for HandShape {
+    type Error = CapacityError;
+
+    fn try_from(v: ([Option<u8>; S], [Option<Finger>; S])) -> Result<Self, Self::Error> {
+        Self::new(&v.0, &v.1)
+    }
 }
 
-impl_hand_shape!(HandShape6, 6);
-impl_hand_shape!(HandShape4, 4);
+impl<const S: usize> TryFrom<[Option<u8>; S]> for HandShape {
+    type Error = CapacityError;
+
+    fn try_from(v: [Option<u8>; S]) -> Result<Self, Self::Error> {
+        Self::new(&v, &[None; S])
+    }
+}
+
+impl<const S: usize> TryFrom<(u8, [Option<u8>; S], [Option<Finger>; S])> for HandShape {
+    type Error = CapacityError;
+
+    fn try_from(v: (u8, [Option<u8>; S], [Option<Finger>; S])) -> Result<Self, Self::Error> {
+        Self::new_barre(v.0, &v.1, &v.2)
+    }
+}
+
+impl<const S: usize> TryFrom<(u8, [Option<u8>; S])> for HandShape {
+    type Error = CapacityError;
+
+    fn try_from(v: (u8, [Option<u8>; S])) -> Result<Self, Self::Error> {
+        Self::new_barre(v.0, &v.1, &[None; S])
+    }
+}
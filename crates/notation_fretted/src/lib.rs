@@ -1,18 +1,31 @@
+pub mod fingering;
 pub mod fretboard;
 pub mod fretted_entry;
 pub mod hand;
+pub mod melody;
 pub mod pick;
 pub mod strum;
+pub mod technique;
+pub mod tuning;
+pub mod voicing;
 
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::fretboard::{Fretboard4, Fretboard6};
+    pub use crate::fretboard::Fretboard;
     #[doc(hidden)]
-    pub use crate::fretted_entry::{FrettedEntry4, FrettedEntry6};
+    pub use crate::fretted_entry::FrettedEntry;
     #[doc(hidden)]
-    pub use crate::hand::{Finger, HandShape4, HandShape6};
+    pub use crate::hand::{Finger, HandShape, ShapeFingers, ShapeFrets};
     #[doc(hidden)]
-    pub use crate::pick::{Pick, PickNote};
+    pub use crate::melody::MelodyConstraints;
+    #[doc(hidden)]
+    pub use crate::pick::{Pick, PickNote, PickNotes};
     #[doc(hidden)]
     pub use crate::strum::Strum;
+    #[doc(hidden)]
+    pub use crate::technique::{Bend, Harmonic, PickLink, PickTechniques};
+    #[doc(hidden)]
+    pub use crate::tuning::{StringTuning, TuningNotes};
+    #[doc(hidden)]
+    pub use crate::voicing::{Voicing, VoicingConstraints};
 }
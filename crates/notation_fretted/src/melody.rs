@@ -0,0 +1,346 @@
+use notation_core::prelude::{Note, Semitones, Tone};
+
+use crate::prelude::{Finger, Fretboard, Pick, PickNote};
+
+/// Preferences for placing melody notes onto the strings, frets are counted from the capo.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct MelodyConstraints {
+    /// Fret window the hand prefers to stay in, open strings are always inside it.
+    pub min_fret: u8,
+    pub max_fret: u8,
+    pub allow_open: bool,
+    /// Octaves to move the notes, e.g. -1 for vocal parts sung higher than the guitar.
+    pub octave: i8,
+    /// Cost for each fret the hand moves between notes.
+    pub shift_cost: u32,
+    /// Cost for each fret out of the window.
+    pub window_cost: u32,
+    /// Cost for each open string, zero to prefer them over fretted notes.
+    pub open_cost: u32,
+}
+impl Default for MelodyConstraints {
+    fn default() -> Self {
+        Self {
+            min_fret: 0,
+            max_fret: 5,
+            allow_open: true,
+            octave: 0,
+            shift_cost: 3,
+            window_cost: 2,
+            open_cost: 1,
+        }
+    }
+}
+
+// Places of the notes in a tone, as (string, fret).
+type TonePlaces = Vec<(u8, u8)>;
+
+struct MelodySearch<'a> {
+    fretboard: &'a Fretboard,
+    constraints: &'a MelodyConstraints,
+}
+impl<'a> MelodySearch<'a> {
+    fn max_position(&self) -> u8 {
+        self.fretboard.fret_num().saturating_sub(1).max(1) as u8
+    }
+    fn note_places(&self, note: &Note) -> Vec<(u8, u8)> {
+        let semitones = Semitones::from(*note).0 as i32 + self.constraints.octave as i32 * 12
+            - self.fretboard.capo as i32;
+        (1..=self.fretboard.string_num() as u8)
+            .filter_map(|string| {
+                let open = self.fretboard.string_note(string)?.0 as i32;
+                let nut = self.fretboard.tuning.string_nut_fret(string) as i32;
+                let fret = match semitones - open {
+                    offset if offset > 0 => offset + nut,
+                    offset => offset,
+                };
+                let open_allowed = fret > 0 || self.constraints.allow_open;
+                if fret < 0 || fret as usize >= self.fretboard.fret_num() || !open_allowed {
+                    None
+                } else {
+                    Some((string, fret as u8))
+                }
+            })
+            .collect()
+    }
+    // Every note on its own string, within the reach of the fingers.
+    fn tone_places(&self, notes: &[Note]) -> Vec<TonePlaces> {
+        let note_places: Vec<Vec<(u8, u8)>> = notes.iter().map(|x| self.note_places(x)).collect();
+        let mut result = vec![];
+        Self::search_places(&note_places, &mut vec![], &mut result);
+        result
+    }
+    fn search_places(
+        note_places: &[Vec<(u8, u8)>],
+        current: &mut TonePlaces,
+        result: &mut Vec<TonePlaces>,
+    ) {
+        let places = match note_places.get(current.len()) {
+            Some(places) => places,
+            None => {
+                result.push(current.clone());
+                return;
+            }
+        };
+        for (string, fret) in places.iter() {
+            if current.iter().any(|x| x.0 == *string) {
+                continue;
+            }
+            let fretted = current
+                .iter()
+                .map(|x| x.1)
+                .chain([*fret])
+                .filter(|x| *x > 0);
+            let (min, max) = fretted.fold((u8::MAX, 0), |(min, max), x| (min.min(x), max.max(x)));
+            if max > min && (max - min) as usize >= Finger::FRETTING.len() {
+                continue;
+            }
+            current.push((*string, *fret));
+            Self::search_places(note_places, current, result);
+            current.pop();
+        }
+    }
+    // Cost to play the places with the index finger on the position.
+    fn calc_cost(&self, places: &TonePlaces, position: u8) -> Option<u32> {
+        let mut cost = 0;
+        for (_, fret) in places.iter() {
+            if *fret == 0 {
+                cost += self.constraints.open_cost;
+                continue;
+            }
+            if *fret < position || (fret - position) as usize >= Finger::FRETTING.len() {
+                return None;
+            }
+            // Pinky is the weakest one.
+            if (fret - position) as usize == Finger::FRETTING.len() - 1 {
+                cost += 1;
+            }
+            if *fret < self.constraints.min_fret {
+                cost += (self.constraints.min_fret - fret) as u32 * self.constraints.window_cost;
+            } else if *fret > self.constraints.max_fret {
+                cost += (fret - self.constraints.max_fret) as u32 * self.constraints.window_cost;
+            }
+        }
+        Some(cost)
+    }
+    // Shortest path through the places and hand positions, moving the hand costs by the frets.
+    fn calc_path(&self, steps: &[Vec<TonePlaces>]) -> Vec<(usize, u8)> {
+        let positions: Vec<u8> = (1..=self.max_position()).collect();
+        let mut costs: Vec<Option<(u32, usize)>> = vec![Some((0, 0)); positions.len()];
+        let mut from: Vec<Vec<usize>> = vec![];
+        let mut choices: Vec<Vec<usize>> = vec![];
+        for (index, step) in steps.iter().enumerate() {
+            let mut next_costs: Vec<Option<(u32, usize)>> = vec![None; positions.len()];
+            let mut next_from = vec![0; positions.len()];
+            for (i, position) in positions.iter().enumerate() {
+                // Best places at the position, ties go to the earlier ones.
+                let step_cost = step
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(choice, places)| {
+                        self.calc_cost(places, *position).map(|x| (x, choice))
+                    })
+                    .min();
+                let (step_cost, choice) = match step_cost {
+                    Some(x) => x,
+                    None => continue,
+                };
+                for (j, cost) in costs.iter().enumerate() {
+                    if let Some((cost, _)) = cost {
+                        let moving = if index == 0 {
+                            0
+                        } else {
+                            (i as i32 - j as i32).unsigned_abs() * self.constraints.shift_cost
+                        };
+                        let total = cost + moving + step_cost;
+                        if next_costs[i].map(|x| total < x.0).unwrap_or(true) {
+                            next_costs[i] = Some((total, choice));
+                            next_from[i] = j;
+                        }
+                    }
+                }
+            }
+            choices.push(
+                next_costs
+                    .iter()
+                    .map(|x| x.map(|y| y.1).unwrap_or(0))
+                    .collect(),
+            );
+            costs = next_costs;
+            from.push(next_from);
+        }
+        let mut result = vec![];
+        let mut current = costs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, x)| x.map(|(cost, _)| (cost, i)))
+            .min()
+            .map(|x| x.1)
+            .unwrap_or(0);
+        for (step_from, step_choices) in from.iter().zip(choices.iter()).rev() {
+            result.push((step_choices[current], positions[current]));
+            current = step_from[current];
+        }
+        result.reverse();
+        result
+    }
+}
+
+impl Fretboard {
+    /// Picks to play the tones as a melody, with the fingers from the hand positions,
+    /// returns None for the tones can't be played on the fretboard.
+    pub fn calc_melody_picks(
+        &self,
+        tones: &[Tone],
+        constraints: &MelodyConstraints,
+    ) -> Vec<Option<Pick>> {
+        let search = MelodySearch {
+            fretboard: self,
+            constraints,
+        };
+        let mut indexes = vec![];
+        let mut steps = vec![];
+        for (index, tone) in tones.iter().enumerate() {
+            let places = search.tone_places(&tone.get_notes());
+            // Tones out of the fretboard are skipped.
+            if !tone.is_none()
+                && places
+                    .iter()
+                    .any(|x| (1..=search.max_position()).any(|p| search.calc_cost(x, p).is_some()))
+            {
+                indexes.push(index);
+                steps.push(places);
+            }
+        }
+        let path = search.calc_path(&steps);
+        let mut picks = vec![None; tones.len()];
+        for ((index, places), (choice, position)) in indexes.iter().zip(steps.iter()).zip(path) {
+            let notes: Vec<PickNote> = places[choice]
+                .iter()
+                .map(|(string, fret)| {
+                    let finger = (*fret > 0).then(|| Finger::FRETTING[(fret - position) as usize]);
+                    PickNote::new(*string, Some(*fret), finger, None, None)
+                })
+                .collect();
+            picks[*index] = Some(Pick::new(&notes));
+        }
+        picks
+    }
+}
+
+#[cfg(test)]
+fn test_picks(texts: &str, constraints: &MelodyConstraints) -> Vec<Option<Vec<PickNote>>> {
+    let tones: Vec<Tone> = texts
+        .split_whitespace()
+        .map(|x| Tone::new(&[x.parse::<Note>().unwrap()]))
+        .collect();
+    Fretboard::new(22, Default::default(), 0)
+        .calc_melody_picks(&tones, constraints)
+        .into_iter()
+        .map(|x| x.map(|pick| pick.get_notes()))
+        .collect()
+}
+
+#[cfg(test)]
+fn test_places(texts: &str, constraints: &MelodyConstraints) -> Vec<Option<(u8, u8)>> {
+    test_picks(texts, constraints)
+        .into_iter()
+        .map(|x| x.map(|notes| (notes[0].string, notes[0].fret.unwrap())))
+        .collect()
+}
+
+// Index finger positions of the fretted notes.
+#[cfg(test)]
+fn test_positions(texts: &str, constraints: &MelodyConstraints) -> Vec<u8> {
+    test_picks(texts, constraints)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|x| {
+            let finger = Finger::FRETTING
+                .iter()
+                .position(|f| Some(*f) == x.fret_finger)?;
+            Some(x.fret? - finger as u8)
+        })
+        .collect()
+}
+
+#[test]
+fn test_melody_window() {
+    let constraints = MelodyConstraints::default();
+    assert_eq!(test_places("A4", &constraints), vec![Some((1, 5))]);
+    let constraints = MelodyConstraints {
+        min_fret: 9,
+        max_fret: 12,
+        ..Default::default()
+    };
+    assert_eq!(test_places("A4", &constraints), vec![Some((2, 10))]);
+}
+
+#[test]
+fn test_melody_open_strings() {
+    let constraints = MelodyConstraints {
+        max_fret: 3,
+        open_cost: 0,
+        ..Default::default()
+    };
+    assert_eq!(test_places("B3", &constraints), vec![Some((2, 0))]);
+    let constraints = MelodyConstraints {
+        max_fret: 3,
+        open_cost: 5,
+        ..Default::default()
+    };
+    assert_eq!(test_places("B3", &constraints), vec![Some((3, 4))]);
+    let constraints = MelodyConstraints {
+        allow_open: false,
+        ..Default::default()
+    };
+    assert_eq!(test_places("E2 B3", &constraints), vec![None, Some((3, 4))]);
+}
+
+#[test]
+fn test_melody_shift() {
+    let run = "C4 D4 E4 F4 G4 A4 B4 C5";
+    let positions = test_positions(run, &MelodyConstraints::default());
+    assert!(positions.iter().any(|x| *x != positions[0]));
+    let constraints = MelodyConstraints {
+        shift_cost: 100,
+        ..Default::default()
+    };
+    let positions = test_positions(run, &constraints);
+    assert_eq!(positions.len(), 8);
+    assert!(positions.iter().all(|x| *x == positions[0]));
+}
+
+#[test]
+fn test_melody_scale_run() {
+    let run = "C4 D4 E4 F4 G4 A4 B4 C5";
+    let places: Vec<(u8, u8)> = test_places(run, &MelodyConstraints::default())
+        .into_iter()
+        .flatten()
+        .collect();
+    assert_eq!(
+        places,
+        vec![
+            (3, 5),
+            (2, 3),
+            (2, 5),
+            (2, 6),
+            (1, 3),
+            (1, 5),
+            (1, 7),
+            (1, 8)
+        ]
+    );
+    let fretboard = Fretboard::new(22, Default::default(), 0);
+    for ((string, fret), text) in places.iter().zip(run.split_whitespace()) {
+        let open = fretboard.string_note(*string).unwrap();
+        let note = text.parse::<Note>().unwrap();
+        assert_eq!(
+            open + Semitones(*fret as i8),
+            Semitones::from(note),
+            "{}",
+            text
+        );
+    }
+}
@@ -2,7 +2,9 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
-use crate::prelude::Finger;
+use notation_core::prelude::{CapacityError, InlineVec};
+
+use crate::prelude::{Finger, PickTechniques};
 use crate::strum::StrumDirection;
 
 #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
@@ -12,6 +14,8 @@ pub struct PickNote {
     pub fret_finger: Option<Finger>,
     pub pick_finger: Option<Finger>,
     pub pick_direction: Option<StrumDirection>,
+    #[serde(default)]
+    pub techniques: PickTechniques,
 }
 impl Display for PickNote {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -31,7 +35,11 @@ impl Display for PickNote {
             self.pick_direction
                 .map(|x| format!("*{}", x))
                 .unwrap_or("".to_string()),
-        )
+        )?;
+        if !self.techniques.is_empty() {
+            write!(f, " {}", self.techniques)?;
+        }
+        Ok(())
     }
 }
 impl PickNote {
@@ -48,6 +56,13 @@ impl PickNote {
             fret_finger,
             pick_finger,
             pick_direction,
+            techniques: PickTechniques::default(),
+        }
+    }
+    pub fn with_techniques(&self, techniques: PickTechniques) -> Self {
+        Self {
+            techniques,
+            ..*self
         }
     }
     pub fn new_string(string: u8) -> Self {
@@ -58,108 +73,65 @@ impl PickNote {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
-pub enum Pick {
-    None,
-    Single(PickNote),
-    Double(PickNote, PickNote),
-    Triple(PickNote, PickNote, PickNote),
-    Tetra(PickNote, PickNote, PickNote, PickNote),
-    Penta(PickNote, PickNote, PickNote, PickNote, PickNote),
-    Hexa(PickNote, PickNote, PickNote, PickNote, PickNote, PickNote),
+pub type PickNotes = InlineVec<PickNote, { Pick::MAX_NOTES }>;
+
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub struct Pick(PickNotes);
+
+impl Pick {
+    /// Enough for 12-string guitars and harp parts.
+    pub const MAX_NOTES: usize = 16;
+    pub const NONE: Self = Self(PickNotes::new());
+
+    pub const fn new(notes: &[PickNote]) -> Self {
+        Self(PickNotes::from_slice(notes))
+    }
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn notes(&self) -> PickNotes {
+        self.0
+    }
 }
 
 impl Display for Pick {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match *self {
-            Pick::None => write!(f, "<Pick>()"),
-            Pick::Single(p1) => write!(f, "<Pick>({})", p1),
-            Pick::Double(p1, p2) => write!(f, "<Pick>({}, {})", p1, p2),
-            Pick::Triple(p1, p2, p3) => write!(f, "<Pick>({}, {}, {})", p1, p2, p3),
-            Pick::Tetra(p1, p2, p3, p4) => {
-                write!(f, "<Pick>({}, {}, {}, {})", p1, p2, p3, p4)
-            }
-            Pick::Penta(p1, p2, p3, p4, p5) => {
-                write!(f, "<Pick>({}, {}, {}, {}, {})", p1, p2, p3, p4, p5)
-            }
-            Pick::Hexa(p1, p2, p3, p4, p5, p6) => {
-                write!(f, "<Pick>({}, {}, {}, {}, {}, {})", p1, p2, p3, p4, p5, p6)
-            }
-        }
+        let notes: Vec<String> = self.0.iter().map(|x| x.to_string()).collect();
+        write!(f, "<Pick>({})", notes.join(", "))
     }
 }
 
 impl From<()> for Pick {
     fn from(_: ()) -> Self {
-        Self::None
+        Self::NONE
     }
 }
 
 impl From<PickNote> for Pick {
     fn from(v: PickNote) -> Self {
-        Self::Single(v)
-    }
-}
-
-impl From<(PickNote, PickNote)> for Pick {
-    fn from(v: (PickNote, PickNote)) -> Self {
-        Self::Double(v.0, v.1)
+        Self::new(&[v])
     }
 }
 
-impl From<(PickNote, PickNote, PickNote)> for Pick {
-    fn from(v: (PickNote, PickNote, PickNote)) -> Self {
-        Self::Triple(v.0, v.1, v.2)
-    }
-}
-
-impl From<(PickNote, PickNote, PickNote, PickNote)> for Pick {
-    fn from(v: (PickNote, PickNote, PickNote, PickNote)) -> Self {
-        Self::Tetra(v.0, v.1, v.2, v.3)
-    }
-}
+impl TryFrom<&[PickNote]> for Pick {
+    type Error = CapacityError;
 
-impl From<(PickNote, PickNote, PickNote, PickNote, PickNote)> for Pick {
-    fn from(v: (PickNote, PickNote, PickNote, PickNote, PickNote)) -> Self {
-        Self::Penta(v.0, v.1, v.2, v.3, v.4)
+    fn try_from(v: &[PickNote]) -> Result<Self, Self::Error> {
+        InlineVec::try_from_slice(v).map(Self)
     }
 }
 
-impl From<(PickNote, PickNote, PickNote, PickNote, PickNote, PickNote)> for Pick {
-    fn from(v: (PickNote, PickNote, PickNote, PickNote, PickNote, PickNote)) -> Self {
-        Self::Hexa(v.0, v.1, v.2, v.3, v.4, v.5)
-    }
-}
+impl TryFrom<Vec<PickNote>> for Pick {
+    type Error = CapacityError;
 
-impl From<Vec<PickNote>> for Pick {
-    fn from(v: Vec<PickNote>) -> Self {
-        match v.len() {
-            0 => Self::None,
-            1 => Self::from(v[0]),
-            2 => Self::from((v[0], v[1])),
-            3 => Self::from((v[0], v[1], v[2])),
-            4 => Self::from((v[0], v[1], v[2], v[3])),
-            5 => Self::from((v[0], v[1], v[2], v[3], v[4])),
-            6 => Self::from((v[0], v[1], v[2], v[3], v[4], v[5])),
-            _ => {
-                println!("PickNote lost: {}", v.len() - 6);
-                Self::from((v[0], v[1], v[2], v[3], v[4], v[5]))
-            }
-        }
+    fn try_from(v: Vec<PickNote>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
     }
 }
 
 impl Pick {
     pub fn get_notes(&self) -> Vec<PickNote> {
-        match *self {
-            Self::None => vec![],
-            Self::Single(p1) => vec![p1],
-            Self::Double(p1, p2) => vec![p1, p2],
-            Self::Triple(p1, p2, p3) => vec![p1, p2, p3],
-            Self::Tetra(p1, p2, p3, p4) => vec![p1, p2, p3, p4],
-            Self::Penta(p1, p2, p3, p4, p5) => vec![p1, p2, p3, p4, p5],
-            Self::Hexa(p1, p2, p3, p4, p5, p6) => vec![p1, p2, p3, p4, p5, p6],
-        }
+        self.0.to_vec()
     }
     pub fn get_pick_note(&self, string: u8) -> Option<PickNote> {
         for note in self.get_notes().iter() {
@@ -181,6 +153,19 @@ impl Pick {
         }
         max
     }
+    /// Only the notes with fret are moved, others are following the hand shape.
+    pub fn transpose_frets(&self, offset: i8) -> Option<Self> {
+        let mut notes = Vec::new();
+        for note in self.get_notes() {
+            let fret = match note.fret {
+                Some(fret) if (fret as i8) + offset < 0 => return None,
+                Some(fret) => Some(((fret as i8) + offset) as u8),
+                None => None,
+            };
+            notes.push(PickNote { fret, ..note });
+        }
+        Some(Self::new(&notes))
+    }
 }
 
 impl From<Pick> for Vec<PickNote> {
@@ -0,0 +1,269 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use notation_core::prelude::ParseTextError;
+
+/// How the note is sounded from the previous one on the same string, without picking again.
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum PickLink {
+    HammerOn,
+    PullOff,
+    Slide,
+    /// Hammered with a finger of the picking hand.
+    Tap,
+}
+impl Display for PickLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl PickLink {
+    pub const ALL: [PickLink; 4] = [
+        PickLink::HammerOn,
+        PickLink::PullOff,
+        PickLink::Slide,
+        PickLink::Tap,
+    ];
+    pub fn to_ident(&self) -> String {
+        match self {
+            PickLink::HammerOn => "h",
+            PickLink::PullOff => "p",
+            PickLink::Slide => "s",
+            PickLink::Tap => "t",
+        }
+        .to_owned()
+    }
+    pub fn from_ident(ident: &str) -> Option<Self> {
+        Self::ALL.iter().find(|x| x.to_ident() == ident).copied()
+    }
+    /// Mark drawn before the fret, e.g. `/` for slides.
+    pub fn to_symbol(&self) -> String {
+        match self {
+            PickLink::Slide => "/".to_owned(),
+            _ => self.to_ident(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum Harmonic {
+    /// Touching the open string over a fret.
+    Natural,
+    /// Touching the fretted string 12 frets higher.
+    Artificial,
+}
+impl Display for Harmonic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl Harmonic {
+    pub const ALL: [Harmonic; 2] = [Harmonic::Natural, Harmonic::Artificial];
+    pub fn to_ident(&self) -> String {
+        match self {
+            Harmonic::Natural => "nh",
+            Harmonic::Artificial => "ah",
+        }
+        .to_owned()
+    }
+    pub fn from_ident(ident: &str) -> Option<Self> {
+        Self::ALL.iter().find(|x| x.to_ident() == ident).copied()
+    }
+    /// Sounding pitch above the open string, frets without a clear natural harmonic
+    /// are sounding as fretted.
+    pub fn calc_semitones(&self, fret: u8) -> u8 {
+        match self {
+            Harmonic::Natural => match fret {
+                12 => 12,
+                7 | 19 => 19,
+                5 | 24 => 24,
+                4 | 9 | 16 => 28,
+                3 => 31,
+                _ => fret,
+            },
+            Harmonic::Artificial => fret + 12,
+        }
+    }
+}
+
+/// Bending the string up from the fretted note.
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct Bend {
+    /// Target pitch above the fretted note, 2 for a full bend.
+    pub semitones: u8,
+    /// Bending back to the fretted note before the end.
+    pub release: bool,
+}
+impl Display for Bend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_ident())
+    }
+}
+impl Bend {
+    pub const MAX_SEMITONES: u8 = 4;
+    pub fn to_ident(&self) -> String {
+        format!("b{}{}", self.semitones, if self.release { "r" } else { "" })
+    }
+    /// `b` for a full bend, `b1` for a half one, `b2r` to release after the bend.
+    pub fn from_ident(ident: &str) -> Option<Self> {
+        let text = ident.strip_prefix('b')?;
+        let (text, release) = match text.strip_suffix('r') {
+            Some(text) => (text, true),
+            None => (text, false),
+        };
+        let semitones = if text.is_empty() {
+            2
+        } else {
+            text.parse::<u8>().ok()?
+        };
+        if semitones == 0 || semitones > Self::MAX_SEMITONES {
+            return None;
+        }
+        Some(Self { semitones, release })
+    }
+}
+
+/// Techniques of a pick note, written after the fret in tabs, e.g. `5@7 h v`.
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub struct PickTechniques {
+    pub link: Option<PickLink>,
+    pub bend: Option<Bend>,
+    pub harmonic: Option<Harmonic>,
+    pub vibrato: bool,
+    pub palm_mute: bool,
+    /// Muted by the fretting hand, only the percussive sound.
+    pub dead: bool,
+    pub let_ring: bool,
+}
+impl PickTechniques {
+    pub const VIBRATO_IDENT: &'static str = "v";
+    pub const PALM_MUTE_IDENT: &'static str = "pm";
+    pub const DEAD_IDENT: &'static str = "x";
+    pub const LET_RING_IDENT: &'static str = "lr";
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+    pub fn has_pitch_bend(&self) -> bool {
+        self.bend.is_some() || self.vibrato || self.link == Some(PickLink::Slide)
+    }
+    pub fn to_idents(&self) -> Vec<String> {
+        let mut idents = vec![];
+        idents.extend(self.link.map(|x| x.to_ident()));
+        idents.extend(self.bend.map(|x| x.to_ident()));
+        idents.extend(self.harmonic.map(|x| x.to_ident()));
+        for (on, ident) in [
+            (self.vibrato, Self::VIBRATO_IDENT),
+            (self.palm_mute, Self::PALM_MUTE_IDENT),
+            (self.dead, Self::DEAD_IDENT),
+            (self.let_ring, Self::LET_RING_IDENT),
+        ] {
+            if on {
+                idents.push(ident.to_owned());
+            }
+        }
+        idents
+    }
+    /// Adds the technique, returns None if the ident is unknown or already set.
+    pub fn with_ident(&self, ident: &str) -> Option<Self> {
+        let mut result = *self;
+        if let Some(link) = PickLink::from_ident(ident) {
+            if self.link.is_some() {
+                return None;
+            }
+            result.link = Some(link);
+        } else if let Some(harmonic) = Harmonic::from_ident(ident) {
+            if self.harmonic.is_some() {
+                return None;
+            }
+            result.harmonic = Some(harmonic);
+        } else if let Some(bend) = Bend::from_ident(ident) {
+            if self.bend.is_some() {
+                return None;
+            }
+            result.bend = Some(bend);
+        } else {
+            let flag = match ident {
+                Self::VIBRATO_IDENT => &mut result.vibrato,
+                Self::PALM_MUTE_IDENT => &mut result.palm_mute,
+                Self::DEAD_IDENT => &mut result.dead,
+                Self::LET_RING_IDENT => &mut result.let_ring,
+                _ => return None,
+            };
+            *flag = true;
+        }
+        (result != *self).then_some(result)
+    }
+    /// Fret in tab style, `x` for dead notes, `<12>` for natural harmonics.
+    pub fn to_fret_text(&self, fret: u8) -> String {
+        if self.dead {
+            "x".to_owned()
+        } else if self.harmonic == Some(Harmonic::Natural) {
+            format!("<{}>", fret)
+        } else {
+            format!("{}", fret)
+        }
+    }
+    /// Marks drawn with the fret, e.g. `h ~` for hammer-on with vibrato.
+    pub fn to_marks_text(&self) -> Option<String> {
+        let mut marks = vec![];
+        marks.extend(self.link.map(|x| x.to_symbol()));
+        marks.extend(self.bend.map(|x| x.to_ident()));
+        if self.harmonic == Some(Harmonic::Artificial) {
+            marks.push("AH".to_owned());
+        }
+        for (on, mark) in [
+            (self.vibrato, "~"),
+            (self.palm_mute, "PM"),
+            (self.let_ring, "LR"),
+        ] {
+            if on {
+                marks.push(mark.to_owned());
+            }
+        }
+        (!marks.is_empty()).then(|| marks.join(" "))
+    }
+    /// Scale of the velocity, legato notes are not picked again.
+    pub fn velocity_factor(&self) -> f32 {
+        let mut factor = match self.link {
+            Some(PickLink::Tap) => 0.9,
+            Some(_) => 0.7,
+            None => 1.0,
+        };
+        if self.palm_mute {
+            factor *= 0.8;
+        }
+        if self.dead {
+            factor *= 0.5;
+        }
+        factor
+    }
+    /// Scale of how long the note is sounding, against the written duration.
+    pub fn length_factor(&self) -> f32 {
+        if self.dead {
+            0.1
+        } else if self.palm_mute {
+            0.35
+        } else if self.let_ring {
+            2.0
+        } else {
+            1.0
+        }
+    }
+}
+impl Display for PickTechniques {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_idents().join(" "))
+    }
+}
+impl FromStr for PickTechniques {
+    type Err = ParseTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split_whitespace()
+            .try_fold(Self::default(), |result, ident| result.with_ident(ident))
+            .ok_or_else(|| ParseTextError::new("PickTechniques", s))
+    }
+}
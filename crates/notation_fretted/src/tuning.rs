@@ -0,0 +1,306 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use notation_core::prelude::{InlineVec, Note, Octave, ParseTextError, Pitch, Semitones};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::Fretboard;
+
+pub type TuningNotes = InlineVec<(Pitch, Octave), { Fretboard::MAX_STRINGS }>;
+
+// https://en.wikipedia.org/wiki/List_of_guitar_tunings
+/// Open pitches of the strings from the highest one, either a named preset or explicit ones.
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub enum StringTuning {
+    #[default]
+    Standard,
+    StandardFlat,
+    DropD,
+    DropC,
+    Dadgad,
+    OpenG,
+    OpenD,
+    OpenE,
+    OpenC,
+    /// Standard with the 4 lower strings an octave higher, from the high strings of a 12-string.
+    Nashville,
+    Bass,
+    Bass5,
+    Standard7,
+    Standard8,
+    /// Courses of 2 strings, the octave one first in the lower 4 courses.
+    Standard12,
+    /// 5-string banjo in open G, the short drone string is the 5th.
+    Banjo,
+    Custom(TuningNotes),
+}
+impl Display for StringTuning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_ident())
+    }
+}
+
+impl StringTuning {
+    pub const PRESETS: [StringTuning; 16] = [
+        Self::Standard,
+        Self::StandardFlat,
+        Self::DropD,
+        Self::DropC,
+        Self::Dadgad,
+        Self::OpenG,
+        Self::OpenD,
+        Self::OpenE,
+        Self::OpenC,
+        Self::Nashville,
+        Self::Bass,
+        Self::Bass5,
+        Self::Standard7,
+        Self::Standard8,
+        Self::Standard12,
+        Self::Banjo,
+    ];
+    const STANDARD: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::E, Octave::P4),
+        (Pitch::B, Octave::P3),
+        (Pitch::G, Octave::P3),
+        (Pitch::D, Octave::P3),
+        (Pitch::A, Octave::P2),
+        (Pitch::E, Octave::P2),
+    ]);
+    const STANDARD_FLAT: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::E_FLAT, Octave::P4),
+        (Pitch::B_FLAT, Octave::P3),
+        (Pitch::G_FLAT, Octave::P3),
+        (Pitch::D_FLAT, Octave::P3),
+        (Pitch::A_FLAT, Octave::P2),
+        (Pitch::E_FLAT, Octave::P2),
+    ]);
+    const DROP_D: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::E, Octave::P4),
+        (Pitch::B, Octave::P3),
+        (Pitch::G, Octave::P3),
+        (Pitch::D, Octave::P3),
+        (Pitch::A, Octave::P2),
+        (Pitch::D, Octave::P2),
+    ]);
+    const DROP_C: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::D, Octave::P4),
+        (Pitch::A, Octave::P3),
+        (Pitch::F, Octave::P3),
+        (Pitch::C, Octave::P3),
+        (Pitch::G, Octave::P2),
+        (Pitch::C, Octave::P2),
+    ]);
+    const DADGAD: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::D, Octave::P4),
+        (Pitch::A, Octave::P3),
+        (Pitch::G, Octave::P3),
+        (Pitch::D, Octave::P3),
+        (Pitch::A, Octave::P2),
+        (Pitch::D, Octave::P2),
+    ]);
+    const OPEN_G: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::D, Octave::P4),
+        (Pitch::B, Octave::P3),
+        (Pitch::G, Octave::P3),
+        (Pitch::D, Octave::P3),
+        (Pitch::G, Octave::P2),
+        (Pitch::D, Octave::P2),
+    ]);
+    const OPEN_D: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::D, Octave::P4),
+        (Pitch::A, Octave::P3),
+        (Pitch::F_SHARP, Octave::P3),
+        (Pitch::D, Octave::P3),
+        (Pitch::A, Octave::P2),
+        (Pitch::D, Octave::P2),
+    ]);
+    const OPEN_E: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::E, Octave::P4),
+        (Pitch::B, Octave::P3),
+        (Pitch::G_SHARP, Octave::P3),
+        (Pitch::E, Octave::P3),
+        (Pitch::B, Octave::P2),
+        (Pitch::E, Octave::P2),
+    ]);
+    const OPEN_C: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::E, Octave::P4),
+        (Pitch::C, Octave::P4),
+        (Pitch::G, Octave::P3),
+        (Pitch::C, Octave::P3),
+        (Pitch::G, Octave::P2),
+        (Pitch::C, Octave::P2),
+    ]);
+    const NASHVILLE: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::E, Octave::P4),
+        (Pitch::B, Octave::P3),
+        (Pitch::G, Octave::P4),
+        (Pitch::D, Octave::P4),
+        (Pitch::A, Octave::P3),
+        (Pitch::E, Octave::P3),
+    ]);
+    const BASS: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::G, Octave::P2),
+        (Pitch::D, Octave::P2),
+        (Pitch::A, Octave::P1),
+        (Pitch::E, Octave::P1),
+    ]);
+    const BASS5: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::G, Octave::P2),
+        (Pitch::D, Octave::P2),
+        (Pitch::A, Octave::P1),
+        (Pitch::E, Octave::P1),
+        (Pitch::B, Octave::P0),
+    ]);
+    const STANDARD7: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::E, Octave::P4),
+        (Pitch::B, Octave::P3),
+        (Pitch::G, Octave::P3),
+        (Pitch::D, Octave::P3),
+        (Pitch::A, Octave::P2),
+        (Pitch::E, Octave::P2),
+        (Pitch::B, Octave::P1),
+    ]);
+    const STANDARD8: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::E, Octave::P4),
+        (Pitch::B, Octave::P3),
+        (Pitch::G, Octave::P3),
+        (Pitch::D, Octave::P3),
+        (Pitch::A, Octave::P2),
+        (Pitch::E, Octave::P2),
+        (Pitch::B, Octave::P1),
+        (Pitch::F_SHARP, Octave::P1),
+    ]);
+    const STANDARD12: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::E, Octave::P4),
+        (Pitch::E, Octave::P4),
+        (Pitch::B, Octave::P3),
+        (Pitch::B, Octave::P3),
+        (Pitch::G, Octave::P4),
+        (Pitch::G, Octave::P3),
+        (Pitch::D, Octave::P4),
+        (Pitch::D, Octave::P3),
+        (Pitch::A, Octave::P3),
+        (Pitch::A, Octave::P2),
+        (Pitch::E, Octave::P3),
+        (Pitch::E, Octave::P2),
+    ]);
+    const BANJO: TuningNotes = TuningNotes::from_slice(&[
+        (Pitch::D, Octave::P4),
+        (Pitch::B, Octave::P3),
+        (Pitch::G, Octave::P3),
+        (Pitch::D, Octave::P3),
+        (Pitch::G, Octave::P4),
+    ]);
+
+    /// Common tuning for the string count, None if there is no preset for it.
+    pub fn default_of(string_num: usize) -> Option<Self> {
+        match string_num {
+            4 => Some(Self::Bass),
+            5 => Some(Self::Bass5),
+            6 => Some(Self::Standard),
+            7 => Some(Self::Standard7),
+            8 => Some(Self::Standard8),
+            12 => Some(Self::Standard12),
+            _ => None,
+        }
+    }
+    pub fn notes(&self) -> TuningNotes {
+        match self {
+            Self::Standard => Self::STANDARD,
+            Self::StandardFlat => Self::STANDARD_FLAT,
+            Self::DropD => Self::DROP_D,
+            Self::DropC => Self::DROP_C,
+            Self::Dadgad => Self::DADGAD,
+            Self::OpenG => Self::OPEN_G,
+            Self::OpenD => Self::OPEN_D,
+            Self::OpenE => Self::OPEN_E,
+            Self::OpenC => Self::OPEN_C,
+            Self::Nashville => Self::NASHVILLE,
+            Self::Bass => Self::BASS,
+            Self::Bass5 => Self::BASS5,
+            Self::Standard7 => Self::STANDARD7,
+            Self::Standard8 => Self::STANDARD8,
+            Self::Standard12 => Self::STANDARD12,
+            Self::Banjo => Self::BANJO,
+            Self::Custom(notes) => *notes,
+        }
+    }
+    pub fn string_num(&self) -> usize {
+        self.notes().len()
+    }
+    /// string is 1-based.
+    pub fn string_note(&self, string: u8) -> Option<Semitones> {
+        if string == 0 {
+            None
+        } else {
+            self.notes().get((string - 1) as usize).map(Semitones::from)
+        }
+    }
+    /// Strings in each course, which are fretted and played together, e.g. 2 for 12-string.
+    pub fn course_size(&self) -> usize {
+        match self {
+            Self::Standard12 => 2,
+            _ => 1,
+        }
+    }
+    /// Fret where the string starts, e.g. 5 for the short drone string of banjos,
+    /// frets on it are numbered as the ones of the neck.
+    pub fn string_nut_fret(&self, string: u8) -> u8 {
+        match (self, string) {
+            (Self::Banjo, 5) => 5,
+            _ => 0,
+        }
+    }
+    /// Semitones above the open string, None for the frets under the nut of the string.
+    pub fn fret_semitones(&self, string: u8, fret: u8) -> Option<u8> {
+        let nut = self.string_nut_fret(string);
+        if fret == 0 {
+            Some(0)
+        } else if fret <= nut {
+            None
+        } else {
+            Some(fret - nut)
+        }
+    }
+    pub fn string_notes(&self) -> Vec<Semitones> {
+        self.notes().iter().map(Semitones::from).collect()
+    }
+    /// Preset name, or the pitches with octaves for custom ones, e.g. "D4 A3 G3 D3 A2 D2".
+    pub fn to_ident(&self) -> String {
+        match self {
+            Self::Dadgad => "DADGAD".to_owned(),
+            Self::Custom(notes) => notes
+                .iter()
+                .map(|(pitch, octave)| format!("{}{}", pitch, octave.to_number()))
+                .collect::<Vec<String>>()
+                .join(" "),
+            _ => format!("{:?}", self),
+        }
+    }
+}
+
+impl FromStr for StringTuning {
+    type Err = ParseTextError;
+
+    /// Preset names are case insensitive, otherwise pitches with octaves from the highest string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+        if let Some(preset) = Self::PRESETS
+            .iter()
+            .find(|x| x.to_ident().eq_ignore_ascii_case(text))
+        {
+            return Ok(*preset);
+        }
+        let err = || ParseTextError::new("StringTuning", s);
+        let notes = text
+            .split_whitespace()
+            .map(|x| x.parse::<Note>().map(|n| (n.pitch, n.octave)))
+            .collect::<Result<Vec<(Pitch, Octave)>, ParseTextError>>()
+            .map_err(|_| err())?;
+        if notes.is_empty() || notes.len() > Fretboard::MAX_STRINGS {
+            return Err(err());
+        }
+        Ok(Self::Custom(TuningNotes::from_slice(&notes)))
+    }
+}
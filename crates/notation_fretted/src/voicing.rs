@@ -0,0 +1,435 @@
+use std::fmt::Display;
+
+use notation_core::prelude::{Chord, Key, Scale, Semitones};
+
+use crate::prelude::{Finger, Fretboard, HandShape};
+
+/// Limits for the voicing search, frets are counted from the capo.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct VoicingConstraints {
+    /// Largest distance between the lowest and highest fretted notes.
+    pub max_span: u8,
+    pub allow_barre: bool,
+    /// Muted strings between the played ones, which need careful strumming.
+    pub allow_inner_mute: bool,
+    /// Lowest played note has to be the bass of the chord, or the root if no bass.
+    pub require_bass: bool,
+    /// Position window for the fretted notes, open strings are always allowed.
+    pub min_fret: u8,
+    pub max_fret: u8,
+}
+impl Default for VoicingConstraints {
+    fn default() -> Self {
+        Self {
+            max_span: 3,
+            allow_barre: true,
+            allow_inner_mute: false,
+            require_bass: true,
+            min_fret: 0,
+            max_fret: 12,
+        }
+    }
+}
+
+/// A playable shape for a chord, lower difficulty is easier to play.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Voicing {
+    pub shape: HandShape,
+    pub difficulty: u32,
+}
+impl Display for Voicing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.to_shape_text(), self.difficulty)
+    }
+}
+impl Voicing {
+    /// Same as in the tab files, from the lowest string, e.g. `Shape ( _ 3 2 0 1 0 )`.
+    pub fn to_shape_text(&self) -> String {
+        let mut text = "Shape (".to_owned();
+        for fret in self.shape.frets.to_vec().into_iter().rev() {
+            match fret {
+                Some(fret) => text.push_str(&format!(" {}", fret)),
+                None => text.push_str(" _"),
+            }
+        }
+        text.push_str(" )");
+        if let Some(barre) = self.shape.barre {
+            text.push_str(&format!(" + {}", barre));
+        }
+        text
+    }
+    /// Fingers from the lowest string, `-` for open or muted ones, e.g. `- R M - I -`.
+    pub fn to_fingers_text(&self) -> String {
+        self.shape
+            .fingers
+            .to_vec()
+            .into_iter()
+            .rev()
+            .map(|x| x.map(|finger| finger.to_string()).unwrap_or("-".to_owned()))
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+// Pitch classes of the chord, relative to C.
+struct ChordTones {
+    root: i32,
+    bass: i32,
+    tones: Vec<i32>,
+    fifth: Option<i32>,
+}
+impl ChordTones {
+    fn new(scale: &Scale, key: &Key, chord: &Chord) -> Self {
+        let root = (scale.calc_do_semitones(key) + Semitones::from(chord.root)).0 as i32;
+        let intervals = chord.intervals.get_intervals();
+        let mut tones = vec![root.rem_euclid(12)];
+        let mut fifth = None;
+        for interval in intervals.iter() {
+            let semitones = Semitones::from(*interval).0 as i32;
+            let tone = (root + semitones).rem_euclid(12);
+            // Fifth is usually left out first when there are not enough strings.
+            if semitones.rem_euclid(12) == 7 && intervals.len() > 2 {
+                fifth = Some(tone);
+            }
+            tones.push(tone);
+        }
+        let bass = match chord.bass {
+            Some(bass) => (root + Semitones::from(bass).0 as i32).rem_euclid(12),
+            None => root.rem_euclid(12),
+        };
+        if !tones.contains(&bass) {
+            tones.push(bass);
+        }
+        Self {
+            root: root.rem_euclid(12),
+            bass,
+            tones,
+            fifth,
+        }
+    }
+    fn missing_tones(&self, played: &[i32]) -> Option<usize> {
+        let mut missing = 0;
+        for tone in self.tones.iter() {
+            if !played.contains(tone) {
+                if Some(*tone) != self.fifth {
+                    return None;
+                }
+                missing += 1;
+            }
+        }
+        Some(missing)
+    }
+}
+
+// Courses are searched from the lowest one, frets are indexed from the one of string 1,
+// each course is a single string except on 12-string guitars.
+struct VoicingSearch<'a> {
+    fretboard: &'a Fretboard,
+    constraints: &'a VoicingConstraints,
+    course_size: usize,
+    chord: ChordTones,
+    frets: Vec<Option<u8>>,
+    voicings: Vec<Voicing>,
+}
+impl<'a> VoicingSearch<'a> {
+    const MAX_FINGERS: usize = 4;
+
+    // Strings of a course are in unison or octaves, so the first one is enough.
+    fn pitch_class(&self, course: usize, fret: u8) -> Option<i32> {
+        let string = (course * self.course_size) as u8 + 1;
+        let semitones = self.fretboard.tuning.fret_semitones(string, fret)?;
+        self.fretboard
+            .string_note(string)
+            .map(|x| (x.0 as i32 + self.fretboard.capo as i32 + semitones as i32).rem_euclid(12))
+    }
+    fn fret_options(&self, course: usize) -> Vec<Option<u8>> {
+        let max_fret = (self.constraints.max_fret as usize)
+            .min(self.fretboard.fret_num().saturating_sub(1)) as u8;
+        let min_fret = self.constraints.min_fret.max(1);
+        let mut options = vec![None, Some(0)];
+        if min_fret <= max_fret {
+            options.extend((min_fret..=max_fret).map(Some));
+        }
+        options
+            .into_iter()
+            .filter(|x| match x {
+                Some(fret) => self
+                    .pitch_class(course, *fret)
+                    .map(|tone| self.chord.tones.contains(&tone))
+                    .unwrap_or(false),
+                None => true,
+            })
+            .collect()
+    }
+    fn fretted(&self) -> Vec<u8> {
+        self.frets
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|x| *x > 0)
+            .collect()
+    }
+    fn is_possible(&self) -> bool {
+        let fretted = self.fretted();
+        if let (Some(min), Some(max)) = (fretted.iter().min(), fretted.iter().max()) {
+            if max - min > self.constraints.max_span {
+                return false;
+            }
+        }
+        if fretted.len() > Self::MAX_FINGERS && !self.constraints.allow_barre {
+            return false;
+        }
+        // Once a string is muted after the played ones, all higher ones stay muted.
+        if !self.constraints.allow_inner_mute {
+            let mut played = false;
+            let mut muted = false;
+            for fret in self.frets.iter().rev() {
+                match fret {
+                    Some(_) if muted => return false,
+                    Some(_) => played = true,
+                    None => muted = played,
+                }
+            }
+        }
+        true
+    }
+    fn search(&mut self, course: usize) {
+        for fret in self.fret_options(course) {
+            self.frets[course] = fret;
+            if let Some(fret) = fret {
+                let is_lowest = self.frets[course + 1..].iter().all(|x| x.is_none());
+                if self.constraints.require_bass
+                    && is_lowest
+                    && self.pitch_class(course, fret) != Some(self.chord.bass)
+                {
+                    continue;
+                }
+            }
+            if !self.is_possible() {
+                continue;
+            }
+            if course == 0 {
+                self.check_voicing();
+            } else {
+                self.search(course - 1);
+            }
+        }
+        self.frets[course] = None;
+    }
+    fn check_voicing(&mut self) {
+        let played: Vec<(usize, u8)> = self
+            .frets
+            .iter()
+            .enumerate()
+            .filter_map(|(course, fret)| fret.map(|x| (course, x)))
+            .collect();
+        if played.len() < 3.min(self.frets.len()) {
+            return;
+        }
+        let tones: Vec<i32> = played
+            .iter()
+            .filter_map(|(course, fret)| self.pitch_class(*course, *fret))
+            .collect();
+        let missing = match self.chord.missing_tones(&tones) {
+            Some(missing) => missing,
+            None => return,
+        };
+        let shape = match Self::assign_fingers(&self.frets, self.constraints.allow_barre) {
+            Some(shape) => shape,
+            None => return,
+        };
+        let bass = tones.last().copied();
+        let difficulty = self.calc_difficulty(&shape, missing, bass);
+        let shape = match self.expand_courses(&shape) {
+            Some(shape) => shape,
+            None => return,
+        };
+        self.voicings.push(Voicing { shape, difficulty });
+    }
+    fn expand_courses(&self, shape: &HandShape) -> Option<HandShape> {
+        if self.course_size == 1 {
+            return Some(*shape);
+        }
+        HandShape::new_barre(
+            shape.barre(),
+            &Self::repeat_items(&shape.frets.to_vec(), self.course_size),
+            &Self::repeat_items(&shape.fingers.to_vec(), self.course_size),
+        )
+        .ok()
+    }
+    fn repeat_items<T: Copy>(items: &[T], count: usize) -> Vec<T> {
+        items
+            .iter()
+            .flat_map(|x| std::iter::repeat_n(*x, count))
+            .collect()
+    }
+    fn calc_difficulty(&self, shape: &HandShape, missing: usize, bass: Option<i32>) -> u32 {
+        let fretted = self.fretted();
+        let min = fretted.iter().min().copied().unwrap_or(0) as u32;
+        let max = fretted.iter().max().copied().unwrap_or(0) as u32;
+        let first = self.frets.iter().position(|x| x.is_some()).unwrap_or(0);
+        let last = self.frets.iter().rposition(|x| x.is_some()).unwrap_or(0);
+        let muted = self.frets.iter().filter(|x| x.is_none()).count() as u32;
+        let inner_muted = self.frets[first..=last]
+            .iter()
+            .filter(|x| x.is_none())
+            .count() as u32;
+        let mut difficulty = (max - min) * 2 + fretted.len() as u32 + min / 2;
+        difficulty += muted * 2 + inner_muted * 3 + missing as u32;
+        if shape.barre.is_some() {
+            difficulty += 4;
+        }
+        if bass != Some(self.chord.bass) {
+            difficulty += if bass == Some(self.chord.root) { 2 } else { 3 };
+        }
+        difficulty
+    }
+    /// Shape of the frets, with a barre on the lowest fret if there are more notes than
+    /// fingers, returns None if the fingers are not enough.
+    fn assign_fingers(frets: &[Option<u8>], allow_barre: bool) -> Option<HandShape> {
+        let fretted: Vec<(usize, u8)> = frets
+            .iter()
+            .enumerate()
+            .filter_map(|(string, fret)| fret.filter(|x| *x > 0).map(|x| (string, x)))
+            .collect();
+        if fretted.len() <= Finger::FRETTING.len() {
+            return HandShape::new(frets, &[])
+                .ok()
+                .map(|x| x.with_auto_fingers());
+        }
+        if !allow_barre {
+            return None;
+        }
+        let barre = fretted.iter().map(|x| x.1).min()?;
+        let lowest = fretted
+            .iter()
+            .filter(|(_, fret)| *fret == barre)
+            .map(|(string, _)| *string)
+            .max()?;
+        // Open strings can't be played with a barre, neither can muted ones under it.
+        if frets.contains(&Some(0)) || frets[..=lowest].iter().any(|x| x.is_none()) {
+            return None;
+        }
+        if fretted.iter().filter(|(_, fret)| *fret > barre).count() >= Finger::FRETTING.len() {
+            return None;
+        }
+        let frets: Vec<Option<u8>> = frets.iter().map(|x| x.map(|fret| fret - barre)).collect();
+        HandShape::new_barre(barre, &frets, &[])
+            .ok()
+            .map(|x| x.with_auto_fingers())
+    }
+}
+
+impl Fretboard {
+    /// Playable shapes for the chord, sorted from the easiest one.
+    pub fn calc_voicings(
+        &self,
+        scale: &Scale,
+        key: &Key,
+        chord: &Chord,
+        constraints: &VoicingConstraints,
+    ) -> Vec<Voicing> {
+        let course_size = self.tuning.course_size();
+        let course_num = self.string_num() / course_size;
+        if course_num == 0 {
+            return vec![];
+        }
+        let mut search = VoicingSearch {
+            fretboard: self,
+            constraints,
+            course_size,
+            chord: ChordTones::new(scale, key, chord),
+            frets: vec![None; course_num],
+            voicings: vec![],
+        };
+        search.search(course_num - 1);
+        let mut voicings = search.voicings;
+        voicings.sort_by_key(|x| (x.difficulty, x.shape.barre, x.shape.frets.to_vec()));
+        voicings
+    }
+}
+
+#[cfg(test)]
+fn test_voicings(
+    tuning: crate::prelude::StringTuning,
+    symbol: &str,
+    constraints: &VoicingConstraints,
+) -> Vec<Voicing> {
+    let (key, scale) = (Key::C, Scale::Major);
+    let chord = symbol
+        .parse::<notation_core::prelude::ChordSymbol>()
+        .unwrap()
+        .to_chord(&key, &scale);
+    Fretboard::new(22, tuning, 0).calc_voicings(&scale, &key, &chord, constraints)
+}
+
+#[test]
+fn test_open_chords_first() {
+    for (symbol, text) in [
+        ("C", "Shape ( _ 3 2 0 1 0 )"),
+        ("G", "Shape ( 3 2 0 0 0 3 )"),
+        ("Am", "Shape ( _ 0 2 2 1 0 )"),
+        ("E7", "Shape ( 0 2 0 1 0 0 )"),
+    ] {
+        let voicings = test_voicings(Default::default(), symbol, &VoicingConstraints::default());
+        assert_eq!(voicings[0].to_shape_text(), text, "{}", symbol);
+    }
+}
+
+#[test]
+fn test_stretch_limits() {
+    let constraints = VoicingConstraints {
+        max_span: 2,
+        min_fret: 5,
+        max_fret: 10,
+        ..Default::default()
+    };
+    let voicings = test_voicings(Default::default(), "F", &constraints);
+    assert!(!voicings.is_empty());
+    for voicing in voicings.iter() {
+        let fretted: Vec<u8> = (1..=6)
+            .filter_map(|string| voicing.shape.string_fret_with_barre(string))
+            .filter(|x| *x > 0)
+            .collect();
+        let (min, max) = (fretted.iter().min().unwrap(), fretted.iter().max().unwrap());
+        assert!(max - min <= 2, "{}", voicing);
+        assert!(*min >= 5 && *max <= 10, "{}", voicing);
+    }
+}
+
+#[test]
+fn test_muted_strings() {
+    let voicings = test_voicings(Default::default(), "C", &VoicingConstraints::default());
+    for voicing in voicings.iter() {
+        let frets = voicing.shape.frets.to_vec();
+        let first = frets.iter().position(|x| x.is_some()).unwrap();
+        let last = frets.iter().rposition(|x| x.is_some()).unwrap();
+        assert!(
+            frets[first..=last].iter().all(|x| x.is_some()),
+            "{}",
+            voicing
+        );
+    }
+    let constraints = VoicingConstraints {
+        allow_inner_mute: true,
+        ..Default::default()
+    };
+    let voicings = test_voicings(Default::default(), "C", &constraints);
+    assert!(voicings
+        .iter()
+        .any(|x| x.to_shape_text() == "Shape ( _ 3 _ 0 1 0 )"));
+}
+
+#[test]
+fn test_courses_fretted_together() {
+    let tuning = crate::prelude::StringTuning::Standard12;
+    let voicings = test_voicings(tuning, "C", &VoicingConstraints::default());
+    assert_eq!(
+        voicings[0].to_shape_text(),
+        "Shape ( _ _ 3 3 2 2 0 0 1 1 0 0 )"
+    );
+    for voicing in voicings.iter() {
+        let frets = voicing.shape.frets.to_vec();
+        assert!(frets.chunks(2).all(|x| x[0] == x[1]), "{}", voicing);
+    }
+}
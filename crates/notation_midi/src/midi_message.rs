@@ -1,6 +1,8 @@
 use helgoboss_midi::{ShortMessage, StructuredShortMessage};
 use notation_model::prelude::*;
 
+use crate::prelude::MidiUtil;
+
 #[derive(Clone, Debug)]
 pub struct MidiMessage {
     pub pass_mode: EntryPassMode,
@@ -8,9 +10,10 @@ pub struct MidiMessage {
     pub duration: Units,
     pub delay: bool,
     pub midi: StructuredShortMessage,
+    /// Scale of the channel velocity, from dynamics and articulations.
+    pub velocity_factor: f32,
 }
 impl MidiMessage {
-    pub const DELAY_GAP: Units = Units(Units::_MIN_ACCURACY * 2.0);
     pub fn new(
         pass_mode: EntryPassMode,
         pos: BarPosition,
@@ -24,23 +27,28 @@ impl MidiMessage {
             duration,
             delay,
             midi,
+            velocity_factor: 1.0,
         }
     }
     pub fn of_entry(entry: &LaneEntry, delay: bool, midi: StructuredShortMessage) -> Self {
         Self {
             pass_mode: entry.pass_mode(),
             pos: entry.bar_position(),
-            duration: entry.tied_units(),
+            duration: MidiUtil::calc_entry_units(entry),
             delay,
             midi,
+            velocity_factor: entry.calc_velocity_factor(),
         }
     }
+    pub fn calc_velocity(&self, velocity: u8) -> u8 {
+        MidiUtil::scale_velocity(velocity, self.velocity_factor)
+    }
     pub fn bar_ordinal(&self) -> usize {
         self.pos.bar_ordinal
     }
     pub fn effect_position(&self) -> BarPosition {
         if self.delay {
-            self.pos.with_delay(self.duration - Self::DELAY_GAP)
+            self.pos.with_delay(self.duration)
         } else {
             self.pos
         }
@@ -48,11 +56,16 @@ impl MidiMessage {
     pub fn effect_units(&self) -> Units {
         self.effect_position().into()
     }
+    /// Raw bytes for external outputs, with the velocity factor applied to note on.
     pub fn to_midi(&self) -> [u8; 3] {
+        let data_byte_2 = match &self.midi {
+            StructuredShortMessage::NoteOn { velocity, .. } => self.calc_velocity((*velocity).into()),
+            _ => self.midi.data_byte_2().into(),
+        };
         [
             self.midi.status_byte(),
             self.midi.data_byte_1().into(),
-            self.midi.data_byte_2().into(),
+            data_byte_2,
         ]
     }
     pub fn should_send_in_seeking(&self) -> bool {
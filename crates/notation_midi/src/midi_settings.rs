@@ -14,11 +14,14 @@ pub struct MidiSettings {
     pub guitar_velocity: u8,
     pub piano_mute: bool,
     pub piano_velocity: u8,
+    pub chord_mute: bool,
+    pub chord_velocity: u8,
     pub use_internal_synth: bool,
     pub click_sound: u8,
     pub vocal_sound: u8,
     pub guitar_sound: u8,
     pub piano_sound: u8,
+    pub chord_sound: u8,
     pub seeking_track: TrackKind,
     pub seeking_init_channel: bool,
 }
@@ -36,11 +39,14 @@ impl Default for MidiSettings {
             guitar_velocity: 120,
             piano_mute: false,
             piano_velocity: 110,
+            chord_mute: true,
+            chord_velocity: 80,
             use_internal_synth: Self::default_use_internal_synth(),
             click_sound: GMSoundSet::Dulcimer as u8,
             vocal_sound: GMSoundSet::Cello as u8,
             guitar_sound: GMSoundSet::AcousticGuitarSteel as u8,
             piano_sound: GMSoundSet::AcousticGrandPiano as u8,
+            chord_sound: GMSoundSet::AcousticGrandPiano as u8,
             seeking_track: TrackKind::Guitar,
             seeking_init_channel: true,
         }
@@ -61,6 +67,7 @@ impl MidiSettings {
             TrackKind::Vocal => Some((self.vocal_sound, self.vocal_velocity)),
             TrackKind::Guitar => Some((self.guitar_sound, self.guitar_velocity)),
             TrackKind::Piano => Some((self.piano_sound, self.piano_velocity)),
+            TrackKind::Chord => Some((self.chord_sound, self.chord_velocity)),
             _ => None,
         }
     }
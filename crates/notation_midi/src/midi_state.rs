@@ -50,9 +50,11 @@ impl MidiChannel {
     fn ensure_sorted(&mut self) -> bool {
         if self.need_sort {
             dmsort::sort_by(&mut self.messages, |a, b| {
+                // Delayed messages (note off) go first, so notes ending right before others
+                // are not cutting them.
                 let units_a = a.effect_units();
                 let units_b = b.effect_units();
-                units_a.cmp(&units_b)
+                units_a.cmp(&units_b).then_with(|| b.delay.cmp(&a.delay))
             });
             self.need_sort = false;
             true
@@ -129,6 +131,13 @@ impl MidiChannel {
                                 settings.piano_velocity
                             };
                         }
+                        TrackKind::Chord => {
+                            velocity = if !seeking && settings.chord_mute {
+                                0
+                            } else {
+                                settings.chord_velocity
+                            };
+                        }
                         _ => (),
                     }
                 }
@@ -153,7 +162,7 @@ impl MidiChannel {
                     count += 1;
                     if !bypass {
                         if !is_seeking || next.should_send_in_seeking() {
-                            hub.send(settings, speed, next, velocity);
+                            hub.send(settings, speed, next, next.calc_velocity(velocity));
                         }
                     }
                 } else {
@@ -204,6 +213,22 @@ impl MidiChannel {
                 ),
                 self.velocity.into(),
             );
+            let mut msgs = MidiUtil::pitch_bend_range_msgs(self.channel);
+            msgs.push(MidiUtil::pitch_bend_msg(self.channel, 0.0));
+            for msg in msgs {
+                hub.send(
+                    settings,
+                    speed,
+                    &MidiMessage::new(
+                        first_msg.pass_mode,
+                        first_msg.pos,
+                        first_msg.duration,
+                        false,
+                        msg,
+                    ),
+                    self.velocity.into(),
+                );
+            }
         }
     }
     pub fn setup(
@@ -305,9 +330,16 @@ impl MidiState {
             let signature = tab.signature();
             let bar_units = tab.bar_units();
             let beat_duration = Units::from(signature.beat_unit);
+            let group_starts: Vec<u8> = signature.get_group_starts().iter().map(|x| x.0).collect();
             for bar in tab.bars.iter() {
+                // Click on every beat, the first beat of each group is accented.
                 for beat in 0..signature.bar_beats {
-                    let in_bar_pos = Units(beat as f32 * Units::from(signature.beat_unit).0);
+                    let in_bar_pos = beat_duration * beat as usize;
+                    let velocity_factor = if group_starts.contains(&beat) {
+                        1.0
+                    } else {
+                        MidiUtil::CLICK_BEAT_FACTOR
+                    };
                     let root = bar
                         .get_chord(Some(in_bar_pos))
                         .map(|x| x.root)
@@ -321,13 +353,16 @@ impl MidiState {
                     if let Some(midi_msg) =
                         MidiUtil::note_midi_on_msg(&note, channel.channel, channel.velocity)
                     {
-                        channel.add_message(MidiMessage::new(
-                            EntryPassMode::Delayed,
-                            pos,
-                            beat_duration,
-                            false,
-                            midi_msg,
-                        ));
+                        channel.add_message(MidiMessage {
+                            velocity_factor,
+                            ..MidiMessage::new(
+                                EntryPassMode::Delayed,
+                                pos,
+                                beat_duration,
+                                false,
+                                midi_msg,
+                            )
+                        });
                     }
                     if let Some(midi_msg) =
                         MidiUtil::note_midi_off_msg(&note, channel.channel, channel.velocity)
@@ -368,9 +403,9 @@ impl MidiState {
             for ((_k, _i), lane) in bar.lanes.iter() {
                 if let Some(channel) = self.get_channel_mut(&lane.track.id, &lane.track.kind) {
                     for entry in lane.entries.iter() {
-                        if let Some(msgs) = MidiUtil::get_midi_msgs(channel, bar, &entry) {
+                        if let Some(msgs) = MidiUtil::get_midi_messages(channel, bar, &entry) {
                             for msg in msgs {
-                                channel.add_message(MidiMessage::of_entry(entry, msg.0, msg.1));
+                                channel.add_message(msg);
                             }
                         }
                     }
@@ -388,7 +423,7 @@ impl MidiState {
     ) {
         self.play_control
             .position
-            .set_in_bar(bar_props.bar_ordinal, Units(0.0));
+            .set_in_bar(bar_props.bar_ordinal, Units::ZERO);
         if self.play_control.is_bar_in_range(bar_props.bar_ordinal) {
             for channel in self.channels.iter_mut() {
                 channel.calc_next_index(&self.play_control.position.bar);
@@ -472,14 +507,14 @@ impl MidiState {
                     }
                 }) {
                     self.setup_seek(
-                        pos.with_in_bar_pos(props.in_bar_pos - Units::HALF_MIN_ACCURACY),
+                        pos.with_in_bar_pos(props.in_bar_pos - Units::TICK),
                     );
                     return true;
                 } else {
                     self.setup_seek(BarPosition::new(
                         pos.bar_units,
                         pos.bar_ordinal,
-                        pos.bar_units - Units::HALF_MIN_ACCURACY,
+                        pos.bar_units - Units::TICK,
                     ));
                     return true;
                 }
@@ -496,8 +531,8 @@ impl MidiState {
     fn seek_passed(&mut self, settings: &MidiSettings) -> bool {
         if self.tab.is_some() {
             let mut pos = self.play_control.position.bar;
-            if pos.in_bar_pos >= pos.bar_units - Units::MIN_ACCURACY {
-                pos = BarPosition::new(pos.bar_units, pos.bar_ordinal + 1, Units::MIN_ACCURACY);
+            if pos.in_bar_pos >= pos.bar_units - Units::TICK {
+                pos = BarPosition::new(pos.bar_units, pos.bar_ordinal + 1, Units::TICK);
                 if let Some(bar) = self.tab.as_ref().unwrap().get_bar(pos) {
                     if let Some(props) = bar.get_next_entry(pos.in_bar_pos, &|x| {
                         if x.track_kind() != settings.seeking_track {
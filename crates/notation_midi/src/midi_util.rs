@@ -1,21 +1,30 @@
 use std::convert::TryFrom;
 
-use helgoboss_midi::{Channel, KeyNumber, StructuredShortMessage, U7};
+use helgoboss_midi::{Channel, ControllerNumber, KeyNumber, StructuredShortMessage, U14, U7};
 use notation_model::prelude::{
-    CoreEntry, Entry, FrettedEntry4, FrettedEntry6, LaneEntry, Note, Pick, Semitones, TabBar, Tone,
+    Chord, CoreEntry, Entry, FrettedEntry, LaneEntry, Note, Octave, Pick, PickLink, PickTechniques,
+    ProtoEntry, TabBar, Tone, Units,
 };
 
-use crate::prelude::MidiChannel;
+use crate::prelude::{MidiChannel, MidiMessage};
 
 pub struct MidiUtil();
 
 impl MidiUtil {
+    /// Semitones of the full pitch bend, set to the channels with `pitch_bend_range_msgs()`.
+    pub const PITCH_BEND_RANGE: u8 = 12;
+    /// Units between the pitch bend changes of bends, slides and vibratos.
+    pub const PITCH_BEND_STEP: f32 = 1.0 / 64.0;
+    pub const MAX_PITCH_BEND_STEPS: usize = 64;
+    pub const VIBRATO_SEMITONES: f32 = 0.3;
+    pub const VIBRATO_PERIOD: f32 = 1.0 / 8.0;
+    /// Velocity factor of the clicks within a beat group, the group starts are accented.
+    pub const CLICK_BEAT_FACTOR: f32 = 0.6;
+
     pub fn note_midi_key_number(note: &Note) -> Option<KeyNumber> {
-        #[cfg(not(target_arch = "wasm32"))]
-        let midi_note = Semitones::from(*note).0 + 12 - 1; //Not sure why got a higher pitch when playing, temp fix for get it right in video
-        #[cfg(target_arch = "wasm32")]
-        let midi_note = Semitones::from(*note).0 + 12;
-        KeyNumber::try_from(midi_note as u8).ok()
+        u8::try_from(note.to_midi_key())
+            .ok()
+            .and_then(|x| KeyNumber::try_from(x).ok())
     }
     pub fn note_midi_on_msg(
         note: &Note,
@@ -41,34 +50,68 @@ impl MidiUtil {
             velocity,
         })
     }
-    pub fn get_tone_midi_msgs(
+    pub fn scale_velocity(velocity: u8, factor: f32) -> u8 {
+        if velocity == 0 {
+            return 0;
+        }
+        (velocity as f32 * factor).round().clamp(1.0, 127.0) as u8
+    }
+    /// How long the notes are sounding, e.g. shorter for staccato.
+    pub fn calc_entry_units(entry: &LaneEntry) -> Units {
+        let units = entry.tied_units();
+        let length_factor = entry.model.calc_length_factor();
+        if length_factor == 1.0 {
+            units
+        } else {
+            Units::from(units.as_f32() * length_factor)
+        }
+    }
+    pub fn get_notes_midi_msgs(
         channel: &MidiChannel,
-        _bar: &TabBar,
-        entry: &LaneEntry,
-        tone: &Tone,
+        notes: &[Note],
+        velocity: U7,
     ) -> Option<Vec<(bool, StructuredShortMessage)>> {
-        if tone.is_none() || entry.prev_is_tie() {
-            return None;
-        }
-        let mut play_msgs: Vec<(bool, StructuredShortMessage)> = tone
-            .get_notes()
+        let mut play_msgs: Vec<(bool, StructuredShortMessage)> = notes
             .iter()
-            .flat_map(|x| MidiUtil::note_midi_on_msg(x, channel.channel, channel.velocity))
+            .flat_map(|x| MidiUtil::note_midi_on_msg(x, channel.channel, velocity))
             .map(|x| (false, x))
             .collect();
-        let mut stop_msgs: Vec<(bool, StructuredShortMessage)> = tone
-            .get_notes()
+        let mut stop_msgs: Vec<(bool, StructuredShortMessage)> = notes
             .iter()
             .flat_map(|x| MidiUtil::note_midi_off_msg(x, channel.channel, channel.velocity))
             .map(|x| (true, x))
             .collect();
         play_msgs.append(&mut stop_msgs);
-        if play_msgs.len() > 0 {
+        if !play_msgs.is_empty() {
             Some(play_msgs)
         } else {
             None
         }
     }
+    pub fn get_tone_midi_msgs(
+        channel: &MidiChannel,
+        _bar: &TabBar,
+        entry: &LaneEntry,
+        tone: &Tone,
+    ) -> Option<Vec<(bool, StructuredShortMessage)>> {
+        if tone.is_none() || entry.prev_is_tie() {
+            return None;
+        }
+        Self::get_notes_midi_msgs(channel, &tone.get_notes(), channel.velocity)
+    }
+    pub fn get_chord_midi_msgs(
+        channel: &MidiChannel,
+        bar: &TabBar,
+        entry: &LaneEntry,
+        chord: &Chord,
+    ) -> Option<Vec<(bool, StructuredShortMessage)>> {
+        if entry.prev_is_tie() {
+            return None;
+        }
+        let meta = bar.tab_meta();
+        let notes = chord.calc_notes(&meta.scale, &meta.key, &Octave::P3);
+        Self::get_notes_midi_msgs(channel, &notes, channel.velocity)
+    }
     pub fn get_core_midi_msgs(
         channel: &MidiChannel,
         bar: &TabBar,
@@ -77,9 +120,27 @@ impl MidiUtil {
     ) -> Option<Vec<(bool, StructuredShortMessage)>> {
         match core_entry {
             CoreEntry::Tone(tone, _) => Self::get_tone_midi_msgs(channel, bar, entry, tone),
+            CoreEntry::Chord(chord, _) => Self::get_chord_midi_msgs(channel, bar, entry, chord),
             _ => None,
         }
     }
+    /// Messages of the entry, with the techniques of the picks.
+    pub fn get_midi_messages(
+        channel: &MidiChannel,
+        bar: &TabBar,
+        entry: &LaneEntry,
+    ) -> Option<Vec<MidiMessage>> {
+        match entry.proto() {
+            ProtoEntry::Fretted(FrettedEntry::Pick(pick, _)) => {
+                Self::get_pick_midi_messages(channel, bar, entry, pick)
+            }
+            _ => Self::get_midi_msgs(channel, bar, entry).map(|msgs| {
+                msgs.into_iter()
+                    .map(|(delay, msg)| MidiMessage::of_entry(entry, delay, msg))
+                    .collect()
+            }),
+        }
+    }
     pub fn get_midi_msgs(
         channel: &MidiChannel,
         bar: &TabBar,
@@ -89,60 +150,197 @@ impl MidiUtil {
             notation_model::prelude::ProtoEntry::Core(core_entry) => {
                 Self::get_core_midi_msgs(channel, bar, entry, core_entry)
             }
-            notation_model::prelude::ProtoEntry::Fretted6(fretted_entry) => {
-                Self::get_fretted_midi_msgs6(channel, bar, entry, fretted_entry)
-            }
-            notation_model::prelude::ProtoEntry::Fretted4(fretted_entry) => {
-                Self::get_fretted_midi_msgs4(channel, bar, entry, fretted_entry)
+            notation_model::prelude::ProtoEntry::Fretted(fretted_entry) => {
+                Self::get_fretted_midi_msgs(channel, bar, entry, fretted_entry)
             }
             _ => None,
         }
     }
 }
 
-macro_rules! impl_get_pick_midi_msgs {
-    ($name:ident, $get_fretted_shape:ident) => {
-        impl MidiUtil {
-            pub fn $name(
-                channel: &MidiChannel,
-                bar: &TabBar,
-                entry: &LaneEntry,
-                pick: &Pick,
-            ) -> Option<Vec<(bool, StructuredShortMessage)>> {
-                if let Some((fretboard, shape)) = bar.$get_fretted_shape(entry) {
-                    let meta = bar.tab_meta();
-                    let tone = fretboard.pick_tone(&meta.scale, &meta.key, &shape, pick);
-                    Self::get_tone_midi_msgs(channel, bar, entry, &tone)
-                } else {
-                    None
-                }
+impl MidiUtil {
+    pub fn get_pick_midi_msgs(
+        channel: &MidiChannel,
+        bar: &TabBar,
+        entry: &LaneEntry,
+        pick: &Pick,
+    ) -> Option<Vec<(bool, StructuredShortMessage)>> {
+        if let Some((fretboard, shape)) = bar.get_fretted_shape(entry) {
+            let meta = bar.tab_meta();
+            let tone = fretboard.pick_tone(&meta.scale, &meta.key, &shape, pick);
+            Self::get_tone_midi_msgs(channel, bar, entry, &tone)
+        } else {
+            None
+        }
+    }
+    /// Notes of the pick with the techniques, as velocities, lengths and pitch bends.
+    pub fn get_pick_midi_messages(
+        channel: &MidiChannel,
+        bar: &TabBar,
+        entry: &LaneEntry,
+        pick: &Pick,
+    ) -> Option<Vec<MidiMessage>> {
+        if entry.prev_is_tie() {
+            return None;
+        }
+        let (fretboard, shape) = bar.get_fretted_shape(entry)?;
+        let meta = bar.tab_meta();
+        let units = Self::calc_entry_units(entry);
+        let mut messages = vec![];
+        let mut pitch_bent = false;
+        for pick_note in pick.get_notes() {
+            let (fret, note) =
+                match fretboard.shape_pick_fret_note(&meta.scale, &meta.key, &shape, pick_note) {
+                    Some(x) => x,
+                    None => continue,
+                };
+            let techniques = pick_note.techniques;
+            let length = Units::from(units.as_f32() * techniques.length_factor());
+            let on_msg = Self::note_midi_on_msg(&note, channel.channel, channel.velocity);
+            let off_msg = Self::note_midi_off_msg(&note, channel.channel, channel.velocity);
+            if let (Some(on_msg), Some(off_msg)) = (on_msg, off_msg) {
+                let on = MidiMessage::of_entry(entry, false, on_msg);
+                messages.push(MidiMessage {
+                    duration: length,
+                    velocity_factor: on.velocity_factor * techniques.velocity_factor(),
+                    ..on
+                });
+                messages.push(MidiMessage {
+                    duration: length,
+                    ..MidiMessage::of_entry(entry, true, off_msg)
+                });
+            }
+            // Pitch bend is changing the whole channel, so only done for one note.
+            if !pitch_bent && techniques.has_pitch_bend() {
+                pitch_bent = true;
+                let slide_from = Self::calc_slide_from(entry, pick_note.string, fret);
+                messages.extend(Self::get_pitch_bend_messages(
+                    channel,
+                    entry,
+                    &techniques,
+                    slide_from,
+                    length,
+                ));
             }
         }
-    };
-}
-
-macro_rules! impl_get_fretted_midi_msgs {
-    ($name:ident, $get_pick_midi_msgs:ident, $fretted_entry:ident) => {
-        impl MidiUtil {
-            pub fn $name(
-                channel: &MidiChannel,
-                bar: &TabBar,
-                entry: &LaneEntry,
-                fretted_entry: &$fretted_entry,
-            ) -> Option<Vec<(bool, StructuredShortMessage)>> {
-                match fretted_entry {
-                    $fretted_entry::Pick(pick, _) => {
-                        Self::$get_pick_midi_msgs(channel, bar, entry, pick)
-                    }
-                    _ => None,
-                }
+        if !messages.is_empty() {
+            Some(messages)
+        } else {
+            None
+        }
+    }
+    pub fn calc_pitch_bend_value(semitones: f32) -> u16 {
+        let value = 8192.0 + semitones / Self::PITCH_BEND_RANGE as f32 * 8192.0;
+        value.round().clamp(0.0, 16383.0) as u16
+    }
+    pub fn pitch_bend_msg(channel: Channel, semitones: f32) -> StructuredShortMessage {
+        StructuredShortMessage::PitchBendChange {
+            channel,
+            pitch_bend_value: U14::new(Self::calc_pitch_bend_value(semitones)),
+        }
+    }
+    /// Registered parameter 0 to set `PITCH_BEND_RANGE`.
+    pub fn pitch_bend_range_msgs(channel: Channel) -> Vec<StructuredShortMessage> {
+        [(101, 0), (100, 0), (6, Self::PITCH_BEND_RANGE), (38, 0)]
+            .into_iter()
+            .map(
+                |(controller, value)| StructuredShortMessage::ControlChange {
+                    channel,
+                    controller_number: ControllerNumber::new(controller),
+                    control_value: U7::new(value),
+                },
+            )
+            .collect()
+    }
+    // Semitones to slide from, the fret of the previous note on the string, or 2 frets below.
+    fn calc_slide_from(entry: &LaneEntry, string: u8, fret: u8) -> f32 {
+        let mut prev = entry.prev();
+        while let Some(prev_entry) = prev {
+            if let Some(pick) = prev_entry.proto().as_fretted().and_then(|x| x.as_pick()) {
+                let prev_fret = pick
+                    .get_notes()
+                    .into_iter()
+                    .find(|x| x.string == string)
+                    .and_then(|x| x.fret);
+                return match prev_fret {
+                    Some(prev_fret) if prev_fret != fret => (prev_fret as f32 - fret as f32).clamp(
+                        -(Self::PITCH_BEND_RANGE as f32),
+                        Self::PITCH_BEND_RANGE as f32,
+                    ),
+                    _ => -2.0,
+                };
+            }
+            prev = prev_entry.prev();
+        }
+        -2.0
+    }
+    // Semitones of the pitch bend at the time in the note, slides and bends take
+    // the first quarter, bends are released in the third one.
+    fn calc_pitch_bend(techniques: &PickTechniques, slide_from: f32, ratio: f32, time: f32) -> f32 {
+        let mut semitones = 0.0;
+        if techniques.link == Some(PickLink::Slide) && ratio < 0.25 {
+            semitones += slide_from * (1.0 - ratio / 0.25);
+        }
+        if let Some(bend) = techniques.bend {
+            let target = bend.semitones as f32;
+            semitones += if ratio < 0.25 {
+                target * ratio / 0.25
+            } else if !bend.release || ratio < 0.5 {
+                target
+            } else if ratio < 0.75 {
+                target * (1.0 - (ratio - 0.5) / 0.25)
+            } else {
+                0.0
+            };
+        }
+        if techniques.vibrato {
+            let phase = time / Self::VIBRATO_PERIOD * std::f32::consts::TAU;
+            semitones += Self::VIBRATO_SEMITONES * phase.sin();
+        }
+        semitones
+    }
+    fn get_pitch_bend_messages(
+        channel: &MidiChannel,
+        entry: &LaneEntry,
+        techniques: &PickTechniques,
+        slide_from: f32,
+        length: Units,
+    ) -> Vec<MidiMessage> {
+        let length = length.as_f32();
+        let steps =
+            ((length / Self::PITCH_BEND_STEP).ceil() as usize).clamp(1, Self::MAX_PITCH_BEND_STEPS);
+        let mut messages = vec![];
+        let mut last_value = None;
+        // Delayed by the time, so the first one is sent before the note on.
+        for step in 0..steps {
+            let ratio = step as f32 / steps as f32;
+            let time = length * ratio;
+            let semitones = Self::calc_pitch_bend(techniques, slide_from, ratio, time);
+            let value = Self::calc_pitch_bend_value(semitones);
+            if last_value != Some(value) {
+                last_value = Some(value);
+                let msg = Self::pitch_bend_msg(channel.channel, semitones);
+                messages.push(MidiMessage {
+                    duration: Units::from(time),
+                    ..MidiMessage::of_entry(entry, true, msg)
+                });
             }
         }
-    };
+        messages.push(MidiMessage {
+            duration: Units::from(length),
+            ..MidiMessage::of_entry(entry, true, Self::pitch_bend_msg(channel.channel, 0.0))
+        });
+        messages
+    }
+    pub fn get_fretted_midi_msgs(
+        channel: &MidiChannel,
+        bar: &TabBar,
+        entry: &LaneEntry,
+        fretted_entry: &FrettedEntry,
+    ) -> Option<Vec<(bool, StructuredShortMessage)>> {
+        match fretted_entry {
+            FrettedEntry::Pick(pick, _) => Self::get_pick_midi_msgs(channel, bar, entry, pick),
+            _ => None,
+        }
+    }
 }
-
-impl_get_pick_midi_msgs!(get_pick_midi_msgs6, get_fretted_shape6);
-impl_get_pick_midi_msgs!(get_pick_midi_msgs4, get_fretted_shape4);
-
-impl_get_fretted_midi_msgs!(get_fretted_midi_msgs6, get_pick_midi_msgs6, FrettedEntry6);
-impl_get_fretted_midi_msgs!(get_fretted_midi_msgs4, get_pick_midi_msgs4, FrettedEntry4);
@@ -155,9 +155,12 @@ impl MidiSynth {
                 pressure_amount: _,
             } => todo!(),
             StructuredShortMessage::PitchBendChange {
-                channel: _,
-                pitch_bend_value: _,
-            } => todo!(),
+                channel,
+                pitch_bend_value,
+            } => {
+                let value: u16 = pitch_bend_value.into();
+                self.synth.pitch_bend(channel.into(), value.into())
+            }
             StructuredShortMessage::SystemExclusiveStart => todo!(),
             StructuredShortMessage::TimeCodeQuarterFrame(_) => todo!(),
             StructuredShortMessage::SongPositionPointer { position: _ } => todo!(),
@@ -40,7 +40,7 @@ impl MidiSynth {
                     Ok(play_note(
                         channel.into(),
                         key_number.into(),
-                        speed.calc_seconds(msg.duration),
+                        speed.calc_seconds(msg.pos.into(), msg.duration),
                         volume * Self::VOLUME_FACTOR,
                     ))
                 } else {
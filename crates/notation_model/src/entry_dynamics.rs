@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use notation_proto::prelude::{Articulation, CoreEntry, Dynamic, DynamicLevel, Units};
+
+use crate::prelude::{LaneEntry, ModelEntry, TabBar, Track};
+
+// Crescendo or decrescendo in progress, with the factors at both ends.
+struct Hairpin {
+    begin: Units,
+    span: Units,
+    from: f32,
+    to: f32,
+}
+impl Hairpin {
+    fn calc_factor(&self, pos: Units) -> f32 {
+        let progress = (pos - self.begin).ratio(&self.span).clamp(0.0, 1.0);
+        self.from + (self.to - self.from) * progress
+    }
+}
+
+/// Scales of the track velocity from the dynamic levels and hairpins, calculated once
+/// when the tab is parsed, keyed by bar ordinal and the index of the entry in the track.
+#[derive(Clone, Debug, Default)]
+pub struct TrackDynamics {
+    factors: HashMap<(usize, usize), f32>,
+}
+
+impl TrackDynamics {
+    /// Entries are walked in the order of their positions in the bars, so the parallel
+    /// sequences within the track and the repeated sections are handled properly.
+    pub fn new(track: &Track, bars: &[Arc<TabBar>]) -> Self {
+        let mut entries: Vec<(Units, usize, Arc<LaneEntry>)> = bars
+            .iter()
+            .flat_map(|bar| bar.lanes.values())
+            .filter(|lane| lane.track.props.index == track.props.index)
+            .flat_map(|lane| lane.entries.iter())
+            .map(|entry| {
+                let position = entry.bar_position();
+                (Units::from(position), position.bar_ordinal, entry.clone())
+            })
+            .collect();
+        entries.sort_by_key(|(pos, _, entry)| (*pos, entry.model.props.index));
+        let dynamics: Vec<Option<Dynamic>> = entries
+            .iter()
+            .map(|(_, _, entry)| entry.proto().as_core().and_then(|x| x.as_dynamic()).copied())
+            .collect();
+        let mut factors = HashMap::new();
+        let mut level = DynamicLevel::default();
+        let mut factor = level.velocity_factor();
+        let mut hairpin: Option<Hairpin> = None;
+        for (i, (pos, bar_ordinal, entry)) in entries.iter().enumerate() {
+            match dynamics[i] {
+                Some(Dynamic::Level(v)) => {
+                    level = v;
+                    factor = level.velocity_factor();
+                    hairpin = None;
+                }
+                Some(dynamic @ (Dynamic::Crescendo(span) | Dynamic::Decrescendo(span))) => {
+                    if let Some(current) = hairpin.as_ref() {
+                        factor = current.calc_factor(*pos);
+                    }
+                    let default_target = dynamic.default_target(&level);
+                    let next_level = dynamics
+                        .iter()
+                        .skip(i + 1)
+                        .flatten()
+                        .find_map(|x| x.as_level().copied());
+                    let target = match next_level {
+                        Some(next)
+                            if (next.velocity_factor() > factor)
+                                == (default_target.velocity_factor() > factor) =>
+                        {
+                            next
+                        }
+                        _ => default_target,
+                    };
+                    level = target;
+                    hairpin = Some(Hairpin {
+                        begin: *pos,
+                        span,
+                        from: factor,
+                        to: target.velocity_factor(),
+                    });
+                }
+                None => (),
+            }
+            let current = hairpin.as_ref().map(|x| x.calc_factor(*pos)).unwrap_or(factor);
+            factors.insert((*bar_ordinal, entry.model.props.index), current);
+        }
+        Self { factors }
+    }
+    pub fn get_factor(&self, bar_ordinal: usize, index: usize) -> Option<f32> {
+        self.factors.get(&(bar_ordinal, index)).copied()
+    }
+}
+
+impl ModelEntry {
+    // Dynamics and articulation marks right before the entry.
+    fn get_marks(&self) -> Vec<CoreEntry> {
+        let mut marks = vec![];
+        if let Some(track) = self.track() {
+            for entry in track.entries.iter().take(self.props.index).rev() {
+                match entry.proto.as_core() {
+                    Some(core) if core.is_dynamic() || core.is_articulation() => {
+                        marks.push(*core);
+                    }
+                    _ => break,
+                }
+            }
+        }
+        marks.reverse();
+        marks
+    }
+    pub fn get_dynamics(&self) -> Vec<Dynamic> {
+        self.get_marks()
+            .iter()
+            .filter_map(|x| x.as_dynamic().copied())
+            .collect()
+    }
+    pub fn get_articulations(&self) -> Vec<Articulation> {
+        self.get_marks()
+            .iter()
+            .filter_map(|x| x.as_articulation().copied())
+            .collect()
+    }
+    pub fn calc_length_factor(&self) -> f32 {
+        self.get_articulations()
+            .iter()
+            .fold(1.0, |factor, x| factor * x.length_factor())
+    }
+}
+
+impl LaneEntry {
+    /// Scale of the track velocity at the entry, from the dynamics and articulations.
+    pub fn calc_velocity_factor(&self) -> f32 {
+        let dynamic_factor = match (self.tab(), self.track()) {
+            (Some(tab), Some(track)) => tab
+                .dynamics
+                .get(track.props.index)
+                .and_then(|x| x.get_factor(self.bar_props().bar_ordinal, self.model.props.index)),
+            _ => None,
+        }
+        .unwrap_or(1.0);
+        self.model
+            .get_articulations()
+            .iter()
+            .fold(dynamic_factor, |factor, x| factor * x.velocity_factor())
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt::Display;
 use std::sync::Arc;
 
-use notation_proto::prelude::{FrettedEntry6, ProtoEntry, TrackKind};
+use notation_proto::prelude::{FrettedEntry, ProtoEntry, TrackKind};
 
 use crate::prelude::ModelEntry;
 
@@ -50,11 +50,11 @@ impl LaneKind {
             TrackKind::Lyrics => Self::Lyrics,
             TrackKind::Vocal => Self::Melody,
             TrackKind::Guitar => match entry {
-                ProtoEntry::Fretted6(entry) => match entry {
-                    FrettedEntry6::Pick(_, _) => Self::Strings,
-                    FrettedEntry6::Strum(_, _) => Self::Strings,
-                    FrettedEntry6::Shape(_, _) => Self::Shapes,
-                    FrettedEntry6::Fretboard(_) => Self::None,
+                ProtoEntry::Fretted(entry) => match entry {
+                    FrettedEntry::Pick(_, _) => Self::Strings,
+                    FrettedEntry::Strum(_, _) => Self::Strings,
+                    FrettedEntry::Shape(_, _) => Self::Shapes,
+                    FrettedEntry::Fretboard(_) => Self::None,
                 },
                 _ => Self::None,
             },
@@ -2,6 +2,7 @@ pub use notation_proto;
 
 pub mod bar;
 pub mod bar_lane;
+pub mod entry_dynamics;
 pub mod form;
 pub mod lane_entry;
 pub mod lane_kind;
@@ -12,7 +13,9 @@ pub mod section;
 pub mod tab;
 pub mod tab_bar;
 pub mod tab_chord;
+pub mod tab_key;
 pub mod track;
+pub mod track_fingering;
 pub mod util;
 
 pub mod prelude {
@@ -23,6 +26,8 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::bar_lane::{BarLane, BarLaneProps};
     #[doc(hidden)]
+    pub use crate::entry_dynamics::TrackDynamics;
+    #[doc(hidden)]
     pub use crate::form::Form;
     #[doc(hidden)]
     pub use crate::lane_entry::{LaneEntry, LaneEntryProps};
@@ -41,6 +46,8 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::play::play_events::*;
     #[doc(hidden)]
+    pub use crate::play::tempo_curve::{TempoCurve, TempoPiece};
+    #[doc(hidden)]
     pub use crate::section::Section;
     #[doc(hidden)]
     pub use crate::tab::Tab;
@@ -51,6 +58,8 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::track::{Track, TrackProps};
     #[doc(hidden)]
+    pub use crate::track_fingering::TrackFingering;
+    #[doc(hidden)]
     pub use notation_proto::prelude::Bar as ProtoBar;
     #[doc(hidden)]
     pub use notation_proto::prelude::BarLayer as ProtoBarLayer;
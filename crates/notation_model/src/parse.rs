@@ -4,7 +4,7 @@ use std::collections::HashMap;
 use std::sync::{Arc, Weak};
 use thiserror::Error;
 
-use crate::prelude::{BarLane, Form, LaneEntry, ModelEntry, Section, Tab, TabBar, TabMeta, Track, Slice};
+use crate::prelude::{BarLane, Form, LaneEntry, ModelEntry, Section, Tab, TabBar, TabMeta, Track, TrackDynamics, Slice};
 use notation_proto::prelude::{Duration, Entry, ProtoEntry, Units};
 
 #[derive(Error, Clone, Debug)]
@@ -21,7 +21,7 @@ impl Tab {
         Arc::<Tab>::new_cyclic(|weak_self| {
             let uuid = proto.uuid;
             let meta = Arc::new(proto.meta);
-            let tracks = proto
+            let tracks: Vec<Arc<Track>> = proto
                 .tracks
                 .into_iter()
                 .enumerate()
@@ -80,6 +80,10 @@ impl Tab {
             } else {
                 all_bars
             };
+            let dynamics = tracks
+                .iter()
+                .map(|track| TrackDynamics::new(track, &bars))
+                .collect();
             Self {
                 uuid,
                 meta,
@@ -87,6 +91,7 @@ impl Tab {
                 sections,
                 form,
                 bars,
+                dynamics,
             }
         })
     }
@@ -161,7 +166,7 @@ impl Section {
 }
 impl ModelEntry {
     pub fn calc_tied_units(entries: &Vec<ProtoEntry>, index: usize) -> Units {
-        let mut units = Units(0.0);
+        let mut units = Units::ZERO;
         if let Some(entry) = entries.get(index) {
             units = units + Units::from(entry.duration());
             if let Some(next_entry) = entries.get(index + 1) {
@@ -192,13 +197,13 @@ impl ModelEntry {
 }
 impl LaneEntry {
     pub fn new_entries(v: Vec<Arc<ModelEntry>>, lane: &Weak<BarLane>, slice: Slice) -> Vec<Arc<LaneEntry>> {
-        let mut pos = 0.0;
+        let mut pos = Units::ZERO;
         v.into_iter()
             .enumerate()
             .map(|(index, entry)| {
                 let in_bar_pos = pos;
-                pos += Units::from(entry.as_ref().duration()).0;
-                LaneEntry::new(lane.clone(), slice.clone(), index, index, entry, Units(in_bar_pos))
+                pos = pos + Units::from(entry.as_ref().duration());
+                LaneEntry::new(lane.clone(), slice.clone(), index, index, entry, in_bar_pos)
             })
             .map(Arc::new)
             .collect()
@@ -1,4 +1,5 @@
 pub mod play_clock;
 pub mod play_control;
 pub mod play_state;
-pub mod play_events;
\ No newline at end of file
+pub mod play_events;
+pub mod tempo_curve;
@@ -1,25 +1,26 @@
+use std::sync::Arc;
+
 use notation_proto::prelude::TabPosition;
 
-use crate::prelude::{BarPosition, Bpm, PlayState, Position, Tab, TabMeta, Units};
+use crate::prelude::{BarPosition, PlayState, Position, Tab, TabMeta, TempoCurve, Units};
 
 #[derive(Debug)]
 pub struct TabPlayStateChanged();
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct PlaySpeed {
-    pub seconds_per_unit: f32,
-    pub units_per_second: f32,
     factor: f32,
+    curve: Arc<TempoCurve>,
 }
 
 impl PlaySpeed {
     pub fn new(tab_meta: &TabMeta) -> Self {
-        let units_per_second =
-            Bpm::from(tab_meta.tempo) as f32 / 60.0 * Units::from(tab_meta.signature.beat_unit).0;
+        Self::with_curve(TempoCurve::new(tab_meta))
+    }
+    pub fn with_curve(curve: TempoCurve) -> Self {
         Self {
-            seconds_per_unit: 1.0 / units_per_second,
-            units_per_second,
             factor: 1.0,
+            curve: Arc::new(curve),
         }
     }
     pub fn factor(&self) -> f32 {
@@ -34,11 +35,16 @@ impl PlaySpeed {
             false
         }
     }
-    pub fn calc_units(&self, seconds: f32) -> Units {
-        Units(seconds * self.units_per_second * self.factor)
+    pub fn curve(&self) -> &TempoCurve {
+        self.curve.as_ref()
+    }
+    /// Units played in the seconds from the position, following the tempo changes.
+    pub fn calc_units(&self, begin: Units, seconds: f32) -> Units {
+        self.curve.calc_units(begin, seconds * self.factor)
     }
-    pub fn calc_seconds(&self, units: Units) -> f32 {
-        units.0 * self.seconds_per_unit / self.factor
+    /// Seconds to play the units from the position, following the tempo changes.
+    pub fn calc_seconds(&self, begin: Units, units: Units) -> f32 {
+        self.curve.calc_seconds(begin, units) / self.factor
     }
 }
 
@@ -76,12 +82,12 @@ impl TickResult {
 impl Default for PlayControl {
     fn default() -> Self {
         let tab_meta = TabMeta::default();
-        Self::_new(&tab_meta, 0)
+        Self::_new(&tab_meta, 0, PlaySpeed::new(&tab_meta))
     }
 }
 
 impl PlayControl {
-    fn _new(tab_meta: &TabMeta, bars: usize) -> Self {
+    fn _new(tab_meta: &TabMeta, bars: usize, play_speed: PlaySpeed) -> Self {
         Self {
             position: Position::new(tab_meta.bar_units()),
             bars,
@@ -89,7 +95,7 @@ impl PlayControl {
             end_bar_ordinal: if bars > 0 { bars - 1 } else { bars },
             should_loop: false,
             play_state: PlayState::default(),
-            play_speed: PlaySpeed::new(tab_meta),
+            play_speed,
         }
     }
     pub fn get_last_bar_ordinal(&self) -> usize {
@@ -100,7 +106,8 @@ impl PlayControl {
         }
     }
     pub fn new(tab: &Tab) -> Self {
-        Self::_new(&tab.meta, tab.bars.len())
+        let play_speed = PlaySpeed::with_curve(TempoCurve::from_tab(tab));
+        Self::_new(&tab.meta, tab.bars.len(), play_speed)
     }
     pub fn play(&mut self) -> bool {
         if self.play_state.is_playing() {
@@ -116,7 +123,7 @@ impl PlayControl {
         } else {
             self.play_state = PlayState::Paused;
             //self.position
-            //    .set_in_bar(self.position.bar.bar_ordinal, Units(0.0));
+            //    .set_in_bar(self.position.bar.bar_ordinal, Units::ZERO);
             true
         }
     }
@@ -124,7 +131,7 @@ impl PlayControl {
         if !self.play_state.is_stopped() {
             self.play_state = PlayState::Stopped;
         }
-        self.position.set_in_bar(self.begin_bar_ordinal, Units(0.0));
+        self.position.set_in_bar(self.begin_bar_ordinal, Units::ZERO);
         true
     }
     pub fn _tick_to_position(&mut self, jumped: bool, pos: TabPosition) -> TickResult {
@@ -153,11 +160,12 @@ impl PlayControl {
             let delta_units = if self.position.bar.bar_ordinal < self.begin_bar_ordinal
                 || self.position.bar.bar_ordinal > self.end_bar_ordinal
             {
-                self.position.set_in_bar(self.begin_bar_ordinal, Units(0.0));
+                self.position.set_in_bar(self.begin_bar_ordinal, Units::ZERO);
                 jumped = true;
-                Units(0.0)
+                Units::ZERO
             } else {
-                self.play_speed.calc_units(delta_seconds)
+                self.play_speed
+                    .calc_units(self.position.tab.in_tab_pos, delta_seconds)
             };
             self._tick_to_position(
                 jumped,
@@ -176,7 +184,7 @@ impl PlayControl {
         BarPosition::new(
             self.position.bar.bar_units,
             self.begin_bar_ordinal,
-            Units(0.0),
+            Units::ZERO,
         )
     }
     pub fn has_selection(&self, add_ready_section: bool) -> bool {
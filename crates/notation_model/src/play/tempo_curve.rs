@@ -0,0 +1,252 @@
+use notation_proto::prelude::{Bpm, Entry, MetaEntry, TempoChange, Units};
+
+use crate::prelude::{Tab, TabMeta};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TempoPiece {
+    pub begin: Units,
+    pub seconds_per_unit: f32,
+}
+
+/// Tempo over the whole tab, kept as pieces of constant speed, each lasting until the
+/// next one begins, ramps are split into small steps.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TempoCurve {
+    pub base_bpm: f32,
+    units_per_beat: f32,
+    pieces: Vec<TempoPiece>,
+}
+
+impl TempoCurve {
+    pub const RAMP_STEPS: i64 = 16;
+
+    pub fn new(tab_meta: &TabMeta) -> Self {
+        let mut curve = Self {
+            base_bpm: Bpm::from(tab_meta.tempo) as f32,
+            units_per_beat: Units::from(tab_meta.signature.beat_unit).as_f32(),
+            pieces: vec![],
+        };
+        curve.push(Units::ZERO, curve.base_bpm, 1.0);
+        curve
+    }
+    fn calc_seconds_per_unit(&self, bpm: f32) -> f32 {
+        60.0 / (bpm.max(1.0) * self.units_per_beat)
+    }
+    // Later changes override the pieces after their positions.
+    fn push(&mut self, begin: Units, bpm: f32, stretch: f32) {
+        self.pieces.retain(|x| x.begin < begin);
+        self.pieces.push(TempoPiece {
+            begin,
+            seconds_per_unit: self.calc_seconds_per_unit(bpm) * stretch,
+        });
+    }
+    // Splits the piece at the position, so that the ones after it can be changed alone.
+    fn split(&mut self, pos: Units) {
+        let index = self.piece_index(pos);
+        let piece = self.pieces[index];
+        if piece.begin < pos {
+            self.pieces.insert(
+                index + 1,
+                TempoPiece {
+                    begin: pos,
+                    seconds_per_unit: piece.seconds_per_unit,
+                },
+            );
+        }
+    }
+    // Pieces within the span are slowed down, the ones after it are kept, e.g. a ramp going on.
+    fn stretch(&mut self, begin: Units, end: Units, stretch: f32) {
+        self.split(begin);
+        self.split(end);
+        for piece in self.pieces.iter_mut() {
+            if piece.begin >= begin && piece.begin < end {
+                piece.seconds_per_unit *= stretch;
+            }
+        }
+    }
+    /// Changes are the meta entries with their positions in the tab, sorted by position.
+    pub fn with_changes(tab_meta: &TabMeta, changes: &[(Units, MetaEntry)]) -> Self {
+        let mut curve = Self::new(tab_meta);
+        let mut bpm = curve.base_bpm;
+        for (pos, change) in changes.iter() {
+            match change {
+                MetaEntry::Tempo(tempo) => {
+                    bpm = Bpm::from(*tempo) as f32;
+                    curve.push(*pos, bpm, 1.0);
+                }
+                MetaEntry::TempoChange(TempoChange::Ramp(tempo, span, ramp)) => {
+                    let target = Bpm::from(*tempo) as f32;
+                    if !span.is_zero() {
+                        for step in 0..Self::RAMP_STEPS {
+                            let begin = *pos + span.scaled(step, Self::RAMP_STEPS);
+                            let progress = (step as f32 + 0.5) / Self::RAMP_STEPS as f32;
+                            let portion = ramp.calc_portion(progress);
+                            curve.push(begin, bpm + (target - bpm) * portion, 1.0);
+                        }
+                    }
+                    bpm = target;
+                    curve.push(*pos + *span, bpm, 1.0);
+                }
+                MetaEntry::TempoChange(TempoChange::Fermata(span, percent)) => {
+                    curve.stretch(*pos, *pos + *span, *percent as f32 / 100.0);
+                }
+                MetaEntry::TempoChange(TempoChange::ATempo) => {
+                    bpm = curve.base_bpm;
+                    curve.push(*pos, bpm, 1.0);
+                }
+                _ => (),
+            }
+        }
+        curve
+    }
+    /// Tempo changes from all tracks, following the form, so repeated sections get them again,
+    /// fermata marks on the notes are included as well.
+    pub fn from_tab(tab: &Tab) -> Self {
+        let mut changes = vec![];
+        for bar in tab.bars.iter() {
+            let bar_pos = bar.tab_position().in_tab_pos;
+            for lane in bar.lanes.values() {
+                for entry in lane.entries.iter() {
+                    let pos = bar_pos + entry.props.in_bar_pos;
+                    if let Some(meta) = entry.proto().as_meta() {
+                        if meta.is_tempo() || meta.is_tempo_change() {
+                            changes.push((pos, *meta));
+                        }
+                    }
+                    let span = Units::from(entry.duration());
+                    if !span.is_zero() {
+                        for articulation in entry.model.get_articulations() {
+                            if let Some(change) = articulation.tempo_change(span) {
+                                changes.push((pos, MetaEntry::from(change)));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        changes.sort_by_key(|x| x.0);
+        changes.dedup();
+        Self::with_changes(&tab.meta, &changes)
+    }
+    pub fn pieces(&self) -> &Vec<TempoPiece> {
+        &self.pieces
+    }
+    fn piece_index(&self, pos: Units) -> usize {
+        self.pieces
+            .iter()
+            .rposition(|x| x.begin <= pos)
+            .unwrap_or(0)
+    }
+    pub fn calc_bpm(&self, pos: Units) -> f32 {
+        let piece = self.pieces[self.piece_index(pos)];
+        60.0 / (piece.seconds_per_unit * self.units_per_beat)
+    }
+    /// Seconds to play the units from the position.
+    pub fn calc_seconds(&self, begin: Units, units: Units) -> f32 {
+        let end = begin + units;
+        let mut index = self.piece_index(begin);
+        let mut pos = begin;
+        let mut seconds = 0.0;
+        while pos < end {
+            let piece_end = self
+                .pieces
+                .get(index + 1)
+                .map(|x| x.begin.min(end))
+                .unwrap_or(end);
+            seconds += (piece_end - pos).as_f32() * self.pieces[index].seconds_per_unit;
+            pos = piece_end;
+            index += 1;
+        }
+        seconds
+    }
+    /// Units played in the seconds from the position.
+    pub fn calc_units(&self, begin: Units, seconds: f32) -> Units {
+        let mut index = self.piece_index(begin);
+        let mut pos = begin;
+        let mut seconds = seconds;
+        while let Some(next) = self.pieces.get(index + 1) {
+            let piece_seconds = (next.begin - pos).as_f32() * self.pieces[index].seconds_per_unit;
+            if piece_seconds >= seconds {
+                break;
+            }
+            seconds -= piece_seconds;
+            pos = next.begin;
+            index += 1;
+        }
+        pos - begin + Units::from(seconds / self.pieces[index].seconds_per_unit)
+    }
+}
+
+#[cfg(test)]
+fn test_curve(changes: &[(i64, MetaEntry)]) -> TempoCurve {
+    use notation_proto::prelude::{Key, Scale, Signature, Tempo};
+    let meta = TabMeta::new(Key::C, Scale::Major, Signature::_4_4, Tempo::Bpm(60));
+    let changes: Vec<(Units, MetaEntry)> = changes
+        .iter()
+        .map(|(pos, change)| (Units::new(*pos, 1), *change))
+        .collect();
+    TempoCurve::with_changes(&meta, &changes)
+}
+
+#[cfg(test)]
+fn assert_near(value: f32, expected: f32) {
+    assert!(
+        (value - expected).abs() < 0.05,
+        "{} is not {}",
+        value,
+        expected
+    );
+}
+
+// Linear ramp from 60 to 120 bpm over 4 whole notes, a whole note is 4 seconds at 60 bpm.
+#[cfg(test)]
+fn test_ramp() -> (i64, MetaEntry) {
+    use notation_proto::prelude::{Tempo, TempoRamp};
+    let ramp = TempoChange::Ramp(Tempo::Bpm(120), Units::new(4, 1), TempoRamp::Linear);
+    (0, MetaEntry::from(ramp))
+}
+
+#[test]
+fn test_tempo_curve_ramp() {
+    let curve = test_curve(&[test_ramp()]);
+    let ramp_seconds = 16.0 * 2.0_f32.ln();
+    assert_near(
+        curve.calc_seconds(Units::ZERO, Units::new(4, 1)),
+        ramp_seconds,
+    );
+    // Ramp steps take the tempo at their middle, this one is from 2 to 2 1/4.
+    assert_near(curve.calc_bpm(Units::new(2, 1)), 60.0 + 15.0 * 2.125);
+    assert_near(curve.calc_seconds(Units::new(4, 1), Units::new(1, 1)), 2.0);
+    let units = curve.calc_units(Units::ZERO, ramp_seconds + 2.0);
+    assert_near(units.as_f32(), 5.0);
+}
+
+#[test]
+fn test_tempo_curve_fermata() {
+    let fermata = TempoChange::Fermata(Units::new(1, 1), 200);
+    let curve = test_curve(&[test_ramp(), (1, MetaEntry::from(fermata))]);
+    assert_near(
+        curve.calc_bpm(Units::new(3, 2)),
+        (60.0 + 15.0 * 1.625) / 2.0,
+    );
+    assert_near(curve.calc_bpm(Units::new(5, 2)), 60.0 + 15.0 * 2.625);
+    assert_near(curve.calc_bpm(Units::new(5, 1)), 120.0);
+    let hold_seconds = 16.0 * (90.0_f32 / 75.0).ln();
+    let seconds = 16.0 * 2.0_f32.ln() + hold_seconds;
+    assert_near(curve.calc_seconds(Units::ZERO, Units::new(4, 1)), seconds);
+    assert_near(curve.calc_units(Units::ZERO, seconds).as_f32(), 4.0);
+}
+
+#[test]
+fn test_tempo_curve_a_tempo() {
+    use notation_proto::prelude::Tempo;
+    let curve = test_curve(&[
+        (1, MetaEntry::Tempo(Tempo::Bpm(120))),
+        (2, MetaEntry::from(TempoChange::ATempo)),
+    ]);
+    assert_near(curve.calc_seconds(Units::ZERO, Units::new(3, 1)), 10.0);
+    assert_near(curve.calc_seconds(Units::new(3, 2), Units::new(1, 1)), 3.0);
+    assert_near(curve.calc_units(Units::ZERO, 6.0).as_f32(), 2.0);
+    assert_near(curve.calc_units(Units::ZERO, 8.0).as_f32(), 2.5);
+    assert_near(curve.calc_bpm(Units::new(5, 2)), 60.0);
+}
@@ -5,7 +5,7 @@ use notation_proto::prelude::{BarPosition, Note, TrackKind, Octave};
 use uuid::Uuid;
 
 use crate::prelude::{
-    Form, Pitch, Section, Signature, Syllable, TabBar, TabMeta, Track, Unit, Units,
+    Form, Pitch, Section, Signature, Syllable, TabBar, TabMeta, Track, TrackDynamics, Unit, Units,
 };
 
 #[derive(Debug)]
@@ -16,6 +16,8 @@ pub struct Tab {
     pub sections: Vec<Arc<Section>>,
     pub form: Form,
     pub bars: Vec<Arc<TabBar>>,
+    /// Velocity scales of the tracks, in the same order.
+    pub dynamics: Vec<TrackDynamics>,
 }
 impl Display for Tab {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -3,8 +3,7 @@ use std::sync::{Arc, Weak};
 use std::collections::HashMap;
 
 use notation_proto::prelude::{
-    BarPosition, Chord, Fretboard4, Fretboard6, HandShape4, HandShape6, Note, Position,
-    TabPosition, Octave,
+    BarPosition, Chord, Fretboard, HandShape, Note, Octave, Position, TabPosition, Tuplet,
 };
 
 use crate::prelude::{
@@ -25,7 +24,7 @@ pub struct TabBarProps {
 
 impl From<TabBarProps> for BarPosition {
     fn from(v: TabBarProps) -> Self {
-        BarPosition::new(v.bar_units, v.bar_ordinal, Units(0.0))
+        BarPosition::new(v.bar_units, v.bar_ordinal, Units::ZERO)
     }
 }
 
@@ -119,7 +118,7 @@ impl TabBar {
         })
     }
     pub fn tab_position(&self) -> TabPosition {
-        TabPosition::new(Units(self.props.bar_ordinal as f32 * self.bar_units().0))
+        TabPosition::new(self.bar_units() * self.props.bar_ordinal)
     }
     pub fn tab_meta(&self) -> Arc<TabMeta> {
         match self.tab.upgrade() {
@@ -182,7 +181,7 @@ impl TabBar {
             .iter()
             .filter_map(| ((_k, _i), lane) | {
                 lane.get_entry(&|x: &LaneEntry| {
-                    if x.props.in_bar_pos.is_bigger_than(&in_bar_pos) {
+                    if x.props.in_bar_pos > in_bar_pos {
                         predicate(x)
                     } else {
                         None
@@ -196,13 +195,13 @@ impl TabBar {
         in_bar_pos: Units,
         predicate: &F,
     ) -> Option<T> {
-        let mut result_in_bar_pos = Units(f32::MAX);
+        let mut result_in_bar_pos: Option<Units> = None;
         let mut result = None;
         for ((_k, _i), lane) in self.lanes.iter() {
             if let Some((in_bar_pos, entry)) = lane.get_entry(&|x: &LaneEntry| {
-                if result_in_bar_pos < x.props.in_bar_pos {
+                if matches!(result_in_bar_pos, Some(pos) if pos < x.props.in_bar_pos) {
                     None
-                } else if x.props.in_bar_pos.is_bigger_than(&in_bar_pos) {
+                } else if x.props.in_bar_pos > in_bar_pos {
                     if let Some(t) = predicate(x) {
                         Some((x.props.in_bar_pos, t))
                     } else {
@@ -212,7 +211,7 @@ impl TabBar {
                     None
                 }
             }) {
-                result_in_bar_pos = in_bar_pos;
+                result_in_bar_pos = Some(in_bar_pos);
                 result = Some(entry)
             }
         }
@@ -256,49 +255,66 @@ impl TabBar {
     pub fn get_chord_of_entry(&self, entry: &LaneEntry) -> Option<Chord> {
         self.get_chord(Some(entry.props.in_bar_pos))
     }
+    /// The outermost tuplet of entries starting within the beat.
+    pub fn get_beat_tuplet(&self, beat: u8) -> Option<Tuplet> {
+        let beat_units = Units::from(self.beat_unit());
+        let begin = beat_units * beat as usize;
+        let end = begin + beat_units;
+        self.lanes.values().find_map(|lane| {
+            lane.entries
+                .iter()
+                .filter(|x| x.props.in_bar_pos >= begin && x.props.in_bar_pos < end)
+                .find_map(|x| x.props.duration.tuplets().first())
+        })
+    }
+    /// Tuplet spans as tuplet, begin and end, entries of the same outermost tuplet are grouped
+    /// in the time of `in_time_of` of their units, counted from the bar start.
+    pub fn get_tuplet_spans(&self) -> Vec<(Tuplet, Units, Units)> {
+        let mut spans = self
+            .lanes
+            .values()
+            .flat_map(|lane| lane.entries.iter())
+            .filter_map(|x| {
+                let duration = x.props.duration;
+                let (tuplet, unit) = duration.tuplets().first().zip(duration.unit())?;
+                let span = Units::from(unit) * tuplet.in_time_of as usize;
+                let begin = span * x.props.in_bar_pos.div_floor(&span) as usize;
+                Some((tuplet, begin, begin + span))
+            })
+            .collect::<Vec<_>>();
+        spans.sort_by_key(|x| (x.1, x.2));
+        spans.dedup();
+        spans
+    }
+    /// Positions and dots of the dotted entries.
+    pub fn get_dotted_entries(&self) -> Vec<(Units, u8)> {
+        let mut dotted = self
+            .lanes
+            .values()
+            .flat_map(|lane| lane.entries.iter())
+            .filter(|x| x.props.duration.dots() > 0)
+            .map(|x| (x.props.in_bar_pos, x.props.duration.dots()))
+            .collect::<Vec<_>>();
+        dotted.sort();
+        dotted.dedup();
+        dotted
+    }
 }
 
-macro_rules! impl_get_fretted_shape {
-    ($name:ident, $strings:literal, $as_fretted:ident, $get_fretboard:ident, $fretboard:ident, $hand_shape:ident) => {
-        impl TabBar {
-            pub fn $name(&self, entry: &LaneEntry) -> Option<($fretboard, $hand_shape)> {
-                entry
-                    .track()
-                    .and_then(|t| t.$get_fretboard())
-                    .map(|fretboard| {
-                        let shape = self
-                            .get_entry_in_other_lane(
-                                LaneKind::Shapes,
-                                Some(entry.lane_props().track.index),
-                                Some(entry.props.in_bar_pos),
-                                &|x: &LaneEntry| {
-                                    x.model()
-                                        .$as_fretted()
-                                        .and_then(|y| y.as_shape())
-                                        .map(|y| y.clone())
-                                },
-                            )
-                            .unwrap_or_default();
-                        (fretboard, shape)
-                    })
-            }
-        }
-    };
+impl TabBar {
+    pub fn get_fretted_shape(&self, entry: &LaneEntry) -> Option<(Fretboard, HandShape)> {
+        entry.track().and_then(|t| t.get_fretboard()).map(|fretboard| {
+            let shape = self
+                .get_entry_in_other_lane(
+                    LaneKind::Shapes,
+                    Some(entry.lane_props().track.index),
+                    Some(entry.props.in_bar_pos),
+                    &|x: &LaneEntry| {
+                        x.model().as_fretted().and_then(|y| y.as_shape()).copied()
+                    },
+                )
+                .unwrap_or_else(|| HandShape::open(fretboard.string_num()));
+            (fretboard, shape)
+        })
+    }
 }
-
-impl_get_fretted_shape!(
-    get_fretted_shape6,
-    6,
-    as_fretted6,
-    get_fretboard6,
-    Fretboard6,
-    HandShape6
-);
-impl_get_fretted_shape!(
-    get_fretted_shape4,
-    4,
-    as_fretted4,
-    get_fretboard4,
-    Fretboard4,
-    HandShape4
-);
@@ -1,7 +1,7 @@
 use std::fmt::Display;
 use std::sync::Arc;
 
-use notation_proto::prelude::{Chord, Position};
+use notation_proto::prelude::{Chord, Position, RomanNumeral};
 
 use crate::prelude::{ModelEntry, Tab};
 use crate::tab_bar::TabBar;
@@ -28,6 +28,9 @@ impl TabChord {
     pub fn tab(&self) -> Option<Arc<Tab>> {
         self.entries.first().and_then(|x| x.tab())
     }
+    pub fn calc_roman_numeral(&self) -> Option<RomanNumeral> {
+        self.tab().and_then(|x| x.meta.calc_roman_numeral(&self.chord))
+    }
     pub fn first_entry(&self) -> Option<Arc<ModelEntry>> {
         self.entries.first().map(|x| x.clone())
     }
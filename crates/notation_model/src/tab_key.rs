@@ -0,0 +1,54 @@
+use notation_proto::prelude::{CoreEntry, Entry, FrettedEntry, Key, KeyCandidate, Note};
+
+use crate::prelude::{LaneEntry, ProtoEntry, Tab, TabBar};
+
+impl TabBar {
+    pub fn get_entry_notes(&self, entry: &LaneEntry) -> Vec<Note> {
+        let meta = self.tab_meta();
+        let tone = match entry.proto() {
+            ProtoEntry::Core(CoreEntry::Tone(tone, _)) => Some(*tone),
+            ProtoEntry::Fretted(FrettedEntry::Pick(pick, _)) => {
+                self.get_fretted_shape(entry).map(|(fretboard, shape)| {
+                    fretboard.pick_tone(&meta.scale, &meta.key, &shape, pick)
+                })
+            }
+            _ => None,
+        };
+        tone.map(|x| x.get_notes()).unwrap_or_default()
+    }
+    /// Notes played in the bar, weighted by their length including ties.
+    pub fn get_weighted_notes(&self) -> Vec<(Note, f32)> {
+        let mut notes = Vec::new();
+        for lane in self.lanes.values() {
+            for entry in lane.entries.iter() {
+                if entry.prev_is_tie() {
+                    continue;
+                }
+                let weight = entry.props.tied_units.as_f32();
+                for note in self.get_entry_notes(entry) {
+                    notes.push((note, weight));
+                }
+            }
+        }
+        notes
+    }
+}
+
+impl Tab {
+    /// Major and minor keys ranked by how well they fit the tones and picks in all bars, best first.
+    pub fn detect_keys(&self) -> Vec<KeyCandidate> {
+        let notes: Vec<(Note, f32)> = self
+            .bars
+            .iter()
+            .flat_map(|x| x.get_weighted_notes())
+            .collect();
+        Key::detect_notes(&notes)
+    }
+    /// The best detected key, if it doesn't agree with the declared one.
+    pub fn check_key(&self) -> Option<KeyCandidate> {
+        self.detect_keys()
+            .first()
+            .filter(|x| !x.agrees_with(&self.meta.key, &self.meta.scale))
+            .copied()
+    }
+}
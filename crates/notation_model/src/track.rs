@@ -4,9 +4,7 @@ use std::sync::{Arc, Weak};
 
 use notation_proto::prelude::Chord;
 
-use crate::prelude::{
-    Fretboard4, Fretboard6, ModelEntry, SliceBegin, SliceEnd, Tab, TabChord, TrackKind,
-};
+use crate::prelude::{Fretboard, ModelEntry, SliceBegin, SliceEnd, Tab, TabChord, TrackKind};
 
 #[derive(Copy, Clone, Debug, Default)]
 pub struct TrackProps {
@@ -140,18 +138,11 @@ impl Track {
     }
 }
 
-macro_rules! impl_get_fretboard {
-    ($name:ident, $strings:literal, $as_fretted:ident, $fretboard:ident) => {
-        impl Track {
-            pub fn $name(&self) -> Option<$fretboard> {
-                self.get_entry(&|x: &ModelEntry| {
-                    x.$as_fretted()
-                        .and_then(|x| x.as_fretboard().map(|z| z.to_owned()))
-                })
-            }
-        }
-    };
+impl Track {
+    pub fn get_fretboard(&self) -> Option<Fretboard> {
+        self.get_entry(&|x: &ModelEntry| {
+            x.as_fretted()
+                .and_then(|x| x.as_fretboard().map(|z| z.to_owned()))
+        })
+    }
 }
-
-impl_get_fretboard!(get_fretboard6, 6, as_fretted6, Fretboard6);
-impl_get_fretboard!(get_fretboard4, 4, as_fretted4, Fretboard4);
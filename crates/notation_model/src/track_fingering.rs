@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use notation_proto::prelude::{FrettedEntry, HandShape, Pick, Units};
+
+use crate::prelude::{LaneEntry, LaneKind, Tab};
+
+/// Fretting fingers of the shapes and picks in a track, planned in playing order, so the
+/// same entry can get different fingers in different bars.
+#[derive(Clone, Debug, Default)]
+pub struct TrackFingering {
+    // Keyed by bar ordinal, lane kind and entry index in the lane.
+    entries: HashMap<(usize, LaneKind, usize), FrettedEntry>,
+}
+
+impl TrackFingering {
+    pub fn from_tab(tab: &Tab, track_index: usize) -> Self {
+        let fretboard = match tab.tracks.get(track_index).and_then(|x| x.get_fretboard()) {
+            Some(fretboard) => fretboard,
+            None => return Self::default(),
+        };
+        let mut keys = vec![];
+        let mut entries = vec![];
+        for bar in tab.bars.iter() {
+            let mut bar_entries: Vec<(Units, LaneKind, usize, FrettedEntry)> = vec![];
+            // Shapes first, so the picks at the same position follow them.
+            for lane_kind in [LaneKind::Shapes, LaneKind::Strings] {
+                if let Some(lane) = bar.lanes.get(&(lane_kind, track_index)) {
+                    for entry in lane.entries.iter() {
+                        if let Some(fretted) = entry.proto().as_fretted() {
+                            if fretted.is_shape() || fretted.is_pick() {
+                                bar_entries.push((
+                                    entry.in_bar_pos(),
+                                    lane_kind,
+                                    entry.props.index,
+                                    fretted.clone(),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+            bar_entries.sort_by_key(|x| (x.0, x.1.order()));
+            for (_, lane_kind, index, fretted) in bar_entries {
+                keys.push((bar.props.bar_ordinal, lane_kind, index));
+                entries.push(fretted);
+            }
+        }
+        fretboard.assign_fingers(&mut entries);
+        Self {
+            entries: keys.into_iter().zip(entries).collect(),
+        }
+    }
+    fn get_entry(&self, lane_kind: LaneKind, entry: &LaneEntry) -> Option<&FrettedEntry> {
+        let key = (entry.bar_props().bar_ordinal, lane_kind, entry.props.index);
+        self.entries.get(&key)
+    }
+    pub fn get_pick(&self, entry: &LaneEntry) -> Option<Pick> {
+        self.get_entry(LaneKind::Strings, entry)
+            .and_then(|x| x.as_pick())
+            .copied()
+    }
+    pub fn get_shape(&self, entry: &LaneEntry) -> Option<HandShape> {
+        self.get_entry(LaneKind::Shapes, entry)
+            .and_then(|x| x.as_shape())
+            .copied()
+    }
+}
@@ -0,0 +1,93 @@
+use crate::prelude::{BarLayer, ProtoEntry, SliceBegin, Tab, Track, TrackKind};
+use notation_core::prelude::{CoreEntry, Tone};
+use notation_fretted::prelude::{Fretboard, FrettedEntry, MelodyConstraints};
+
+impl Track {
+    /// Guitar track picking the tones of this one, e.g. from a vocal track.
+    ///
+    /// The fretboard is added as the first entry, other entries are kept after it at the
+    /// same order, tones out of the fretboard become rests.
+    pub fn to_melody_track(
+        &self,
+        id: &str,
+        fretboard: Fretboard,
+        constraints: &MelodyConstraints,
+    ) -> Self {
+        let tones: Vec<Tone> = self
+            .entries
+            .iter()
+            .map(|x| match x {
+                ProtoEntry::Core(CoreEntry::Tone(tone, _)) => *tone,
+                _ => Tone::NONE,
+            })
+            .collect();
+        let picks = fretboard.calc_melody_picks(&tones, constraints);
+        let mut entries = vec![ProtoEntry::Fretted(FrettedEntry::Fretboard(fretboard))];
+        for (entry, pick) in self.entries.iter().zip(picks) {
+            entries.push(match (entry, pick) {
+                (ProtoEntry::Core(CoreEntry::Tone(_, duration)), Some(pick)) => {
+                    ProtoEntry::from(FrettedEntry::from((pick, *duration)))
+                }
+                (ProtoEntry::Core(CoreEntry::Tone(_, duration)), None) => {
+                    ProtoEntry::Core(CoreEntry::Rest(*duration))
+                }
+                _ => entry.clone(),
+            });
+        }
+        Self::new(id.to_owned(), TrackKind::Guitar, entries)
+    }
+}
+
+impl BarLayer {
+    // Same slices in another track, with the indexes moved by the offset.
+    fn to_track(&self, track: &str, offset: usize) -> Self {
+        let slices = self
+            .slices
+            .iter()
+            .map(|x| {
+                let mut slice = x.clone();
+                if let SliceBegin::Index(index) = slice.begin {
+                    slice.begin = SliceBegin::Index(index + offset);
+                }
+                slice
+            })
+            .collect();
+        Self::new(track.to_owned(), slices)
+    }
+}
+
+impl Tab {
+    /// Adds a guitar track playing the melody of the track, which is placed at the same bars,
+    /// returns None if the track is not found or the id is taken.
+    pub fn add_melody_track(
+        &self,
+        track_id: &str,
+        id: &str,
+        fretboard: Fretboard,
+        constraints: &MelodyConstraints,
+    ) -> Option<Self> {
+        if self.tracks.iter().any(|x| x.id == id) {
+            return None;
+        }
+        let track = self.tracks.iter().find(|x| x.id == track_id)?;
+        let mut tracks = self.tracks.clone();
+        tracks.push(track.to_melody_track(id, fretboard, constraints));
+        let mut sections = self.sections.clone();
+        for bar in sections.iter_mut().flat_map(|x| x.bars.iter_mut()) {
+            let layers: Vec<BarLayer> = bar
+                .layers
+                .iter()
+                .filter(|x| x.track == track_id)
+                .map(|x| x.to_track(id, 1))
+                .collect();
+            bar.layers.extend(layers);
+        }
+        Some(Self {
+            uuid: self.uuid,
+            meta: self.meta,
+            tracks,
+            sections,
+            form: self.form.clone(),
+        })
+    }
+}
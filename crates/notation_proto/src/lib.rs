@@ -1,6 +1,7 @@
 pub use uuid;
 pub use {notation_core, notation_fretted, notation_guitar};
 
+pub mod arrange;
 pub mod bar;
 pub mod lyric_entry;
 pub mod position;
@@ -9,6 +10,7 @@ pub mod section;
 pub mod slice;
 pub mod tab;
 pub mod track;
+pub mod transpose;
 pub mod prelude {
     #[doc(hidden)]
     pub use uuid::Uuid;
@@ -29,6 +31,8 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::track::{Track, TrackKind};
     #[doc(hidden)]
+    pub use crate::transpose::TransposeStrategy;
+    #[doc(hidden)]
     pub use notation_core::prelude::*;
     #[doc(hidden)]
     pub use notation_fretted::prelude::*;
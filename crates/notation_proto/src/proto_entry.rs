@@ -3,7 +3,7 @@ use std::fmt::Display;
 use serde::{Deserialize, Serialize};
 
 use notation_core::prelude::{CoreEntry, Duration, Entry, EntryPassMode, MetaEntry};
-use notation_fretted::prelude::{FrettedEntry4, FrettedEntry6};
+use notation_fretted::prelude::FrettedEntry;
 
 use crate::prelude::LyricEntry;
 
@@ -13,8 +13,9 @@ pub enum ProtoEntry {
     Meta(MetaEntry),
     Core(CoreEntry),
     Lyric(LyricEntry),
-    Fretted6(FrettedEntry6),
-    Fretted4(FrettedEntry4),
+    /// Tabs saved before the string count was generalised use `Fretted6` and `Fretted4`.
+    #[serde(alias = "Fretted6", alias = "Fretted4")]
+    Fretted(FrettedEntry),
     Extra(String, String),
 }
 impl Display for ProtoEntry {
@@ -24,8 +25,7 @@ impl Display for ProtoEntry {
             ProtoEntry::Meta(x) => write!(f, "Meta({})", x),
             ProtoEntry::Core(x) => write!(f, "Core({})", x),
             ProtoEntry::Lyric(x) => write!(f, "Lyric({})", x),
-            ProtoEntry::Fretted6(x) => write!(f, "Fretted6({})", x),
-            ProtoEntry::Fretted4(x) => write!(f, "Fretted4({})", x),
+            ProtoEntry::Fretted(x) => write!(f, "Fretted({})", x),
             ProtoEntry::Extra(x, y) => write!(f, "Extra({}, {})", x, y),
         }
     }
@@ -37,8 +37,7 @@ impl ProtoEntry {
             ProtoEntry::Meta(entry) => entry.duration(),
             ProtoEntry::Core(entry) => entry.duration(),
             ProtoEntry::Lyric(entry) => entry.duration(),
-            ProtoEntry::Fretted6(entry) => entry.duration(),
-            ProtoEntry::Fretted4(entry) => entry.duration(),
+            ProtoEntry::Fretted(entry) => entry.duration(),
             ProtoEntry::Extra(_, _) => Duration::Zero,
         }
     }
@@ -48,8 +47,7 @@ impl ProtoEntry {
             ProtoEntry::Meta(entry) => entry.pass_mode(),
             ProtoEntry::Core(entry) => entry.pass_mode(),
             ProtoEntry::Lyric(entry) => entry.pass_mode(),
-            ProtoEntry::Fretted6(entry) => entry.pass_mode(),
-            ProtoEntry::Fretted4(entry) => entry.pass_mode(),
+            ProtoEntry::Fretted(entry) => entry.pass_mode(),
             ProtoEntry::Extra(_, _) => EntryPassMode::Immediate,
         }
     }
@@ -111,37 +109,19 @@ impl ProtoEntry {
             Err(self)
         }
     }
-    /// Returns `true` if the proto_entry is [`FrettedSix`].
-    pub fn is_fretted6(&self) -> bool {
-        matches!(self, Self::Fretted6(..))
+    /// Returns `true` if the proto_entry is [`Fretted`].
+    pub fn is_fretted(&self) -> bool {
+        matches!(self, Self::Fretted(..))
     }
-    pub fn as_fretted6(&self) -> Option<&FrettedEntry6> {
-        if let Self::Fretted6(v) = self {
+    pub fn as_fretted(&self) -> Option<&FrettedEntry> {
+        if let Self::Fretted(v) = self {
             Some(v)
         } else {
             None
         }
     }
-    pub fn try_into_fretted6(self) -> Result<FrettedEntry6, Self> {
-        if let Self::Fretted6(v) = self {
-            Ok(v)
-        } else {
-            Err(self)
-        }
-    }
-    /// Returns `true` if the proto_entry is [`FrettedFour`].
-    pub fn is_fretted4(&self) -> bool {
-        matches!(self, Self::Fretted4(..))
-    }
-    pub fn as_fretted4(&self) -> Option<&FrettedEntry4> {
-        if let Self::Fretted4(v) = self {
-            Some(v)
-        } else {
-            None
-        }
-    }
-    pub fn try_into_fretted4(self) -> Result<FrettedEntry4, Self> {
-        if let Self::Fretted4(v) = self {
+    pub fn try_into_fretted(self) -> Result<FrettedEntry, Self> {
+        if let Self::Fretted(v) = self {
             Ok(v)
         } else {
             Err(self)
@@ -223,14 +203,22 @@ impl From<LyricEntry> for ProtoEntry {
     }
 }
 
-impl From<FrettedEntry6> for ProtoEntry {
-    fn from(v: FrettedEntry6) -> Self {
-        ProtoEntry::Fretted6(v)
+impl From<FrettedEntry> for ProtoEntry {
+    fn from(v: FrettedEntry) -> Self {
+        ProtoEntry::Fretted(v)
     }
 }
 
-impl From<FrettedEntry4> for ProtoEntry {
-    fn from(v: FrettedEntry4) -> Self {
-        ProtoEntry::Fretted4(v)
+#[test]
+fn test_fretted_aliases() {
+    use notation_fretted::prelude::{Fretboard, StringTuning};
+    let fretboard = ProtoEntry::from(FrettedEntry::from(Fretboard::new(
+        20,
+        StringTuning::Standard,
+        0,
+    )));
+    for name in ["Fretted", "Fretted6", "Fretted4"] {
+        let text = format!("{}(Fretboard((total_fret_num: 20, tuning: Standard, capo: 0)))", name);
+        assert_eq!(ron::from_str::<ProtoEntry>(&text).unwrap(), fretboard);
     }
 }
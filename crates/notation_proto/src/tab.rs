@@ -5,7 +5,7 @@ use std::fmt::Display;
 
 use crate::prelude::{Form, Section, Track};
 use notation_core::prelude::{
-    Key, Note, Pitch, Scale, Signature, Syllable, Tempo, Units, Octave,
+    Chord, Key, Note, Octave, Pitch, RomanNumeral, Scale, Signature, Syllable, Tempo, Units,
 };
 
 #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
@@ -28,6 +28,9 @@ impl TabMeta {
     pub fn calc_note_from_syllable(&self, syllable: &Syllable, octave: &Octave) -> Note {
         self.scale.calc_note_from_syllable(&self.key, syllable, octave)
     }
+    pub fn calc_roman_numeral(&self, chord: &Chord) -> Option<RomanNumeral> {
+        RomanNumeral::from_chord(chord, &self.scale)
+    }
 }
 impl Display for TabMeta {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
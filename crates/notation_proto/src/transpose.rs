@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+
+use std::fmt::Display;
+
+use crate::prelude::{ProtoEntry, Tab, TabMeta, Track};
+use notation_core::prelude::{CoreEntry, Key, MetaEntry, Note, Scale, Semitones, Tone};
+
+// Chords are using moveable do, so only the key and the absolute things need to be moved.
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub enum TransposeStrategy {
+    /// Change the key only, fretted tracks are kept as they are.
+    #[default]
+    Key,
+    /// Keep the shapes in fretted tracks, and move the capo instead.
+    Capo,
+    /// Keep the capo, and move the shapes along the neck.
+    Reshape,
+}
+impl Display for TransposeStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl TransposeStrategy {
+    pub const ALL: [TransposeStrategy; 3] = [Self::Key, Self::Capo, Self::Reshape];
+}
+
+impl TabMeta {
+    pub fn transpose(&self, semitones: Semitones) -> Self {
+        Self {
+            key: self.scale.transpose_key(&self.key, semitones),
+            ..*self
+        }
+    }
+}
+
+impl ProtoEntry {
+    pub fn transpose_capo(&self, offset: i8) -> Option<Self> {
+        match self {
+            Self::Fretted(x) => x.transpose_capo(offset).map(Self::Fretted),
+            _ => Some(self.clone()),
+        }
+    }
+    pub fn transpose_frets(&self, offset: i8) -> Option<Self> {
+        match self {
+            Self::Fretted(x) => x.transpose_frets(offset).map(Self::Fretted),
+            _ => Some(self.clone()),
+        }
+    }
+}
+
+fn transpose_tone(tone: &Tone, scale: &Scale, key: &Key, semitones: Semitones) -> Tone {
+    let notes = tone
+        .get_notes()
+        .into_iter()
+        .map(|x| scale.calc_note_from_semitones(key, Semitones::from(x) + semitones))
+        .collect::<Vec<Note>>();
+    Tone::new(&notes)
+}
+
+impl Track {
+    fn has_fretboard(&self) -> bool {
+        self.entries.iter().any(|x| match x {
+            ProtoEntry::Fretted(entry) => entry.is_fretboard(),
+            _ => false,
+        })
+    }
+    fn try_transpose_fretted<F: Fn(&ProtoEntry) -> Option<ProtoEntry>>(
+        &self,
+        f: F,
+    ) -> Option<Vec<ProtoEntry>> {
+        self.entries.iter().map(f).collect()
+    }
+    // Shapes can't go below the nut, move them an octave higher in that case.
+    fn reshape_fretted(&self, offset: i8) -> Vec<ProtoEntry> {
+        self.try_transpose_fretted(|x| x.transpose_frets(offset))
+            .or_else(|| self.try_transpose_fretted(|x| x.transpose_frets(offset + 12)))
+            .unwrap_or_else(|| self.entries.clone())
+    }
+    /// `meta` is the one before transposing, needed to track key changes within the track.
+    pub fn transpose(
+        &self,
+        meta: &TabMeta,
+        semitones: Semitones,
+        strategy: TransposeStrategy,
+    ) -> Self {
+        let offset = semitones.0;
+        let entries = match strategy {
+            TransposeStrategy::Key => self.entries.clone(),
+            TransposeStrategy::Capo if self.has_fretboard() => self
+                .try_transpose_fretted(|x| x.transpose_capo(offset))
+                .or_else(|| self.try_transpose_fretted(|x| x.transpose_capo(offset - 12)))
+                .or_else(|| self.try_transpose_fretted(|x| x.transpose_capo(offset + 12)))
+                .unwrap_or_else(|| self.reshape_fretted(offset)),
+            _ => self.reshape_fretted(offset),
+        };
+        let meta = meta.transpose(semitones);
+        let mut key = meta.key;
+        let mut scale = meta.scale;
+        let entries = entries
+            .into_iter()
+            .map(|entry| match entry {
+                ProtoEntry::Meta(MetaEntry::Key(x)) => {
+                    key = scale.transpose_key(&x, semitones);
+                    ProtoEntry::Meta(MetaEntry::Key(key))
+                }
+                ProtoEntry::Meta(MetaEntry::Scale(x)) => {
+                    scale = x;
+                    entry
+                }
+                ProtoEntry::Core(CoreEntry::Tone(tone, duration)) => ProtoEntry::Core(
+                    CoreEntry::Tone(transpose_tone(&tone, &scale, &key, semitones), duration),
+                ),
+                _ => entry,
+            })
+            .collect();
+        Self::new(self.id.clone(), self.kind, entries)
+    }
+}
+
+impl Tab {
+    /// Move the whole tab to another key, e.g. to fit a singer's range.
+    pub fn transpose(&self, semitones: Semitones, strategy: TransposeStrategy) -> Self {
+        Self {
+            uuid: self.uuid,
+            meta: self.meta.transpose(semitones),
+            tracks: self
+                .tracks
+                .iter()
+                .map(|x| x.transpose(&self.meta, semitones, strategy))
+                .collect(),
+            sections: self.sections.clone(),
+            form: self.form.clone(),
+        }
+    }
+}
@@ -3,6 +3,8 @@ use std::io::Write;
 
 use ron::ser::{to_string_pretty, PrettyConfig};
 
+use anyhow::Context;
+
 use notation_proto::prelude::*;
 
 pub fn convert_tab(tab: &Tab) -> String {
@@ -23,3 +25,8 @@ pub fn write_tab(tab: &Tab, path: &'static str) {
     file.write_all(s.as_bytes()).unwrap();
     println!("Tab written to: `{}` [{}] - {}", path, s.len(), tab);
 }
+
+pub fn read_tab(path: &str) -> anyhow::Result<Tab> {
+    let s = std::fs::read_to_string(path).with_context(|| format!("Read failed: `{}`", path))?;
+    ron::from_str(&s).with_context(|| format!("Deserialization failed: `{}`", path))
+}